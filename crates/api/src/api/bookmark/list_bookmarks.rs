@@ -44,6 +44,7 @@ pub(super) async fn handler(
             } else {
                 None
             },
+            search: query.search,
             cursor,
             limit: Some(PAGINATION_LIMIT),
             user_id: user_id.as_inner(),
@@ -75,6 +76,10 @@ pub(super) struct BookmarkListQuery {
     #[param(nullable = false)]
     #[serde(rename = "tag[]")]
     tags: Option<Vec<Uuid>>,
+    /// Full-text search query matched against the title and author of the bookmark. Results are
+    /// ranked by relevance when set.
+    #[param(nullable = false)]
+    search: Option<String>,
     /// Pagination cursor
     #[param(nullable = false)]
     cursor: Option<String>,
@@ -65,6 +65,11 @@ struct Bookmark {
     archived_path: Option<String>,
     /// Linked tags
     tags: Vec<Tag>,
+    /// HTML-escaped excerpt of the bookmark with the terms matching the search query wrapped in
+    /// `<mark>` tags, only present when searching
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    snippet: Option<String>,
     /// Timestamp at which the bookmark was created
     created_at: DateTime<Utc>,
     /// Timestamp at which the bookmark was modified
@@ -82,6 +87,7 @@ impl From<BookmarkDto> for Bookmark {
             author: value.author,
             archived_path: value.archived_path,
             tags: value.tags.into_iter().map(Into::into).collect(),
+            snippet: value.search_match.and_then(|e| e.snippet),
             created_at: value.created_at,
             updated_at: value.updated_at,
         }
@@ -8,7 +8,7 @@ use colette_handler::{CreateCollectionCommand, CreateCollectionError, Handler as
 
 use crate::api::{
     ApiState,
-    collection::{BookmarkFilter, COLLECTIONS_TAG},
+    collection::{BookmarkFilter, COLLECTIONS_TAG, EntryFilter},
     common::{ApiError, Auth, CreatedResource, Json, NonEmptyString},
 };
 
@@ -32,6 +32,7 @@ pub(super) async fn handler(
         .handle(CreateCollectionCommand {
             title: body.title.into(),
             filter: body.filter.into(),
+            entry_filter: body.entry_filter.map(Into::into),
             user_id,
         })
         .await
@@ -54,6 +55,7 @@ pub(super) struct CollectionCreate {
     #[schema(value_type = String, min_length = 1)]
     title: NonEmptyString,
     filter: BookmarkFilter,
+    entry_filter: Option<EntryFilter>,
 }
 
 #[derive(utoipa::IntoResponses)]
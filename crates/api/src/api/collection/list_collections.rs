@@ -9,7 +9,7 @@ use colette_handler::{CollectionCursor, Handler as _, ListCollectionsQuery};
 use crate::api::{
     ApiState,
     collection::{COLLECTIONS_TAG, Collection},
-    common::{ApiError, Auth, Query},
+    common::{ApiError, Auth, ListInclude, Query},
     pagination::{PAGINATION_LIMIT, Paginated, decode_cursor},
 };
 
@@ -39,6 +39,7 @@ pub(super) async fn handler(
         .handle(ListCollectionsQuery {
             cursor,
             limit: Some(PAGINATION_LIMIT),
+            with_counts: query.include.contains(&ListInclude::Counts),
             user_id: user_id.as_inner(),
         })
         .await
@@ -61,6 +62,9 @@ pub(super) struct CollectionListQuery {
     /// Pagination cursor
     #[param(nullable = false)]
     cursor: Option<String>,
+    /// Optional data to include with each item
+    #[serde(default)]
+    include: Vec<ListInclude>,
 }
 
 #[derive(utoipa::IntoResponses)]
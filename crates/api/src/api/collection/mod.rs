@@ -6,7 +6,7 @@ use colette_handler::CollectionDto;
 use utoipa::OpenApi;
 use uuid::Uuid;
 
-use crate::api::{ApiState, pagination::Paginated};
+use crate::api::{ApiState, entry::EntryCounts, pagination::Paginated};
 
 mod create_collection;
 mod delete_collection;
@@ -40,6 +40,12 @@ struct Collection {
     id: Uuid,
     title: String,
     filter: BookmarkFilter,
+    entry_filter: Option<EntryFilter>,
+    /// Entry counts of the collection, only present if requested and the collection has an entry
+    /// filter
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    entry_counts: Option<EntryCounts>,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
 }
@@ -50,6 +56,8 @@ impl From<CollectionDto> for Collection {
             id: value.id,
             title: value.title,
             filter: value.filter.into(),
+            entry_filter: value.entry_filter.map(Into::into),
+            entry_counts: value.entry_counts.map(Into::into),
             created_at: value.created_at,
             updated_at: value.updated_at,
         }
@@ -378,6 +386,8 @@ pub(crate) enum EntryTextField {
     Description,
     Author,
     Tag,
+    SubscriptionTitle,
+    FeedHost,
 }
 
 impl From<EntryTextField> for colette_crud::EntryTextField {
@@ -388,6 +398,8 @@ impl From<EntryTextField> for colette_crud::EntryTextField {
             EntryTextField::Description => Self::Description,
             EntryTextField::Author => Self::Author,
             EntryTextField::Tag => Self::Tag,
+            EntryTextField::SubscriptionTitle => Self::SubscriptionTitle,
+            EntryTextField::FeedHost => Self::FeedHost,
         }
     }
 }
@@ -400,6 +412,8 @@ impl From<colette_crud::EntryTextField> for EntryTextField {
             colette_crud::EntryTextField::Description => Self::Description,
             colette_crud::EntryTextField::Author => Self::Author,
             colette_crud::EntryTextField::Tag => Self::Tag,
+            colette_crud::EntryTextField::SubscriptionTitle => Self::SubscriptionTitle,
+            colette_crud::EntryTextField::FeedHost => Self::FeedHost,
         }
     }
 }
@@ -408,12 +422,16 @@ impl From<colette_crud::EntryTextField> for EntryTextField {
 #[serde(rename_all = "camelCase")]
 pub(crate) enum EntryBooleanField {
     HasRead,
+    Starred,
+    HasNote,
 }
 
 impl From<EntryBooleanField> for colette_crud::EntryBooleanField {
     fn from(value: EntryBooleanField) -> Self {
         match value {
             EntryBooleanField::HasRead => Self::HasRead,
+            EntryBooleanField::Starred => Self::Starred,
+            EntryBooleanField::HasNote => Self::HasNote,
         }
     }
 }
@@ -422,6 +440,8 @@ impl From<colette_crud::EntryBooleanField> for EntryBooleanField {
     fn from(value: colette_crud::EntryBooleanField) -> Self {
         match value {
             colette_crud::EntryBooleanField::HasRead => Self::HasRead,
+            colette_crud::EntryBooleanField::Starred => Self::Starred,
+            colette_crud::EntryBooleanField::HasNote => Self::HasNote,
         }
     }
 }
@@ -431,12 +451,14 @@ impl From<colette_crud::EntryBooleanField> for EntryBooleanField {
 #[serde(rename_all = "camelCase")]
 pub(crate) enum EntryDateField {
     PublishedAt,
+    ReadAt,
 }
 
 impl From<EntryDateField> for colette_crud::EntryDateField {
     fn from(value: EntryDateField) -> Self {
         match value {
             EntryDateField::PublishedAt => Self::PublishedAt,
+            EntryDateField::ReadAt => Self::ReadAt,
         }
     }
 }
@@ -445,6 +467,7 @@ impl From<colette_crud::EntryDateField> for EntryDateField {
     fn from(value: colette_crud::EntryDateField) -> Self {
         match value {
             colette_crud::EntryDateField::PublishedAt => Self::PublishedAt,
+            colette_crud::EntryDateField::ReadAt => Self::ReadAt,
         }
     }
 }
@@ -8,7 +8,7 @@ use colette_handler::{Handler as _, UpdateCollectionCommand, UpdateCollectionErr
 
 use crate::api::{
     ApiState,
-    collection::{BookmarkFilter, COLLECTIONS_TAG},
+    collection::{BookmarkFilter, COLLECTIONS_TAG, EntryFilter},
     common::{ApiError, Auth, Id, Json, NonEmptyString, Path},
 };
 
@@ -35,6 +35,7 @@ pub(super) async fn handler(
             id: id.into(),
             title: body.title.map(Into::into),
             filter: body.filter.map(Into::into),
+            entry_filter: body.entry_filter.map(|e| e.map(Into::into)),
             user_id,
         })
         .await
@@ -55,6 +56,8 @@ pub(super) struct CollectionUpdate {
     #[schema(value_type = Option<String>, min_length = 1, nullable = false)]
     title: Option<NonEmptyString>,
     filter: Option<BookmarkFilter>,
+    #[serde(default, with = "serde_with::rust::double_option")]
+    entry_filter: Option<Option<EntryFilter>>,
 }
 
 #[derive(utoipa::IntoResponses)]
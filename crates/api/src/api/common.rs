@@ -13,10 +13,10 @@ use axum_extra::{
 use colette_authentication::UserId;
 use colette_crypto::OtpCodeGenerator;
 use colette_handler::*;
-use colette_http::ReqwestClient;
+use colette_http::{CachingClient, PoliteClient, ReqwestClient};
 use colette_jwt::JwtManagerImpl;
 use colette_oidc::OidcClientImpl;
-use colette_queue::TokioJobProducer;
+use colette_queue::{PostgresJobProducer, PostgresJobStore};
 use colette_repository::*;
 use colette_s3::S3ClientImpl;
 use colette_smtp::SmtpClientImpl;
@@ -79,6 +79,7 @@ pub struct ApiState {
     pub export_backup: Arc<
         ExportBackupHandler<
             PostgresBookmarkRepository,
+            PostgresEntryRepository,
             PostgresSubscriptionRepository,
             PostgresTagRepository,
         >,
@@ -88,19 +89,34 @@ pub struct ApiState {
     pub list_bookmarks:
         Arc<ListBookmarksHandler<PostgresBookmarkRepository, PostgresCollectionRepository>>,
     pub get_bookmark: Arc<GetBookmarkHandler<PostgresBookmarkRepository>>,
-    pub create_bookmark: Arc<CreateBookmarkHandler<PostgresBookmarkRepository, TokioJobProducer>>,
-    pub update_bookmark: Arc<UpdateBookmarkHandler<PostgresBookmarkRepository, TokioJobProducer>>,
-    pub delete_bookmark: Arc<DeleteBookmarkHandler<PostgresBookmarkRepository, TokioJobProducer>>,
-    pub scrape_bookmark: Arc<ScrapeBookmarkHandler<ReqwestClient>>,
-    pub refresh_bookmark: Arc<RefreshBookmarkHandler<PostgresBookmarkRepository, ReqwestClient>>,
+    pub create_bookmark:
+        Arc<CreateBookmarkHandler<PostgresBookmarkRepository, PostgresJobProducer>>,
+    pub update_bookmark:
+        Arc<UpdateBookmarkHandler<PostgresBookmarkRepository, PostgresJobProducer>>,
+    pub delete_bookmark:
+        Arc<DeleteBookmarkHandler<PostgresBookmarkRepository, PostgresJobProducer>>,
+    pub scrape_bookmark: Arc<ScrapeBookmarkHandler<CachingClient<PoliteClient<ReqwestClient>>>>,
+    pub refresh_bookmark: Arc<
+        RefreshBookmarkHandler<
+            PostgresBookmarkRepository,
+            CachingClient<PoliteClient<ReqwestClient>>,
+        >,
+    >,
     pub link_bookmark_tags: Arc<LinkBookmarkTagsHandler<PostgresBookmarkRepository>>,
-    pub import_bookmarks: Arc<ImportBookmarksHandler<PostgresBookmarkRepository, TokioJobProducer>>,
+    pub import_bookmarks:
+        Arc<ImportBookmarksHandler<PostgresBookmarkRepository, PostgresJobProducer>>,
     pub export_bookmarks: Arc<ExportBookmarksHandler<PostgresBookmarkRepository>>,
-    pub archive_thumbnail:
-        Arc<ArchiveThumbnailHandler<PostgresBookmarkRepository, ReqwestClient, S3ClientImpl>>,
+    pub archive_thumbnail: Arc<
+        ArchiveThumbnailHandler<
+            PostgresBookmarkRepository,
+            CachingClient<PoliteClient<ReqwestClient>>,
+            S3ClientImpl,
+        >,
+    >,
 
     // Collections
-    pub list_collections: Arc<ListCollectionsHandler<PostgresCollectionRepository>>,
+    pub list_collections:
+        Arc<ListCollectionsHandler<PostgresCollectionRepository, PostgresEntryRepository>>,
     pub get_collection: Arc<GetCollectionHandler<PostgresCollectionRepository>>,
     pub create_collection: Arc<CreateCollectionHandler<PostgresCollectionRepository>>,
     pub update_collection: Arc<UpdateCollectionHandler<PostgresCollectionRepository>>,
@@ -112,10 +128,24 @@ pub struct ApiState {
     pub get_entry: Arc<GetEntryHandler<PostgresEntryRepository>>,
     pub mark_entry_as_read: Arc<MarkEntryAsReadHandler<PostgresEntryRepository>>,
     pub mark_entry_as_unread: Arc<MarkEntryAsUnreadHandler<PostgresEntryRepository>>,
+    pub mark_entries:
+        Arc<MarkEntriesHandler<PostgresEntryRepository, PostgresCollectionRepository>>,
+    pub star_entry: Arc<StarEntryHandler<PostgresEntryRepository>>,
+    pub unstar_entry: Arc<UnstarEntryHandler<PostgresEntryRepository>>,
+    pub update_entry: Arc<UpdateEntryHandler<PostgresEntryRepository>>,
 
     // Feeds
-    pub detect_feeds: Arc<DetectFeedsHandler<ReqwestClient>>,
-    pub scrape_feed: Arc<ScrapeFeedHandler<PostgresFeedRepository, ReqwestClient>>,
+    pub detect_feeds: Arc<DetectFeedsHandler<CachingClient<PoliteClient<ReqwestClient>>>>,
+    pub scrape_feed:
+        Arc<ScrapeFeedHandler<PostgresFeedRepository, CachingClient<PoliteClient<ReqwestClient>>>>,
+    pub verify_websub: Arc<VerifyWebSubHandler<PostgresFeedRepository>>,
+    pub receive_websub: Arc<ReceiveWebSubHandler<PostgresFeedRepository, PostgresRuleRepository>>,
+
+    // Jobs
+    pub list_jobs: Arc<ListJobsHandler<PostgresJobStore>>,
+    pub get_job: Arc<GetJobHandler<PostgresJobStore>>,
+    pub retry_job: Arc<RetryJobHandler<PostgresJobStore>>,
+    pub cancel_job: Arc<CancelJobHandler<PostgresJobStore>>,
 
     // Personal access tokens
     pub list_pats: Arc<ListPatsHandler<PostgresPatRepository>>,
@@ -125,12 +155,22 @@ pub struct ApiState {
     pub delete_pat: Arc<DeletePatHandler<PostgresPatRepository>>,
     pub validate_pat: Arc<ValidatePatHandler<PostgresPatRepository>>,
 
+    // Rules
+    pub list_rules: Arc<ListRulesHandler<PostgresRuleRepository>>,
+    pub get_rule: Arc<GetRuleHandler<PostgresRuleRepository>>,
+    pub create_rule: Arc<CreateRuleHandler<PostgresRuleRepository>>,
+    pub update_rule: Arc<UpdateRuleHandler<PostgresRuleRepository>>,
+    pub delete_rule: Arc<DeleteRuleHandler<PostgresRuleRepository>>,
+    pub run_rule: Arc<RunRuleHandler<PostgresRuleRepository>>,
+
     // Subscriptions
     pub list_subscriptions: Arc<ListSubscriptionsHandler<PostgresSubscriptionRepository>>,
     pub get_subscription: Arc<GetSubscriptionHandler<PostgresSubscriptionRepository>>,
     pub create_subscription: Arc<CreateSubscriptionHandler<PostgresSubscriptionRepository>>,
     pub update_subscription: Arc<UpdateSubscriptionHandler<PostgresSubscriptionRepository>>,
     pub delete_subscription: Arc<DeleteSubscriptionHandler<PostgresSubscriptionRepository>>,
+    pub refresh_subscription:
+        Arc<RefreshSubscriptionHandler<PostgresSubscriptionRepository, PostgresJobProducer>>,
     pub link_subscription_tags: Arc<LinkSubscriptionTagsHandler<PostgresSubscriptionRepository>>,
     pub import_subscriptions: Arc<ImportSubscriptionsHandler<PostgresSubscriptionRepository>>,
     pub export_subscriptions: Arc<ExportSubscriptionsHandler<PostgresSubscriptionRepository>>,
@@ -170,6 +210,14 @@ pub(crate) struct CreatedResource {
     pub(crate) id: Uuid,
 }
 
+/// Optional data to include in list responses
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) enum ListInclude {
+    /// Total and unread entry counts
+    Counts,
+}
+
 #[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize)]
 #[serde(try_from = "String", into = "String")]
 pub(crate) struct NonEmptyString(String);
@@ -42,6 +42,7 @@ pub(super) async fn handler(
             subscription_id: query.subscription_id,
             has_read: query.has_read,
             tags: query.tags,
+            search: query.search,
             cursor,
             limit: Some(PAGINATION_LIMIT),
             user_id: user_id.as_inner(),
@@ -73,9 +74,13 @@ pub(super) struct EntryListQuery {
     #[param(nullable = false)]
     #[serde(rename = "tag[]")]
     tags: Option<Vec<Uuid>>,
-    /// Filter by the ID of a collection whose filters may apply to the entry
+    /// Filter by the ID of a collection whose entry filter applies to the entry
     #[param(nullable = false)]
     collection_id: Option<Uuid>,
+    /// Full-text search query matched against the title, description, and author of the entry.
+    /// Results are ranked by relevance when set.
+    #[param(nullable = false)]
+    search: Option<String>,
     /// Pagination cursor
     #[param(nullable = false)]
     cursor: Option<String>,
@@ -0,0 +1,88 @@
+use axum::{
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use colette_crud::CollectionError;
+use colette_handler::{Handler as _, MarkEntriesCommand, MarkEntriesError};
+
+use crate::api::{
+    ApiState,
+    common::{ApiError, Auth, Json},
+    entry::{ENTRIES_TAG, EntriesMarked, EntrySelection},
+};
+
+#[utoipa::path(
+  post,
+  path = "/mark-read",
+  request_body = EntrySelection,
+  responses(OkResponse, ErrResponse),
+  operation_id = "markEntriesAsRead",
+  description = "Mark all entries matching the selection as read",
+  tag = ENTRIES_TAG
+)]
+#[axum::debug_handler]
+pub(super) async fn handler(
+    State(state): State<ApiState>,
+    Auth { user_id }: Auth,
+    Json(body): Json<EntrySelection>,
+) -> Result<OkResponse, ErrResponse> {
+    match state
+        .mark_entries
+        .handle(MarkEntriesCommand {
+            collection_id: body.collection_id,
+            subscription_id: body.subscription_id,
+            tags: body.tags,
+            published_before: body.published_before,
+            has_read: true,
+            user_id,
+        })
+        .await
+    {
+        Ok(count) => Ok(OkResponse(EntriesMarked { count })),
+        Err(e) => match e {
+            MarkEntriesError::Collection(CollectionError::NotFound(_)) => {
+                Err(ErrResponse::NotFound(e.into()))
+            }
+            _ => Err(ErrResponse::InternalServerError(e.into())),
+        },
+    }
+}
+
+#[derive(utoipa::IntoResponses)]
+#[response(status = StatusCode::OK, description = "Number of entries marked as read")]
+pub(super) struct OkResponse(EntriesMarked);
+
+impl IntoResponse for OkResponse {
+    fn into_response(self) -> Response {
+        (StatusCode::OK, axum::Json(self.0)).into_response()
+    }
+}
+
+#[allow(dead_code)]
+#[derive(utoipa::IntoResponses)]
+pub(super) enum ErrResponse {
+    #[response(status = StatusCode::UNAUTHORIZED, description = "User not authenticated")]
+    Unauthorized(ApiError),
+
+    #[response(status = StatusCode::UNPROCESSABLE_ENTITY, description = "Invalid input")]
+    UnprocessableEntity(ApiError),
+
+    #[response(status = StatusCode::NOT_FOUND, description = "Collection not found")]
+    NotFound(ApiError),
+
+    #[response(status = "default", description = "Unknown error")]
+    InternalServerError(ApiError),
+}
+
+impl IntoResponse for ErrResponse {
+    fn into_response(self) -> Response {
+        match self {
+            Self::NotFound(e) => (StatusCode::NOT_FOUND, e).into_response(),
+            Self::InternalServerError(_) => {
+                (StatusCode::INTERNAL_SERVER_ERROR, ApiError::unknown()).into_response()
+            }
+            _ => unreachable!(),
+        }
+    }
+}
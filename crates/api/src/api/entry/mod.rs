@@ -1,22 +1,28 @@
 use axum::{Router, routing};
 use chrono::{DateTime, Utc};
-use colette_handler::EntryDto;
+use colette_handler::{EnclosureDto, EntryDto};
 use url::Url;
 use utoipa::OpenApi;
 use uuid::Uuid;
 
 use crate::api::{ApiState, pagination::Paginated};
 
+mod get_entry;
 mod list_entries;
+mod mark_entries_as_read;
+mod mark_entries_as_unread;
 mod mark_entry_as_read;
 mod mark_entry_as_unread;
+mod star_entry;
+mod unstar_entry;
+mod update_entry;
 
 const ENTRIES_TAG: &str = "Entries";
 
 #[derive(OpenApi)]
 #[openapi(
-    components(schemas(Entry, Paginated<Entry>)),
-    paths(list_entries::handler, mark_entry_as_read::handler, mark_entry_as_unread::handler)
+    components(schemas(Entry, ContentType, Enclosure, Paginated<Entry>, update_entry::EntryUpdate, EntrySelection, EntriesMarked, EntryCounts)),
+    paths(list_entries::handler, mark_entries_as_read::handler, mark_entries_as_unread::handler, get_entry::handler, update_entry::handler, mark_entry_as_read::handler, mark_entry_as_unread::handler, star_entry::handler, unstar_entry::handler)
 )]
 pub(crate) struct EntryApi;
 
@@ -24,6 +30,13 @@ impl EntryApi {
     pub(crate) fn router() -> Router<ApiState> {
         Router::new()
             .route("/", routing::get(list_entries::handler))
+            .route("/mark-read", routing::post(mark_entries_as_read::handler))
+            .route(
+                "/mark-unread",
+                routing::post(mark_entries_as_unread::handler),
+            )
+            .route("/{id}", routing::get(get_entry::handler))
+            .route("/{id}", routing::patch(update_entry::handler))
             .route(
                 "/{id}/markAsRead",
                 routing::post(mark_entry_as_read::handler),
@@ -32,10 +45,12 @@ impl EntryApi {
                 "/{id}/markAsUnread",
                 routing::post(mark_entry_as_unread::handler),
             )
+            .route("/{id}/star", routing::post(star_entry::handler))
+            .route("/{id}/unstar", routing::post(unstar_entry::handler))
     }
 }
 
-/// A feed entry, with read status
+/// A feed entry, with read and star status
 #[derive(Debug, Clone, serde::Serialize, utoipa::ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub(crate) struct Entry {
@@ -47,19 +62,44 @@ pub(crate) struct Entry {
     title: String,
     /// Timestamp at which the entry was published
     published_at: DateTime<Utc>,
-    /// Description of the entry
+    /// Description of the entry as sanitized HTML, usually a summary of its content
     #[schema(required)]
     description: Option<String>,
+    /// Full content of the entry, sanitized unless it is plain text
+    #[schema(required)]
+    content: Option<String>,
+    /// Markup of the full content of the entry
+    #[schema(required)]
+    content_type: Option<ContentType>,
+    /// Plain-text excerpt of the entry, for use in list views
+    #[schema(required)]
+    excerpt: Option<String>,
+    /// Full article extracted from the webpage the entry links to, only present if the
+    /// subscription has full article fetching enabled
+    #[schema(required)]
+    article_content: Option<String>,
     /// Author of the entry
     #[schema(required)]
     author: Option<String>,
     /// Thumbnail URL of the entry
     #[schema(required)]
     thumbnail_url: Option<Url>,
+    /// Media files attached to the entry, such as podcast episodes or videos
+    enclosures: Vec<Enclosure>,
     /// Read status of the entry
     read_status: ReadStatus,
+    /// Star status of the entry
+    star_status: StarStatus,
+    /// Private note of the user about the entry
+    #[schema(required)]
+    note: Option<String>,
     /// Unique identifier of the associated feed
     feed_id: Uuid,
+    /// HTML-escaped plain-text excerpt of the entry with the terms matching the search query
+    /// wrapped in `<mark>` tags, only present when searching
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    snippet: Option<String>,
 }
 
 impl From<EntryDto> for Entry {
@@ -70,10 +110,111 @@ impl From<EntryDto> for Entry {
             title: value.title,
             published_at: value.published_at,
             description: value.description,
+            content: value.content,
+            content_type: value.content_type.map(Into::into),
+            excerpt: value.excerpt,
+            article_content: value.article_content,
             author: value.author,
             thumbnail_url: value.thumbnail_url,
+            enclosures: value.enclosures.into_iter().map(Into::into).collect(),
             read_status: value.read_status.into(),
+            star_status: value.star_status.into(),
+            note: value.note,
             feed_id: value.feed_id,
+            snippet: value.search_match.and_then(|e| e.snippet),
+        }
+    }
+}
+
+/// Selection of entries to mark as read or unread. All selectors are optional, and are combined
+/// when several are given.
+#[derive(Debug, Clone, serde::Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct EntrySelection {
+    /// Only select entries of the collection with this ID
+    #[schema(nullable = false)]
+    collection_id: Option<Uuid>,
+    /// Only select entries of the subscription with this ID
+    #[schema(nullable = false)]
+    subscription_id: Option<Uuid>,
+    /// Only select entries of subscriptions linked to these tags
+    #[schema(nullable = false)]
+    tags: Option<Vec<Uuid>>,
+    /// Only select entries published before this timestamp
+    #[schema(nullable = false)]
+    published_before: Option<DateTime<Utc>>,
+}
+
+/// Result of marking a selection of entries
+#[derive(Debug, Clone, serde::Serialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct EntriesMarked {
+    /// Number of entries whose read status changed
+    count: u64,
+}
+
+/// Counts of the entries associated with a subscription, tag or collection
+#[derive(Debug, Clone, serde::Serialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct EntryCounts {
+    /// Number of entries
+    total: i64,
+    /// Number of unread entries
+    unread: i64,
+}
+
+impl From<colette_handler::EntryCounts> for EntryCounts {
+    fn from(value: colette_handler::EntryCounts) -> Self {
+        Self {
+            total: value.total,
+            unread: value.unread,
+        }
+    }
+}
+
+/// Markup of the full content of an entry
+#[derive(Debug, Clone, serde::Serialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) enum ContentType {
+    Text,
+    Html,
+    Xhtml,
+}
+
+impl From<colette_ingestion::ContentType> for ContentType {
+    fn from(value: colette_ingestion::ContentType) -> Self {
+        match value {
+            colette_ingestion::ContentType::Text => Self::Text,
+            colette_ingestion::ContentType::Html => Self::Html,
+            colette_ingestion::ContentType::Xhtml => Self::Xhtml,
+        }
+    }
+}
+
+/// Media file attached to an entry
+#[derive(Debug, Clone, serde::Serialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Enclosure {
+    /// URL of the media file
+    url: Url,
+    /// MIME type of the media file
+    #[schema(required)]
+    mime_type: Option<String>,
+    /// Size of the media file in bytes
+    #[schema(required)]
+    length: Option<u64>,
+    /// Duration of the media file in seconds
+    #[schema(required)]
+    duration_sec: Option<u32>,
+}
+
+impl From<EnclosureDto> for Enclosure {
+    fn from(value: EnclosureDto) -> Self {
+        Self {
+            url: value.url,
+            mime_type: value.mime_type,
+            length: value.length,
+            duration_sec: value.duration_sec,
         }
     }
 }
@@ -92,3 +233,18 @@ impl From<colette_crud::ReadStatus> for ReadStatus {
         }
     }
 }
+
+#[derive(Debug, Clone, serde::Serialize, utoipa::ToSchema)]
+pub enum StarStatus {
+    Unstarred,
+    Starred(DateTime<Utc>),
+}
+
+impl From<colette_crud::StarStatus> for StarStatus {
+    fn from(value: colette_crud::StarStatus) -> Self {
+        match value {
+            colette_crud::StarStatus::Unstarred => StarStatus::Unstarred,
+            colette_crud::StarStatus::Starred(starred_at) => StarStatus::Starred(starred_at),
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use axum::{
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use colette_crud::EntryError;
+use colette_handler::{Handler as _, UpdateEntryCommand, UpdateEntryError};
+
+use crate::api::{
+    ApiState,
+    common::{ApiError, Auth, Id, Json, NonEmptyString, Path},
+    entry::ENTRIES_TAG,
+};
+
+#[utoipa::path(
+    patch,
+    path = "/{id}",
+    params(Id),
+    request_body = EntryUpdate,
+    responses(OkResponse, ErrResponse),
+    operation_id = "updateEntry",
+    description = "Update the private note of an entry by ID",
+    tag = ENTRIES_TAG
+)]
+#[axum::debug_handler]
+pub(super) async fn handler(
+    State(state): State<ApiState>,
+    Path(Id(id)): Path<Id>,
+    Auth { user_id }: Auth,
+    Json(body): Json<EntryUpdate>,
+) -> Result<OkResponse, ErrResponse> {
+    match state
+        .update_entry
+        .handle(UpdateEntryCommand {
+            id: id.into(),
+            note: body.note.map(|e| e.map(Into::into)),
+            user_id,
+        })
+        .await
+    {
+        Ok(()) => Ok(OkResponse),
+        Err(e) => match e {
+            UpdateEntryError::Entry(EntryError::NotFound(_)) => {
+                Err(ErrResponse::NotFound(e.into()))
+            }
+            _ => Err(ErrResponse::InternalServerError(e.into())),
+        },
+    }
+}
+
+#[derive(Debug, Clone, serde::Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct EntryUpdate {
+    /// Private note about the entry, or null to remove it
+    #[schema(value_type = Option<String>, min_length = 1)]
+    #[serde(default, with = "serde_with::rust::double_option")]
+    note: Option<Option<NonEmptyString>>,
+}
+
+#[derive(utoipa::IntoResponses)]
+#[response(status = StatusCode::NO_CONTENT, description = "Successfully updated entry")]
+pub(super) struct OkResponse;
+
+impl IntoResponse for OkResponse {
+    fn into_response(self) -> Response {
+        StatusCode::NO_CONTENT.into_response()
+    }
+}
+
+#[allow(dead_code)]
+#[derive(utoipa::IntoResponses)]
+pub(super) enum ErrResponse {
+    #[response(status = StatusCode::UNAUTHORIZED, description = "User not authenticated")]
+    Unauthorized(ApiError),
+
+    #[response(status = StatusCode::NOT_FOUND, description = "Entry not found")]
+    NotFound(ApiError),
+
+    #[response(status = StatusCode::UNPROCESSABLE_ENTITY, description = "Invalid input")]
+    UnprocessableEntity(ApiError),
+
+    #[response(status = "default", description = "Unknown error")]
+    InternalServerError(ApiError),
+}
+
+impl IntoResponse for ErrResponse {
+    fn into_response(self) -> Response {
+        match self {
+            Self::NotFound(e) => (StatusCode::NOT_FOUND, e).into_response(),
+            Self::InternalServerError(_) => {
+                (StatusCode::INTERNAL_SERVER_ERROR, ApiError::unknown()).into_response()
+            }
+            _ => unreachable!(),
+        }
+    }
+}
@@ -5,14 +5,21 @@ use utoipa::OpenApi;
 use crate::api::ApiState;
 
 mod detect_feeds;
+mod receive_websub;
 mod scrape_feed;
+mod verify_websub;
 
 const FEEDS_TAG: &str = "Feeds";
 
 #[derive(OpenApi)]
 #[openapi(
     components(schemas(FeedScraped, detect_feeds::FeedDetect, detect_feeds::FeedDetected)),
-    paths(detect_feeds::handler, scrape_feed::handler)
+    paths(
+        detect_feeds::handler,
+        scrape_feed::handler,
+        verify_websub::handler,
+        receive_websub::handler
+    )
 )]
 pub(crate) struct FeedApi;
 
@@ -21,6 +28,10 @@ impl FeedApi {
         Router::new()
             .route("/detect", routing::post(detect_feeds::handler))
             .route("/scrape", routing::post(scrape_feed::handler))
+            .route(
+                "/{id}/websub",
+                routing::get(verify_websub::handler).post(receive_websub::handler),
+            )
     }
 }
 
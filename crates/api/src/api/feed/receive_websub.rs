@@ -0,0 +1,101 @@
+use axum::{
+    body::Bytes,
+    extract::State,
+    http::{HeaderMap, StatusCode, header},
+    response::{IntoResponse, Response},
+};
+use colette_handler::{Handler as _, ReceiveWebSubCommand, ReceiveWebSubError};
+use colette_ingestion::FeedError;
+use uuid::Uuid;
+
+use crate::api::{
+    ApiState,
+    common::{ApiError, Id, Path},
+    feed::FEEDS_TAG,
+};
+
+const HUB_SIGNATURE_HEADER: &str = "X-Hub-Signature";
+
+#[utoipa::path(
+    post,
+    path = "/{id}/websub",
+    params(Id, ("X-Hub-Signature" = String, Header, description = "HMAC signature of the payload, computed with the subscription secret")),
+    request_body(content = String, description = "Feed document pushed by the hub", content_type = "application/xml"),
+    responses(OkResponse, ErrResponse),
+    operation_id = "receiveWebSub",
+    description = "Receive feed content pushed by a WebSub hub",
+    tag = FEEDS_TAG
+)]
+#[axum::debug_handler]
+pub(super) async fn handler(
+    State(state): State<ApiState>,
+    Path(id): Path<Uuid>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<OkResponse, ErrResponse> {
+    let signature = headers
+        .get(HUB_SIGNATURE_HEADER)
+        .and_then(|e| e.to_str().ok())
+        .map(ToOwned::to_owned);
+    let content_type = headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|e| e.to_str().ok())
+        .map(ToOwned::to_owned);
+
+    match state
+        .receive_websub
+        .handle(ReceiveWebSubCommand {
+            id: id.into(),
+            signature,
+            content_type,
+            body,
+        })
+        .await
+    {
+        Ok(()) => Ok(OkResponse),
+        Err(e) => match e {
+            // Hubs must not be told whether the signature matched, so the payload is dropped
+            ReceiveWebSubError::Signature => Ok(OkResponse),
+            ReceiveWebSubError::Feed(FeedError::NotFound(_)) => {
+                Err(ErrResponse::NotFound(e.into()))
+            }
+            ReceiveWebSubError::Scraper(_) => Err(ErrResponse::UnprocessableEntity(e.into())),
+            _ => Err(ErrResponse::InternalServerError(e.into())),
+        },
+    }
+}
+
+#[derive(utoipa::IntoResponses)]
+#[response(status = StatusCode::NO_CONTENT, description = "Successfully received content")]
+pub(super) struct OkResponse;
+
+impl IntoResponse for OkResponse {
+    fn into_response(self) -> Response {
+        StatusCode::NO_CONTENT.into_response()
+    }
+}
+
+#[allow(dead_code)]
+#[derive(utoipa::IntoResponses)]
+pub(super) enum ErrResponse {
+    #[response(status = StatusCode::NOT_FOUND, description = "Feed not found")]
+    NotFound(ApiError),
+
+    #[response(status = StatusCode::UNPROCESSABLE_ENTITY, description = "Invalid feed document")]
+    UnprocessableEntity(ApiError),
+
+    #[response(status = "default", description = "Unknown error")]
+    InternalServerError(ApiError),
+}
+
+impl IntoResponse for ErrResponse {
+    fn into_response(self) -> Response {
+        match self {
+            Self::NotFound(e) => (StatusCode::NOT_FOUND, e).into_response(),
+            Self::UnprocessableEntity(e) => (StatusCode::UNPROCESSABLE_ENTITY, e).into_response(),
+            Self::InternalServerError(_) => {
+                (StatusCode::INTERNAL_SERVER_ERROR, ApiError::unknown()).into_response()
+            }
+        }
+    }
+}
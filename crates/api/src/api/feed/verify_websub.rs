@@ -0,0 +1,112 @@
+use axum::{
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use colette_handler::{Handler as _, VerifyWebSubCommand, VerifyWebSubError};
+use colette_ingestion::{FeedError, WebSubMode};
+use url::Url;
+use uuid::Uuid;
+
+use crate::api::{
+    ApiState,
+    common::{ApiError, Id, Path, Query},
+    feed::FEEDS_TAG,
+};
+
+#[utoipa::path(
+    get,
+    path = "/{id}/websub",
+    params(Id, WebSubVerifyQuery),
+    responses(OkResponse, ErrResponse),
+    operation_id = "verifyWebSub",
+    description = "Verify the intent of a WebSub subscription request, echoing the challenge sent by the hub",
+    tag = FEEDS_TAG
+)]
+#[axum::debug_handler]
+pub(super) async fn handler(
+    State(state): State<ApiState>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<WebSubVerifyQuery>,
+) -> Result<OkResponse, ErrResponse> {
+    let mode = query
+        .mode
+        .parse::<WebSubMode>()
+        .map_err(|e| ErrResponse::UnprocessableEntity(e.into()))?;
+
+    match state
+        .verify_websub
+        .handle(VerifyWebSubCommand {
+            id: id.into(),
+            mode,
+            topic: query.topic,
+            challenge: query.challenge,
+            lease_seconds: query.lease_seconds,
+        })
+        .await
+    {
+        Ok(challenge) => Ok(OkResponse(challenge)),
+        Err(e) => match e {
+            VerifyWebSubError::Unexpected | VerifyWebSubError::Feed(FeedError::NotFound(_)) => {
+                Err(ErrResponse::NotFound(e.into()))
+            }
+            _ => Err(ErrResponse::InternalServerError(e.into())),
+        },
+    }
+}
+
+#[derive(Debug, Clone, serde::Deserialize, utoipa::IntoParams)]
+#[into_params(parameter_in = Query)]
+pub(super) struct WebSubVerifyQuery {
+    /// Subscription mode being verified: subscribe, unsubscribe, or denied
+    #[serde(rename = "hub.mode")]
+    #[param(rename = "hub.mode")]
+    mode: String,
+    /// Topic URL of the subscription
+    #[serde(rename = "hub.topic")]
+    #[param(rename = "hub.topic")]
+    topic: Url,
+    /// Random string to echo back to the hub
+    #[serde(rename = "hub.challenge")]
+    #[param(rename = "hub.challenge", nullable = false)]
+    challenge: Option<String>,
+    /// Number of seconds the subscription is leased for
+    #[serde(rename = "hub.lease_seconds")]
+    #[param(rename = "hub.lease_seconds", nullable = false)]
+    lease_seconds: Option<u32>,
+}
+
+#[derive(utoipa::IntoResponses)]
+#[response(status = StatusCode::OK, description = "Echoed hub challenge")]
+pub(super) struct OkResponse(#[to_schema] String);
+
+impl IntoResponse for OkResponse {
+    fn into_response(self) -> Response {
+        (StatusCode::OK, self.0).into_response()
+    }
+}
+
+#[allow(dead_code)]
+#[derive(utoipa::IntoResponses)]
+pub(super) enum ErrResponse {
+    #[response(status = StatusCode::NOT_FOUND, description = "Subscription not requested")]
+    NotFound(ApiError),
+
+    #[response(status = StatusCode::UNPROCESSABLE_ENTITY, description = "Invalid input")]
+    UnprocessableEntity(ApiError),
+
+    #[response(status = "default", description = "Unknown error")]
+    InternalServerError(ApiError),
+}
+
+impl IntoResponse for ErrResponse {
+    fn into_response(self) -> Response {
+        match self {
+            Self::NotFound(e) => (StatusCode::NOT_FOUND, e).into_response(),
+            Self::UnprocessableEntity(e) => (StatusCode::UNPROCESSABLE_ENTITY, e).into_response(),
+            Self::InternalServerError(_) => {
+                (StatusCode::INTERNAL_SERVER_ERROR, ApiError::unknown()).into_response()
+            }
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use axum::{
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use colette_handler::{CancelJobCommand, CancelJobError, Handler as _};
+
+use crate::api::{
+    ApiState,
+    common::{ApiError, Auth, Id, Path},
+    job::JOBS_TAG,
+};
+
+#[utoipa::path(
+  post,
+  path = "/{id}/cancel",
+  params(Id),
+  responses(OkResponse, ErrResponse),
+  operation_id = "cancelJob",
+  description = "Cancel a pending job",
+  tag = JOBS_TAG
+)]
+#[axum::debug_handler]
+pub(super) async fn handler(
+    State(state): State<ApiState>,
+    Path(Id(id)): Path<Id>,
+    Auth { user_id }: Auth,
+) -> Result<OkResponse, ErrResponse> {
+    match state
+        .cancel_job
+        .handle(CancelJobCommand { id, user_id })
+        .await
+    {
+        Ok(()) => Ok(OkResponse),
+        Err(e) => match e {
+            CancelJobError::Queue(colette_queue::Error::NotFound(_)) => {
+                Err(ErrResponse::NotFound(e.into()))
+            }
+            CancelJobError::Queue(colette_queue::Error::NotCancellable(_)) => {
+                Err(ErrResponse::Conflict(e.into()))
+            }
+            _ => Err(ErrResponse::InternalServerError(e.into())),
+        },
+    }
+}
+
+#[derive(utoipa::IntoResponses)]
+#[response(status = StatusCode::NO_CONTENT, description = "Successfully cancelled job")]
+pub(super) struct OkResponse;
+
+impl IntoResponse for OkResponse {
+    fn into_response(self) -> Response {
+        StatusCode::NO_CONTENT.into_response()
+    }
+}
+
+#[allow(dead_code)]
+#[derive(utoipa::IntoResponses)]
+pub(super) enum ErrResponse {
+    #[response(status = StatusCode::UNAUTHORIZED, description = "User not authenticated")]
+    Unauthorized(ApiError),
+
+    #[response(status = StatusCode::NOT_FOUND, description = "Job not found")]
+    NotFound(ApiError),
+
+    #[response(status = StatusCode::CONFLICT, description = "Job is no longer pending")]
+    Conflict(ApiError),
+
+    #[response(status = "default", description = "Unknown error")]
+    InternalServerError(ApiError),
+}
+
+impl IntoResponse for ErrResponse {
+    fn into_response(self) -> Response {
+        match self {
+            Self::NotFound(e) => (StatusCode::NOT_FOUND, e).into_response(),
+            Self::Conflict(e) => (StatusCode::CONFLICT, e).into_response(),
+            Self::InternalServerError(_) => {
+                (StatusCode::INTERNAL_SERVER_ERROR, ApiError::unknown()).into_response()
+            }
+            _ => unreachable!(),
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use axum::{
+    Json,
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use colette_handler::{Handler as _, JobCursor, ListJobsQuery};
+
+use crate::api::{
+    ApiState,
+    common::{ApiError, Auth, Query},
+    job::{JOBS_TAG, Job, JobStatus},
+    pagination::{PAGINATION_LIMIT, Paginated, decode_cursor},
+};
+
+#[utoipa::path(
+    get,
+    path = "",
+    params(JobListQuery),
+    responses(OkResponse, ErrResponse),
+    operation_id = "listJobs",
+    description = "List user jobs",
+    tag = JOBS_TAG
+)]
+#[axum::debug_handler]
+pub(super) async fn handler(
+    State(state): State<ApiState>,
+    Query(query): Query<JobListQuery>,
+    Auth { user_id }: Auth,
+) -> Result<OkResponse, ErrResponse> {
+    let cursor = query
+        .cursor
+        .map(|e| decode_cursor::<JobCursor>(&e))
+        .transpose()
+        .map_err(|e| ErrResponse::InternalServerError(e.into()))?;
+
+    match state
+        .list_jobs
+        .handle(ListJobsQuery {
+            job_type: query.job_type,
+            status: query.status.map(Into::into),
+            cursor,
+            limit: Some(PAGINATION_LIMIT),
+            user_id: user_id.as_inner(),
+        })
+        .await
+    {
+        Ok(jobs) => {
+            let data = jobs.try_into().map_err(ErrResponse::InternalServerError)?;
+
+            Ok(OkResponse(data))
+        }
+        Err(e) => Err(ErrResponse::InternalServerError(e.into())),
+    }
+}
+
+#[derive(Debug, Clone, serde::Deserialize, utoipa::IntoParams)]
+#[serde(rename_all = "camelCase")]
+#[into_params(parameter_in = Query)]
+pub(super) struct JobListQuery {
+    /// Filter by the type of the job
+    #[param(nullable = false)]
+    job_type: Option<String>,
+    /// Filter by the processing status of the job
+    #[param(nullable = false)]
+    status: Option<JobStatus>,
+    /// Pagination cursor
+    #[param(nullable = false)]
+    cursor: Option<String>,
+}
+
+#[derive(utoipa::IntoResponses)]
+#[response(status = StatusCode::OK, description = "Paginated list of jobs")]
+pub(super) struct OkResponse(Paginated<Job>);
+
+impl IntoResponse for OkResponse {
+    fn into_response(self) -> Response {
+        (StatusCode::OK, Json(self.0)).into_response()
+    }
+}
+
+#[allow(dead_code)]
+#[derive(utoipa::IntoResponses)]
+pub(super) enum ErrResponse {
+    #[response(status = StatusCode::UNAUTHORIZED, description = "User not authenticated")]
+    Unauthorized(ApiError),
+
+    #[response(status = "default", description = "Unknown error")]
+    InternalServerError(ApiError),
+}
+
+impl IntoResponse for ErrResponse {
+    fn into_response(self) -> Response {
+        match self {
+            Self::InternalServerError(_) => {
+                (StatusCode::INTERNAL_SERVER_ERROR, ApiError::unknown()).into_response()
+            }
+            _ => unreachable!(),
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use axum::{Router, routing};
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use utoipa::OpenApi;
+use uuid::Uuid;
+
+use crate::api::{ApiState, pagination::Paginated};
+
+mod cancel_job;
+mod get_job;
+mod list_jobs;
+mod retry_job;
+
+const JOBS_TAG: &str = "Jobs";
+
+#[derive(OpenApi)]
+#[openapi(
+    components(schemas(Job, Paginated<Job>, JobStatus)),
+    paths(list_jobs::handler, get_job::handler, retry_job::handler, cancel_job::handler)
+)]
+pub(crate) struct JobApi;
+
+impl JobApi {
+    pub(crate) fn router() -> Router<ApiState> {
+        Router::new()
+            .route("/", routing::get(list_jobs::handler))
+            .route("/{id}", routing::get(get_job::handler))
+            .route("/{id}/retry", routing::post(retry_job::handler))
+            .route("/{id}/cancel", routing::post(cancel_job::handler))
+    }
+}
+
+/// Background job queued on behalf of the user, such as a bookmark import
+#[derive(Debug, Clone, serde::Serialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Job {
+    /// Unique identifier of the job
+    id: Uuid,
+    /// Type of the job, such as "import_bookmarks"
+    job_type: String,
+    /// Input data of the job
+    #[schema(value_type = Object)]
+    data: Value,
+    /// Processing status of the job
+    status: JobStatus,
+    /// Error message from the last failed attempt
+    #[schema(required)]
+    message: Option<String>,
+    /// Number of attempts made so far
+    attempts: u32,
+    /// Number of attempts after which the job is marked as dead
+    max_attempts: u32,
+    /// Timestamp at which the job is next eligible to run
+    run_at: DateTime<Utc>,
+    /// Timestamp at which the last attempt started
+    #[schema(required)]
+    started_at: Option<DateTime<Utc>>,
+    /// Timestamp at which the job completed, died, or was cancelled
+    #[schema(required)]
+    completed_at: Option<DateTime<Utc>>,
+    /// Timestamp at which the job was created
+    created_at: DateTime<Utc>,
+}
+
+impl From<colette_queue::Job> for Job {
+    fn from(value: colette_queue::Job) -> Self {
+        Self {
+            id: value.id,
+            job_type: value.job_type,
+            data: value.data,
+            status: value.status.into(),
+            message: value.message,
+            attempts: value.attempts,
+            max_attempts: value.max_attempts,
+            run_at: value.run_at,
+            started_at: value.started_at,
+            completed_at: value.completed_at,
+            created_at: value.created_at,
+        }
+    }
+}
+
+/// Processing status of a job
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(crate) enum JobStatus {
+    Pending,
+    Running,
+    Completed,
+    Dead,
+    Cancelled,
+}
+
+impl From<JobStatus> for colette_queue::JobStatus {
+    fn from(value: JobStatus) -> Self {
+        match value {
+            JobStatus::Pending => Self::Pending,
+            JobStatus::Running => Self::Running,
+            JobStatus::Completed => Self::Completed,
+            JobStatus::Dead => Self::Dead,
+            JobStatus::Cancelled => Self::Cancelled,
+        }
+    }
+}
+
+impl From<colette_queue::JobStatus> for JobStatus {
+    fn from(value: colette_queue::JobStatus) -> Self {
+        match value {
+            colette_queue::JobStatus::Pending => Self::Pending,
+            colette_queue::JobStatus::Running => Self::Running,
+            colette_queue::JobStatus::Completed => Self::Completed,
+            colette_queue::JobStatus::Dead => Self::Dead,
+            colette_queue::JobStatus::Cancelled => Self::Cancelled,
+        }
+    }
+}
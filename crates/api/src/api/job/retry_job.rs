@@ -0,0 +1,84 @@
+use axum::{
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use colette_handler::{Handler as _, RetryJobCommand, RetryJobError};
+
+use crate::api::{
+    ApiState,
+    common::{ApiError, Auth, Id, Path},
+    job::JOBS_TAG,
+};
+
+#[utoipa::path(
+  post,
+  path = "/{id}/retry",
+  params(Id),
+  responses(OkResponse, ErrResponse),
+  operation_id = "retryJob",
+  description = "Re-enqueue a dead or cancelled job",
+  tag = JOBS_TAG
+)]
+#[axum::debug_handler]
+pub(super) async fn handler(
+    State(state): State<ApiState>,
+    Path(Id(id)): Path<Id>,
+    Auth { user_id }: Auth,
+) -> Result<OkResponse, ErrResponse> {
+    match state
+        .retry_job
+        .handle(RetryJobCommand { id, user_id })
+        .await
+    {
+        Ok(()) => Ok(OkResponse),
+        Err(e) => match e {
+            RetryJobError::Queue(colette_queue::Error::NotFound(_)) => {
+                Err(ErrResponse::NotFound(e.into()))
+            }
+            RetryJobError::Queue(colette_queue::Error::NotRetryable(_)) => {
+                Err(ErrResponse::Conflict(e.into()))
+            }
+            _ => Err(ErrResponse::InternalServerError(e.into())),
+        },
+    }
+}
+
+#[derive(utoipa::IntoResponses)]
+#[response(status = StatusCode::NO_CONTENT, description = "Successfully re-enqueued job")]
+pub(super) struct OkResponse;
+
+impl IntoResponse for OkResponse {
+    fn into_response(self) -> Response {
+        StatusCode::NO_CONTENT.into_response()
+    }
+}
+
+#[allow(dead_code)]
+#[derive(utoipa::IntoResponses)]
+pub(super) enum ErrResponse {
+    #[response(status = StatusCode::UNAUTHORIZED, description = "User not authenticated")]
+    Unauthorized(ApiError),
+
+    #[response(status = StatusCode::NOT_FOUND, description = "Job not found")]
+    NotFound(ApiError),
+
+    #[response(status = StatusCode::CONFLICT, description = "Job is neither dead nor cancelled")]
+    Conflict(ApiError),
+
+    #[response(status = "default", description = "Unknown error")]
+    InternalServerError(ApiError),
+}
+
+impl IntoResponse for ErrResponse {
+    fn into_response(self) -> Response {
+        match self {
+            Self::NotFound(e) => (StatusCode::NOT_FOUND, e).into_response(),
+            Self::Conflict(e) => (StatusCode::CONFLICT, e).into_response(),
+            Self::InternalServerError(_) => {
+                (StatusCode::INTERNAL_SERVER_ERROR, ApiError::unknown()).into_response()
+            }
+            _ => unreachable!(),
+        }
+    }
+}
@@ -6,7 +6,7 @@ use axum::{
 };
 use bookmark::BookmarkApi;
 use collection::CollectionApi;
-use common::{ApiError, verify_auth_extension};
+use common::{ApiError, ListInclude, verify_auth_extension};
 pub use common::{
     ApiState, Config as ApiConfig, OidcConfig as ApiOidcConfig, S3Config as ApiS3Config,
     ServerConfig as ApiServerConfig,
@@ -14,6 +14,8 @@ pub use common::{
 use config::ConfigApi;
 use entry::EntryApi;
 use feed::FeedApi;
+use job::JobApi;
+use rule::RuleApi;
 use subscription::SubscriptionApi;
 use tag::TagApi;
 use tower_http::{cors::CorsLayer, trace::TraceLayer};
@@ -37,8 +39,10 @@ mod common;
 pub mod config;
 mod entry;
 mod feed;
+mod job;
 mod pagination;
 mod pat;
+mod rule;
 mod subscription;
 mod tag;
 
@@ -59,11 +63,13 @@ const API_PREFIX: &str = "/api";
         (path = "/config", api = ConfigApi),
         (path = "/entries", api = EntryApi),
         (path = "/feeds", api = FeedApi),
+        (path = "/jobs", api = JobApi),
         (path = "/pats", api = PersonalAccessTokensApi),
+        (path = "/rules", api = RuleApi),
         (path = "/subscriptions", api = SubscriptionApi),
         (path = "/tags", api = TagApi),
     ),
-    components(schemas(CreatedResource, ApiError)),
+    components(schemas(CreatedResource, ApiError, ListInclude)),
     security(("bearerAuth" = [])),
     modifiers(&Security)
 )]
@@ -113,7 +119,9 @@ pub fn create_router(api_state: ApiState, origin_urls: Option<Vec<Url>>) -> Rout
         .nest("/bookmarks", BookmarkApi::router())
         .nest("/collections", CollectionApi::router())
         .nest("/entries", EntryApi::router())
+        .nest("/jobs", JobApi::router())
         .nest("/pats", PersonalAccessTokensApi::router())
+        .nest("/rules", RuleApi::router())
         .nest("/subscriptions", SubscriptionApi::router())
         .nest("/tags", TagApi::router())
         .layer(middleware::from_fn_with_state(
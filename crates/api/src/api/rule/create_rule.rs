@@ -0,0 +1,109 @@
+use axum::{
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use colette_crud::RuleError;
+use colette_handler::{CreateRuleCommand, CreateRuleError, Handler as _};
+
+use crate::api::{
+    ApiState,
+    collection::EntryFilter,
+    common::{ApiError, Auth, CreatedResource, Json, NonEmptyString},
+    rule::{RULES_TAG, RuleAction},
+};
+
+#[utoipa::path(
+  post,
+  path = "",
+  request_body = RuleCreate,
+  responses(OkResponse, ErrResponse),
+  operation_id = "createRule",
+  description = "Create a rule",
+  tag = RULES_TAG
+)]
+#[axum::debug_handler]
+pub(super) async fn handler(
+    State(state): State<ApiState>,
+    Auth { user_id }: Auth,
+    Json(body): Json<RuleCreate>,
+) -> Result<OkResponse, ErrResponse> {
+    match state
+        .create_rule
+        .handle(CreateRuleCommand {
+            title: body.title.into(),
+            condition: body.condition.into(),
+            actions: body.actions.into_iter().map(Into::into).collect(),
+            enabled: body.enabled,
+            user_id,
+        })
+        .await
+    {
+        Ok(data) => Ok(OkResponse(CreatedResource {
+            id: data.id().as_inner(),
+        })),
+        Err(e) => match e {
+            CreateRuleError::Rule(RuleError::Conflict(_)) => Err(ErrResponse::Conflict(e.into())),
+            CreateRuleError::Rule(
+                RuleError::InvalidTitleLength | RuleError::NoActions | RuleError::EmptyCondition,
+            ) => Err(ErrResponse::UnprocessableEntity(e.into())),
+            _ => Err(ErrResponse::InternalServerError(e.into())),
+        },
+    }
+}
+
+#[derive(Debug, Clone, serde::Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct RuleCreate {
+    #[schema(value_type = String, min_length = 1)]
+    title: NonEmptyString,
+    condition: EntryFilter,
+    #[schema(min_items = 1)]
+    actions: Vec<RuleAction>,
+    #[serde(default = "enabled")]
+    #[schema(default = true)]
+    enabled: bool,
+}
+
+fn enabled() -> bool {
+    true
+}
+
+#[derive(utoipa::IntoResponses)]
+#[response(status = StatusCode::CREATED, description = "New rule ID")]
+pub(super) struct OkResponse(CreatedResource);
+
+impl IntoResponse for OkResponse {
+    fn into_response(self) -> Response {
+        (StatusCode::CREATED, axum::Json(self.0)).into_response()
+    }
+}
+
+#[allow(dead_code)]
+#[derive(utoipa::IntoResponses)]
+pub(super) enum ErrResponse {
+    #[response(status = StatusCode::UNAUTHORIZED, description = "User not authenticated")]
+    Unauthorized(ApiError),
+
+    #[response(status = StatusCode::CONFLICT, description = "Rule already exists")]
+    Conflict(ApiError),
+
+    #[response(status = StatusCode::UNPROCESSABLE_ENTITY, description = "Invalid input")]
+    UnprocessableEntity(ApiError),
+
+    #[response(status = "default", description = "Unknown error")]
+    InternalServerError(ApiError),
+}
+
+impl IntoResponse for ErrResponse {
+    fn into_response(self) -> Response {
+        match self {
+            Self::Conflict(e) => (StatusCode::CONFLICT, e).into_response(),
+            Self::UnprocessableEntity(e) => (StatusCode::UNPROCESSABLE_ENTITY, e).into_response(),
+            Self::InternalServerError(_) => {
+                (StatusCode::INTERNAL_SERVER_ERROR, ApiError::unknown()).into_response()
+            }
+            _ => unreachable!(),
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use axum::{Router, routing};
+use chrono::{DateTime, Utc};
+use colette_handler::RuleDto;
+use utoipa::OpenApi;
+use uuid::Uuid;
+
+use crate::api::{ApiState, collection::EntryFilter, pagination::Paginated};
+
+mod create_rule;
+mod delete_rule;
+mod get_rule;
+mod list_rules;
+mod run_rule;
+mod update_rule;
+
+const RULES_TAG: &str = "Rules";
+
+#[derive(OpenApi)]
+#[openapi(
+    components(schemas(Rule, Paginated<Rule>, create_rule::RuleCreate, update_rule::RuleUpdate, run_rule::RuleRun, RuleAction)),
+    paths(list_rules::handler, create_rule::handler, get_rule::handler, update_rule::handler, delete_rule::handler, run_rule::handler)
+)]
+pub(crate) struct RuleApi;
+
+impl RuleApi {
+    pub(crate) fn router() -> Router<ApiState> {
+        Router::new()
+            .route("/", routing::get(list_rules::handler))
+            .route("/", routing::post(create_rule::handler))
+            .route("/{id}", routing::get(get_rule::handler))
+            .route("/{id}", routing::patch(update_rule::handler))
+            .route("/{id}", routing::delete(delete_rule::handler))
+            .route("/{id}/run", routing::post(run_rule::handler))
+    }
+}
+
+/// Rule applied to incoming entries of the user
+#[derive(Debug, Clone, serde::Serialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+struct Rule {
+    id: Uuid,
+    title: String,
+    /// Entries matching this filter have the actions applied
+    condition: EntryFilter,
+    actions: Vec<RuleAction>,
+    /// Whether the rule is applied to new entries
+    enabled: bool,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<RuleDto> for Rule {
+    fn from(value: RuleDto) -> Self {
+        Self {
+            id: value.id,
+            title: value.title,
+            condition: value.condition.into(),
+            actions: value.actions.into_iter().map(Into::into).collect(),
+            enabled: value.enabled,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
+
+/// Action applied to an entry matching the condition of a rule
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+enum RuleAction {
+    MarkAsRead,
+    Star,
+    /// Tags the entry with the given tag IDs
+    AddTags(Vec<Uuid>),
+    /// Hides the entry from entry listings
+    Hide,
+}
+
+impl From<RuleAction> for colette_crud::RuleAction {
+    fn from(value: RuleAction) -> Self {
+        match value {
+            RuleAction::MarkAsRead => Self::MarkAsRead,
+            RuleAction::Star => Self::Star,
+            RuleAction::AddTags(value) => Self::AddTags(value),
+            RuleAction::Hide => Self::Hide,
+        }
+    }
+}
+
+impl From<colette_crud::RuleAction> for RuleAction {
+    fn from(value: colette_crud::RuleAction) -> Self {
+        match value {
+            colette_crud::RuleAction::MarkAsRead => Self::MarkAsRead,
+            colette_crud::RuleAction::Star => Self::Star,
+            colette_crud::RuleAction::AddTags(value) => Self::AddTags(value),
+            colette_crud::RuleAction::Hide => Self::Hide,
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use axum::{
+    Json,
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use colette_crud::RuleError;
+use colette_handler::{Handler as _, RunRuleCommand, RunRuleError};
+
+use crate::api::{
+    ApiState,
+    common::{ApiError, Auth, Id, Path},
+    rule::RULES_TAG,
+};
+
+#[utoipa::path(
+    post,
+    path = "/{id}/run",
+    params(Id),
+    responses(OkResponse, ErrResponse),
+    operation_id = "runRule",
+    description = "Run a rule against all existing entries, whether or not it is enabled",
+    tag = RULES_TAG
+)]
+#[axum::debug_handler]
+pub(super) async fn handler(
+    State(state): State<ApiState>,
+    Path(Id(id)): Path<Id>,
+    Auth { user_id }: Auth,
+) -> Result<OkResponse, ErrResponse> {
+    match state
+        .run_rule
+        .handle(RunRuleCommand {
+            id: id.into(),
+            user_id,
+        })
+        .await
+    {
+        Ok(matched) => Ok(OkResponse(RuleRun { matched })),
+        Err(e) => match e {
+            RunRuleError::Rule(RuleError::NotFound(_)) => Err(ErrResponse::NotFound(e.into())),
+            _ => Err(ErrResponse::InternalServerError(e.into())),
+        },
+    }
+}
+
+/// Result of running a rule
+#[derive(Debug, Clone, serde::Serialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct RuleRun {
+    /// Number of entries that matched the rule
+    matched: u64,
+}
+
+#[derive(utoipa::IntoResponses)]
+#[response(status = StatusCode::OK, description = "Rule run result")]
+pub(super) struct OkResponse(RuleRun);
+
+impl IntoResponse for OkResponse {
+    fn into_response(self) -> Response {
+        (StatusCode::OK, Json(self.0)).into_response()
+    }
+}
+
+#[allow(dead_code)]
+#[derive(utoipa::IntoResponses)]
+pub(super) enum ErrResponse {
+    #[response(status = StatusCode::UNAUTHORIZED, description = "User not authenticated")]
+    Unauthorized(ApiError),
+
+    #[response(status = StatusCode::NOT_FOUND, description = "Rule not found")]
+    NotFound(ApiError),
+
+    #[response(status = "default", description = "Unknown error")]
+    InternalServerError(ApiError),
+}
+
+impl IntoResponse for ErrResponse {
+    fn into_response(self) -> Response {
+        match self {
+            Self::NotFound(e) => (StatusCode::NOT_FOUND, e).into_response(),
+            Self::InternalServerError(_) => {
+                (StatusCode::INTERNAL_SERVER_ERROR, ApiError::unknown()).into_response()
+            }
+            _ => unreachable!(),
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use axum::{
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use colette_crud::RuleError;
+use colette_handler::{Handler as _, UpdateRuleCommand, UpdateRuleError};
+
+use crate::api::{
+    ApiState,
+    collection::EntryFilter,
+    common::{ApiError, Auth, Id, Json, NonEmptyString, Path},
+    rule::{RULES_TAG, RuleAction},
+};
+
+#[utoipa::path(
+    patch,
+    path = "/{id}",
+    params(Id),
+    request_body = RuleUpdate,
+    responses(OkResponse, ErrResponse),
+    operation_id = "updateRule",
+    description = "Update a rule by ID",
+    tag = RULES_TAG
+)]
+#[axum::debug_handler]
+pub(super) async fn handler(
+    State(state): State<ApiState>,
+    Path(Id(id)): Path<Id>,
+    Auth { user_id }: Auth,
+    Json(body): Json<RuleUpdate>,
+) -> Result<OkResponse, ErrResponse> {
+    match state
+        .update_rule
+        .handle(UpdateRuleCommand {
+            id: id.into(),
+            title: body.title.map(Into::into),
+            condition: body.condition.map(Into::into),
+            actions: body
+                .actions
+                .map(|e| e.into_iter().map(Into::into).collect()),
+            enabled: body.enabled,
+            user_id,
+        })
+        .await
+    {
+        Ok(_) => Ok(OkResponse),
+        Err(e) => match e {
+            UpdateRuleError::Rule(RuleError::NotFound(_)) => Err(ErrResponse::NotFound(e.into())),
+            UpdateRuleError::Rule(RuleError::Conflict(_)) => Err(ErrResponse::Conflict(e.into())),
+            UpdateRuleError::Rule(
+                RuleError::InvalidTitleLength | RuleError::NoActions | RuleError::EmptyCondition,
+            ) => Err(ErrResponse::UnprocessableEntity(e.into())),
+            _ => Err(ErrResponse::InternalServerError(e.into())),
+        },
+    }
+}
+
+#[derive(Debug, Clone, serde::Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct RuleUpdate {
+    #[schema(value_type = Option<String>, min_length = 1, nullable = false)]
+    title: Option<NonEmptyString>,
+    #[schema(nullable = false)]
+    condition: Option<EntryFilter>,
+    #[schema(min_items = 1, nullable = false)]
+    actions: Option<Vec<RuleAction>>,
+    #[schema(nullable = false)]
+    enabled: Option<bool>,
+}
+
+#[derive(utoipa::IntoResponses)]
+#[response(status = StatusCode::NO_CONTENT, description = "Successfully updated rule")]
+pub(super) struct OkResponse;
+
+impl IntoResponse for OkResponse {
+    fn into_response(self) -> Response {
+        StatusCode::NO_CONTENT.into_response()
+    }
+}
+
+#[allow(dead_code)]
+#[derive(utoipa::IntoResponses)]
+pub(super) enum ErrResponse {
+    #[response(status = StatusCode::UNAUTHORIZED, description = "User not authenticated")]
+    Unauthorized(ApiError),
+
+    #[response(status = StatusCode::NOT_FOUND, description = "Rule not found")]
+    NotFound(ApiError),
+
+    #[response(status = StatusCode::CONFLICT, description = "Rule already exists")]
+    Conflict(ApiError),
+
+    #[response(status = StatusCode::UNPROCESSABLE_ENTITY, description = "Invalid input")]
+    UnprocessableEntity(ApiError),
+
+    #[response(status = "default", description = "Unknown error")]
+    InternalServerError(ApiError),
+}
+
+impl IntoResponse for ErrResponse {
+    fn into_response(self) -> Response {
+        match self {
+            Self::NotFound(e) => (StatusCode::NOT_FOUND, e).into_response(),
+            Self::Conflict(e) => (StatusCode::CONFLICT, e).into_response(),
+            Self::UnprocessableEntity(e) => (StatusCode::UNPROCESSABLE_ENTITY, e).into_response(),
+            Self::InternalServerError(_) => {
+                (StatusCode::INTERNAL_SERVER_ERROR, ApiError::unknown()).into_response()
+            }
+            _ => unreachable!(),
+        }
+    }
+}
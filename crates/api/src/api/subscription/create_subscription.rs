@@ -34,6 +34,7 @@ pub(super) async fn handler(
             title: body.title.into(),
             description: body.description.map(Into::into),
             feed_id: body.feed_id.into(),
+            fetch_full_article: body.fetch_full_article,
             user_id,
         })
         .await
@@ -62,6 +63,9 @@ pub(super) struct SubscriptionCreate {
     description: Option<NonEmptyString>,
     /// Unique identifier of the associated RSS feed
     feed_id: Uuid,
+    /// Whether to download the full article of each entry, for feeds that only publish a teaser
+    #[serde(default)]
+    fetch_full_article: bool,
 }
 
 #[derive(utoipa::IntoResponses)]
@@ -9,7 +9,7 @@ use uuid::Uuid;
 
 use crate::api::{
     ApiState,
-    common::{ApiError, Auth, Query},
+    common::{ApiError, Auth, ListInclude, Query},
     pagination::{PAGINATION_LIMIT, Paginated, decode_cursor},
     subscription::{SUBSCRIPTIONS_TAG, Subscription},
 };
@@ -45,6 +45,7 @@ pub(super) async fn handler(
             },
             cursor,
             limit: Some(PAGINATION_LIMIT),
+            with_counts: query.include.contains(&ListInclude::Counts),
             user_id: user_id.as_inner(),
         })
         .await
@@ -74,6 +75,9 @@ pub(super) struct SubscriptionListQuery {
     /// Pagination cursor
     #[param(nullable = false)]
     cursor: Option<String>,
+    /// Optional data to include with each item
+    #[serde(default)]
+    include: Vec<ListInclude>,
 }
 
 #[derive(utoipa::IntoResponses)]
@@ -5,7 +5,7 @@ use url::Url;
 use utoipa::OpenApi;
 use uuid::Uuid;
 
-use crate::api::{ApiState, pagination::Paginated, tag::Tag};
+use crate::api::{ApiState, entry::EntryCounts, pagination::Paginated, tag::Tag};
 
 mod create_subscription;
 mod delete_subscription;
@@ -14,14 +14,15 @@ mod get_subscription;
 mod import_subscriptions;
 mod link_subscription_tags;
 mod list_subscriptions;
+mod refresh_subscription;
 mod update_subscription;
 
 const SUBSCRIPTIONS_TAG: &str = "Subscriptions";
 
 #[derive(OpenApi)]
 #[openapi(
-    components(schemas(Subscription, Paginated<Subscription>, create_subscription::SubscriptionCreate, update_subscription::SubscriptionUpdate, link_subscription_tags::LinkSubscriptionTags)),
-    paths(list_subscriptions::handler, create_subscription::handler, get_subscription::handler, update_subscription::handler, delete_subscription::handler, link_subscription_tags::handler, import_subscriptions::handler, export_subscriptions::handler)
+    components(schemas(Subscription, Paginated<Subscription>, FeedHealth, FeedStatus, create_subscription::SubscriptionCreate, update_subscription::SubscriptionUpdate, link_subscription_tags::LinkSubscriptionTags)),
+    paths(list_subscriptions::handler, create_subscription::handler, get_subscription::handler, update_subscription::handler, delete_subscription::handler, link_subscription_tags::handler, refresh_subscription::handler, import_subscriptions::handler, export_subscriptions::handler)
 )]
 pub(crate) struct SubscriptionApi;
 
@@ -37,6 +38,10 @@ impl SubscriptionApi {
                 "/{id}/linkTags",
                 routing::post(link_subscription_tags::handler),
             )
+            .route(
+                "/{id}/refresh",
+                routing::post(refresh_subscription::handler),
+            )
             .route("/import", routing::post(import_subscriptions::handler))
             .route("/export", routing::post(export_subscriptions::handler))
     }
@@ -57,10 +62,18 @@ struct Subscription {
     /// Description of the subscription
     #[schema(required)]
     description: Option<String>,
+    /// Whether the full article of each entry is downloaded from its link
+    fetch_full_article: bool,
+    /// Health of the associated feed
+    feed_health: FeedHealth,
     /// Linked tags
     tags: Vec<Tag>,
     /// Count of unread entries associated with the subscription
     unread_count: i64,
+    /// Entry counts of the subscription, only present if requested
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    entry_counts: Option<EntryCounts>,
     /// Timestamp at which the subscription was created
     created_at: DateTime<Utc>,
     /// Timestamp at which the subscription was modified
@@ -75,10 +88,52 @@ impl From<SubscriptionDto> for Subscription {
             link: value.link,
             title: value.title,
             description: value.description,
+            fetch_full_article: value.fetch_full_article,
+            feed_health: FeedHealth {
+                status: value.feed_status.into(),
+                failure_count: value.feed_failure_count,
+                last_error: value.feed_last_error,
+            },
             tags: value.tags.into_iter().map(Into::into).collect(),
             unread_count: value.unread_count,
+            entry_counts: value.entry_counts.map(Into::into),
             created_at: value.created_at,
             updated_at: value.updated_at,
         }
     }
 }
+
+/// Health of a feed, based on its recent refreshes
+#[derive(Debug, Clone, serde::Serialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+struct FeedHealth {
+    /// Refresh status of the feed
+    status: FeedStatus,
+    /// Number of consecutive failed refreshes
+    failure_count: u32,
+    /// Error message of the latest failed refresh
+    #[schema(required)]
+    last_error: Option<String>,
+}
+
+/// Refresh status of a feed. Failing feeds are refreshed with exponential backoff, and disabled
+/// feeds are no longer refreshed automatically until a manual refresh succeeds.
+#[derive(Debug, Clone, serde::Serialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+enum FeedStatus {
+    Healthy,
+    Refreshing,
+    Failing,
+    Disabled,
+}
+
+impl From<colette_ingestion::FeedStatus> for FeedStatus {
+    fn from(value: colette_ingestion::FeedStatus) -> Self {
+        match value {
+            colette_ingestion::FeedStatus::Healthy => Self::Healthy,
+            colette_ingestion::FeedStatus::Refreshing => Self::Refreshing,
+            colette_ingestion::FeedStatus::Failing => Self::Failing,
+            colette_ingestion::FeedStatus::Disabled => Self::Disabled,
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use axum::{
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use colette_crud::SubscriptionError;
+use colette_handler::{Handler as _, RefreshSubscriptionCommand, RefreshSubscriptionError};
+
+use crate::api::{
+    ApiState,
+    common::{ApiError, Auth, Id, Path},
+    subscription::SUBSCRIPTIONS_TAG,
+};
+
+#[utoipa::path(
+    post,
+    path = "/{id}/refresh",
+    params(Id),
+    responses(OkResponse, ErrResponse),
+    operation_id = "refreshSubscription",
+    description = "Queue the feed of a subscription for an immediate refresh. A successful refresh re-enables a disabled feed.",
+    tag = SUBSCRIPTIONS_TAG
+)]
+#[axum::debug_handler]
+pub(super) async fn handler(
+    State(state): State<ApiState>,
+    Path(Id(id)): Path<Id>,
+    Auth { user_id }: Auth,
+) -> Result<OkResponse, ErrResponse> {
+    match state
+        .refresh_subscription
+        .handle(RefreshSubscriptionCommand {
+            id: id.into(),
+            user_id,
+        })
+        .await
+    {
+        Ok(()) => Ok(OkResponse),
+        Err(e) => match e {
+            RefreshSubscriptionError::Subscription(SubscriptionError::NotFound(_)) => {
+                Err(ErrResponse::NotFound(e.into()))
+            }
+            _ => Err(ErrResponse::InternalServerError(e.into())),
+        },
+    }
+}
+
+#[derive(utoipa::IntoResponses)]
+#[response(status = StatusCode::ACCEPTED, description = "Successfully queued feed refresh")]
+pub(super) struct OkResponse;
+
+impl IntoResponse for OkResponse {
+    fn into_response(self) -> Response {
+        StatusCode::ACCEPTED.into_response()
+    }
+}
+
+#[allow(dead_code)]
+#[derive(utoipa::IntoResponses)]
+pub(super) enum ErrResponse {
+    #[response(status = StatusCode::UNAUTHORIZED, description = "User not authenticated")]
+    Unauthorized(ApiError),
+
+    #[response(status = StatusCode::NOT_FOUND, description = "Subscription not found")]
+    NotFound(ApiError),
+
+    #[response(status = "default", description = "Unknown error")]
+    InternalServerError(ApiError),
+}
+
+impl IntoResponse for ErrResponse {
+    fn into_response(self) -> Response {
+        match self {
+            Self::NotFound(e) => (StatusCode::NOT_FOUND, e).into_response(),
+            Self::InternalServerError(_) => {
+                (StatusCode::INTERNAL_SERVER_ERROR, ApiError::unknown()).into_response()
+            }
+            _ => unreachable!(),
+        }
+    }
+}
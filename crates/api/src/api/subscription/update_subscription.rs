@@ -35,6 +35,7 @@ pub(super) async fn handler(
             id: id.into(),
             title: body.title.map(Into::into),
             description: body.description.map(|e| e.map(Into::into)),
+            fetch_full_article: body.fetch_full_article,
             user_id,
         })
         .await
@@ -60,6 +61,8 @@ pub(super) struct SubscriptionUpdate {
     #[serde(default, with = "serde_with::rust::double_option")]
     #[schema(value_type = Option<Option<String>>, min_length = 1)]
     description: Option<Option<NonEmptyString>>,
+    /// Whether to download the full article of each entry, for feeds that only publish a teaser
+    fetch_full_article: Option<bool>,
 }
 
 #[derive(utoipa::IntoResponses)]
@@ -8,7 +8,7 @@ use colette_handler::{Handler as _, ListTagsQuery, TagCursor};
 
 use crate::api::{
     ApiState,
-    common::{ApiError, Auth, Query},
+    common::{ApiError, Auth, ListInclude, Query},
     pagination::{PAGINATION_LIMIT, Paginated, decode_cursor},
     tag::{TAGS_TAG, Tag},
 };
@@ -39,6 +39,7 @@ pub(super) async fn handler(
         .handle(ListTagsQuery {
             cursor,
             limit: Some(PAGINATION_LIMIT),
+            with_counts: query.include.contains(&ListInclude::Counts),
             user_id: user_id.as_inner(),
         })
         .await
@@ -59,6 +60,9 @@ pub(super) struct TagListQuery {
     /// Pagination cursor
     #[param(nullable = false)]
     cursor: Option<String>,
+    /// Optional data to include with each item
+    #[serde(default)]
+    include: Vec<ListInclude>,
 }
 
 #[derive(utoipa::IntoResponses)]
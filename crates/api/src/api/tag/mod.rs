@@ -4,7 +4,7 @@ use colette_handler::TagDto;
 use utoipa::OpenApi;
 use uuid::Uuid;
 
-use crate::api::{ApiState, pagination::Paginated};
+use crate::api::{ApiState, entry::EntryCounts, pagination::Paginated};
 
 mod create_tag;
 mod delete_tag;
@@ -40,6 +40,10 @@ pub(crate) struct Tag {
     id: Uuid,
     /// Human-readable name of the tag, unique per user
     title: String,
+    /// Entry counts of the subscriptions and entries linked to the tag, only present if requested
+    #[schema(nullable = false)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    entry_counts: Option<EntryCounts>,
     /// Timestamp at which the tag was created
     created_at: DateTime<Utc>,
     /// Timestamp at which the tag was last modified
@@ -51,6 +55,7 @@ impl From<TagDto> for Tag {
         Self {
             id: value.id,
             title: value.title,
+            entry_counts: value.entry_counts.map(Into::into),
             created_at: value.created_at,
             updated_at: value.updated_at,
         }
@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use config::{Config, Environment, FileFormat};
 use url::Url;
 
@@ -104,6 +106,8 @@ pub async fn from_env() -> Result<AppConfig, Box<dyn std::error::Error>> {
         smtp: raw.smtp,
         s3,
         oidc,
+        http: raw.http,
+        plugins: raw.plugins,
     })
 }
 
@@ -116,6 +120,8 @@ pub struct AppConfig {
     pub smtp: SmtpConfig,
     pub s3: S3Config,
     pub oidc: Option<OidcConfig>,
+    pub http: HttpConfig,
+    pub plugins: PluginsConfig,
 }
 
 #[derive(Debug, Clone, serde::Deserialize)]
@@ -172,6 +178,33 @@ pub struct OidcConfig {
     pub sign_in_text: String,
 }
 
+/// Outgoing request politeness. The interval is in milliseconds, and the retry delay in seconds.
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct HttpConfig {
+    pub user_agent: String,
+    pub max_concurrency_per_host: usize,
+    pub min_request_interval: u64,
+    pub max_retry_delay: u64,
+    pub respect_robots_txt: bool,
+    pub cache: HttpCacheConfig,
+}
+
+/// In-memory response cache, optionally persisted to a directory. The TTL, used for responses
+/// without caching headers, is in seconds.
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct HttpCacheConfig {
+    pub capacity: usize,
+    pub max_body_size: usize,
+    pub default_ttl: u64,
+    pub dir: Option<PathBuf>,
+}
+
+/// Directory of declarative scraper plugin definitions, in TOML or JSON
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+pub struct PluginsConfig {
+    pub dir: Option<PathBuf>,
+}
+
 #[derive(Debug, Clone, serde::Deserialize)]
 struct RawConfig {
     server: ServerConfig,
@@ -182,6 +215,9 @@ struct RawConfig {
     smtp: SmtpConfig,
     s3: RawS3Config,
     oidc: Option<RawOidcConfig>,
+    http: HttpConfig,
+    #[serde(default)]
+    plugins: PluginsConfig,
 }
 
 #[derive(Debug, Clone, serde::Deserialize)]
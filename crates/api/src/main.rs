@@ -4,11 +4,11 @@ use api::{ApiConfig, ApiOidcConfig, ApiS3Config, ApiServerConfig, ApiState};
 use chrono::Duration;
 use colette_crypto::OtpCodeGenerator;
 use colette_handler::*;
-use colette_http::ReqwestClient;
+use colette_http::{CacheOptions, CachingClient, PoliteClient, PolitenessOptions, ReqwestClient};
 use colette_jwt::JwtManagerImpl;
 use colette_oidc::OidcClientImpl;
-use colette_plugins::{register_bookmark_plugins, register_feed_plugins};
-use colette_queue::TokioQueue;
+use colette_plugins::{DeclarativePlugins, register_bookmark_plugins, register_feed_plugins};
+use colette_queue::{PostgresJobProducer, PostgresJobStore};
 use colette_repository::*;
 use colette_s3::S3ClientImpl;
 use colette_scraper::{bookmark::BookmarkScraper, feed::FeedScraper};
@@ -47,12 +47,39 @@ async fn main() -> Result<(), Box<dyn Error>> {
     let bookmark_repository = PostgresBookmarkRepository::new(pool.clone());
     let collection_repository = PostgresCollectionRepository::new(pool.clone());
     let pat_repository = PostgresPatRepository::new(pool.clone());
+    let rule_repository = PostgresRuleRepository::new(pool.clone());
     let subscription_repository = PostgresSubscriptionRepository::new(pool.clone());
     let entry_repository = PostgresEntryRepository::new(pool.clone());
     let tag_repository = PostgresTagRepository::new(pool.clone());
 
-    let reqwest_client = reqwest::Client::builder().build()?;
-    let http_client = ReqwestClient::new(reqwest_client.clone());
+    let reqwest_client = reqwest::Client::builder()
+        .user_agent(app_config.http.user_agent.clone())
+        .build()?;
+    let http_client = CachingClient::new(
+        PoliteClient::new(
+            ReqwestClient::new(reqwest_client.clone()),
+            PolitenessOptions {
+                user_agent: app_config.http.user_agent,
+                max_concurrency_per_host: app_config.http.max_concurrency_per_host,
+                min_request_interval: std::time::Duration::from_millis(
+                    app_config.http.min_request_interval,
+                ),
+                max_retry_delay: std::time::Duration::from_secs(app_config.http.max_retry_delay),
+                respect_robots_txt: app_config.http.respect_robots_txt,
+            },
+        )?,
+        CacheOptions {
+            capacity: app_config.http.cache.capacity,
+            max_body_size: app_config.http.cache.max_body_size,
+            default_ttl: std::time::Duration::from_secs(app_config.http.cache.default_ttl),
+            dir: app_config.http.cache.dir,
+        },
+    );
+
+    let declarative_plugins = match app_config.plugins.dir {
+        Some(ref dir) => DeclarativePlugins::load(dir)?,
+        None => DeclarativePlugins::default(),
+    };
 
     let jwt_config = JwtConfig {
         secret: app_config.jwt.secret.into_bytes(),
@@ -101,14 +128,12 @@ async fn main() -> Result<(), Box<dyn Error>> {
     })
     .await?;
 
-    let (scrape_feed_producer, scrape_feed_consumer) = TokioQueue::new().split();
-    let (scrape_bookmark_producer, scrape_bookmark_consumer) = TokioQueue::new().split();
-    let (archive_thumbnail_producer, archive_thumbnail_consumer) = TokioQueue::new().split();
-    let (import_bookmarks_producer, import_bookmarks_consumer) = TokioQueue::new().split();
+    let job_producer = PostgresJobProducer::new(pool.clone());
+    let job_store = PostgresJobStore::new(pool.clone());
 
     let bookmark_scraper = Arc::new(BookmarkScraper::new(
         http_client.clone(),
-        register_bookmark_plugins(reqwest_client.clone()),
+        register_bookmark_plugins(http_client.clone(), &declarative_plugins),
     ));
 
     let feed_repository = PostgresFeedRepository::new(pool.clone());
@@ -116,7 +141,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
 
     let feed_scraper = Arc::new(FeedScraper::new(
         http_client.clone(),
-        register_feed_plugins(reqwest_client),
+        register_feed_plugins(http_client.clone(), &declarative_plugins),
     ));
 
     let list_bookmarks_handler = Arc::new(ListBookmarksHandler::new(
@@ -163,6 +188,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
         ))),
         export_backup: Arc::new(ExportBackupHandler::new(
             bookmark_repository.clone(),
+            entry_repository.clone(),
             subscription_repository.clone(),
             tag_repository.clone(),
         )),
@@ -172,28 +198,31 @@ async fn main() -> Result<(), Box<dyn Error>> {
         get_bookmark: Arc::new(GetBookmarkHandler::new(bookmark_repository.clone())),
         create_bookmark: Arc::new(CreateBookmarkHandler::new(
             bookmark_repository.clone(),
-            archive_thumbnail_producer.clone(),
+            job_producer.clone(),
         )),
         update_bookmark: Arc::new(UpdateBookmarkHandler::new(
             bookmark_repository.clone(),
-            archive_thumbnail_producer.clone(),
+            job_producer.clone(),
         )),
         delete_bookmark: Arc::new(DeleteBookmarkHandler::new(
             bookmark_repository.clone(),
-            archive_thumbnail_producer,
+            job_producer.clone(),
         )),
         scrape_bookmark: Arc::new(ScrapeBookmarkHandler::new(bookmark_scraper)),
         refresh_bookmark: refresh_bookmark_handler.clone(),
         link_bookmark_tags: Arc::new(LinkBookmarkTagsHandler::new(bookmark_repository.clone())),
         import_bookmarks: Arc::new(ImportBookmarksHandler::new(
             bookmark_repository.clone(),
-            import_bookmarks_producer,
+            job_producer.clone(),
         )),
         export_bookmarks: Arc::new(ExportBookmarksHandler::new(bookmark_repository)),
         archive_thumbnail: archive_thumbnail_handler.clone(),
 
         // Collections
-        list_collections: Arc::new(ListCollectionsHandler::new(collection_repository.clone())),
+        list_collections: Arc::new(ListCollectionsHandler::new(
+            collection_repository.clone(),
+            entry_repository.clone(),
+        )),
         get_collection: Arc::new(GetCollectionHandler::new(collection_repository.clone())),
         create_collection: Arc::new(CreateCollectionHandler::new(collection_repository.clone())),
         update_collection: Arc::new(UpdateCollectionHandler::new(collection_repository.clone())),
@@ -202,11 +231,18 @@ async fn main() -> Result<(), Box<dyn Error>> {
         // Entries
         list_entries: Arc::new(ListEntriesHandler::new(
             entry_repository.clone(),
-            collection_repository,
+            collection_repository.clone(),
         )),
         get_entry: Arc::new(GetEntryHandler::new(entry_repository.clone())),
         mark_entry_as_read: Arc::new(MarkEntryAsReadHandler::new(entry_repository.clone())),
-        mark_entry_as_unread: Arc::new(MarkEntryAsUnreadHandler::new(entry_repository)),
+        mark_entry_as_unread: Arc::new(MarkEntryAsUnreadHandler::new(entry_repository.clone())),
+        mark_entries: Arc::new(MarkEntriesHandler::new(
+            entry_repository.clone(),
+            collection_repository,
+        )),
+        star_entry: Arc::new(StarEntryHandler::new(entry_repository.clone())),
+        unstar_entry: Arc::new(UnstarEntryHandler::new(entry_repository.clone())),
+        update_entry: Arc::new(UpdateEntryHandler::new(entry_repository)),
 
         // Feeds
         detect_feeds: Arc::new(DetectFeedsHandler::new(http_client, feed_scraper.clone())),
@@ -214,6 +250,17 @@ async fn main() -> Result<(), Box<dyn Error>> {
             feed_repository.clone(),
             feed_scraper.clone(),
         )),
+        verify_websub: Arc::new(VerifyWebSubHandler::new(feed_repository.clone())),
+        receive_websub: Arc::new(ReceiveWebSubHandler::new(
+            feed_repository.clone(),
+            rule_repository.clone(),
+        )),
+
+        // Jobs
+        list_jobs: Arc::new(ListJobsHandler::new(job_store.clone())),
+        get_job: Arc::new(GetJobHandler::new(job_store.clone())),
+        retry_job: Arc::new(RetryJobHandler::new(job_store.clone())),
+        cancel_job: Arc::new(CancelJobHandler::new(job_store)),
 
         // Personal access tokens
         list_pats: Arc::new(ListPatsHandler::new(pat_repository.clone())),
@@ -223,6 +270,14 @@ async fn main() -> Result<(), Box<dyn Error>> {
         delete_pat: Arc::new(DeletePatHandler::new(pat_repository.clone())),
         validate_pat: Arc::new(ValidatePatHandler::new(pat_repository)),
 
+        // Rules
+        list_rules: Arc::new(ListRulesHandler::new(rule_repository.clone())),
+        get_rule: Arc::new(GetRuleHandler::new(rule_repository.clone())),
+        create_rule: Arc::new(CreateRuleHandler::new(rule_repository.clone())),
+        update_rule: Arc::new(UpdateRuleHandler::new(rule_repository.clone())),
+        delete_rule: Arc::new(DeleteRuleHandler::new(rule_repository.clone())),
+        run_rule: Arc::new(RunRuleHandler::new(rule_repository)),
+
         // Subscriptions
         list_subscriptions: Arc::new(ListSubscriptionsHandler::new(
             subscription_repository.clone(),
@@ -240,6 +295,10 @@ async fn main() -> Result<(), Box<dyn Error>> {
         link_subscription_tags: Arc::new(LinkSubscriptionTagsHandler::new(
             subscription_repository.clone(),
         )),
+        refresh_subscription: Arc::new(RefreshSubscriptionHandler::new(
+            subscription_repository.clone(),
+            job_producer,
+        )),
         import_subscriptions: Arc::new(ImportSubscriptionsHandler::new(
             subscription_repository.clone(),
         )),
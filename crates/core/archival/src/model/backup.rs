@@ -37,10 +37,26 @@ pub struct BackupSubscription {
     pub description: Option<String>,
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub tags: Vec<BackupTag>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub entries: Vec<BackupEntry>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
+/// Entry of a subscription that is starred or has a note, identified by its link since entry IDs
+/// aren't stable across instances. The title and publish date are kept so that the entry can be
+/// recreated if the feed no longer contains it.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct BackupEntry {
+    pub link: Url,
+    pub title: String,
+    pub published_at: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starred_at: Option<DateTime<Utc>>,
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct BackupTag {
     pub id: Uuid,
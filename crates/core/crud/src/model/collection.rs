@@ -12,19 +12,26 @@ pub struct Collection {
     id: CollectionId,
     title: CollectionTitle,
     filter: BookmarkFilter,
+    entry_filter: Option<EntryFilter>,
     user_id: UserId,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
 }
 
 impl Collection {
-    pub fn new(title: CollectionTitle, filter: BookmarkFilter, user_id: UserId) -> Self {
+    pub fn new(
+        title: CollectionTitle,
+        filter: BookmarkFilter,
+        entry_filter: Option<EntryFilter>,
+        user_id: UserId,
+    ) -> Self {
         let now = Utc::now();
 
         Self {
             id: uuid_generate_ts(now).into(),
             title,
             filter,
+            entry_filter,
             user_id,
             created_at: now,
             updated_at: now,
@@ -57,6 +64,24 @@ impl Collection {
         }
     }
 
+    pub fn entry_filter(&self) -> Option<&EntryFilter> {
+        self.entry_filter.as_ref()
+    }
+
+    pub fn set_entry_filter(&mut self, value: EntryFilter) {
+        if self.entry_filter.as_ref().is_none_or(|e| &value != e) {
+            self.entry_filter = Some(value);
+            self.updated_at = Utc::now();
+        }
+    }
+
+    pub fn remove_entry_filter(&mut self) {
+        if self.entry_filter.is_some() {
+            self.entry_filter = None;
+            self.updated_at = Utc::now();
+        }
+    }
+
     pub fn user_id(&self) -> UserId {
         self.user_id
     }
@@ -74,6 +99,7 @@ impl Collection {
         id: Uuid,
         title: String,
         filter: BookmarkFilter,
+        entry_filter: Option<EntryFilter>,
         user_id: Uuid,
         created_at: DateTime<Utc>,
         updated_at: DateTime<Utc>,
@@ -82,6 +108,7 @@ impl Collection {
             id: CollectionId(id),
             title: CollectionTitle(title),
             filter,
+            entry_filter,
             user_id: user_id.into(),
             created_at,
             updated_at,
@@ -208,7 +235,7 @@ pub enum BookmarkDateField {
     UpdatedAt,
 }
 
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum EntryFilter {
     Text {
@@ -233,7 +260,20 @@ pub enum EntryFilter {
     Not(Box<EntryFilter>),
 }
 
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+impl EntryFilter {
+    /// Whether the filter contains an `And` or `Or` group without any conditions
+    pub fn has_empty_group(&self) -> bool {
+        match self {
+            Self::And(filters) | Self::Or(filters) => {
+                filters.is_empty() || filters.iter().any(Self::has_empty_group)
+            }
+            Self::Not(filter) => filter.has_empty_group(),
+            _ => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum EntryTextField {
     Link,
@@ -241,22 +281,27 @@ pub enum EntryTextField {
     Description,
     Author,
     Tag,
+    SubscriptionTitle,
+    FeedHost,
 }
 
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum EntryNumberField {}
 
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum EntryBooleanField {
     HasRead,
+    Starred,
+    HasNote,
 }
 
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum EntryDateField {
     PublishedAt,
+    ReadAt,
 }
 
 #[derive(Debug, thiserror::Error)]
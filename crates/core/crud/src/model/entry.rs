@@ -6,6 +6,8 @@ use uuid::Uuid;
 pub struct Entry {
     id: EntryId,
     read_status: ReadStatus,
+    star_status: StarStatus,
+    note: Option<String>,
     user_id: UserId,
 }
 
@@ -40,14 +42,60 @@ impl Entry {
         }
     }
 
+    pub fn star_status(&self) -> &StarStatus {
+        &self.star_status
+    }
+
+    pub fn star(&mut self) -> Result<(), EntryError> {
+        match self.star_status {
+            StarStatus::Unstarred => {
+                self.star_status = StarStatus::Starred(Utc::now());
+
+                Ok(())
+            }
+            StarStatus::Starred(_) => Err(EntryError::AlreadyStarred(self.id.0)),
+        }
+    }
+
+    pub fn unstar(&mut self) -> Result<(), EntryError> {
+        match self.star_status {
+            StarStatus::Unstarred => Err(EntryError::AlreadyUnstarred(self.id.0)),
+            StarStatus::Starred(_) => {
+                self.star_status = StarStatus::Unstarred;
+
+                Ok(())
+            }
+        }
+    }
+
+    pub fn note(&self) -> Option<&str> {
+        self.note.as_deref()
+    }
+
+    pub fn set_note(&mut self, value: String) {
+        self.note = Some(value);
+    }
+
+    pub fn remove_note(&mut self) {
+        self.note = None;
+    }
+
     pub fn user_id(&self) -> UserId {
         self.user_id
     }
 
-    pub fn from_unchecked(id: Uuid, read_status: ReadStatus, user_id: Uuid) -> Self {
+    pub fn from_unchecked(
+        id: Uuid,
+        read_status: ReadStatus,
+        star_status: StarStatus,
+        note: Option<String>,
+        user_id: Uuid,
+    ) -> Self {
         Self {
             id: EntryId(id),
             read_status,
+            star_status,
+            note,
             user_id: user_id.into(),
         }
     }
@@ -78,6 +126,12 @@ pub enum ReadStatus {
     Read(DateTime<Utc>),
 }
 
+#[derive(Debug, Clone)]
+pub enum StarStatus {
+    Unstarred,
+    Starred(DateTime<Utc>),
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum EntryError {
     #[error("entry not found with ID: {0}")]
@@ -88,4 +142,10 @@ pub enum EntryError {
 
     #[error("entry {0} already marked as unread")]
     AlreadyUnread(Uuid),
+
+    #[error("entry {0} already starred")]
+    AlreadyStarred(Uuid),
+
+    #[error("entry {0} already unstarred")]
+    AlreadyUnstarred(Uuid),
 }
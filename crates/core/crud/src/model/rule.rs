@@ -0,0 +1,265 @@
+use chrono::{DateTime, Utc};
+use colette_authentication::UserId;
+use colette_common::uuid_generate_ts;
+use uuid::Uuid;
+
+use crate::EntryFilter;
+
+pub const RULE_TITLE_MAX_LENGTH: usize = 50;
+
+#[derive(Debug, Clone)]
+pub struct Rule {
+    id: RuleId,
+    title: RuleTitle,
+    condition: RuleCondition,
+    actions: RuleActions,
+    enabled: bool,
+    user_id: UserId,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl Rule {
+    pub fn new(
+        title: RuleTitle,
+        condition: RuleCondition,
+        actions: RuleActions,
+        enabled: bool,
+        user_id: UserId,
+    ) -> Self {
+        let now = Utc::now();
+
+        Self {
+            id: uuid_generate_ts(now).into(),
+            title,
+            condition,
+            actions,
+            enabled,
+            user_id,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn id(&self) -> RuleId {
+        self.id
+    }
+
+    pub fn title(&self) -> &RuleTitle {
+        &self.title
+    }
+
+    pub fn set_title(&mut self, value: RuleTitle) {
+        if value != self.title {
+            self.title = value;
+            self.updated_at = Utc::now();
+        }
+    }
+
+    pub fn condition(&self) -> &RuleCondition {
+        &self.condition
+    }
+
+    pub fn set_condition(&mut self, value: RuleCondition) {
+        if value != self.condition {
+            self.condition = value;
+            self.updated_at = Utc::now();
+        }
+    }
+
+    pub fn actions(&self) -> &RuleActions {
+        &self.actions
+    }
+
+    pub fn set_actions(&mut self, value: RuleActions) {
+        if value != self.actions {
+            self.actions = value;
+            self.updated_at = Utc::now();
+        }
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn set_enabled(&mut self, value: bool) {
+        if value != self.enabled {
+            self.enabled = value;
+            self.updated_at = Utc::now();
+        }
+    }
+
+    pub fn user_id(&self) -> UserId {
+        self.user_id
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_unchecked(
+        id: Uuid,
+        title: String,
+        condition: EntryFilter,
+        actions: Vec<RuleAction>,
+        enabled: bool,
+        user_id: Uuid,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id: RuleId(id),
+            title: RuleTitle(title),
+            condition: RuleCondition(condition),
+            actions: RuleActions(actions),
+            enabled,
+            user_id: user_id.into(),
+            created_at,
+            updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RuleId(Uuid);
+
+impl RuleId {
+    pub fn new(id: Uuid) -> Self {
+        Into::into(id)
+    }
+
+    pub fn as_inner(&self) -> Uuid {
+        self.0
+    }
+}
+
+impl From<Uuid> for RuleId {
+    fn from(value: Uuid) -> Self {
+        Self(value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleTitle(String);
+
+impl RuleTitle {
+    pub fn new(value: String) -> Result<Self, RuleError> {
+        if value.is_empty() || value.len() > RULE_TITLE_MAX_LENGTH {
+            return Err(RuleError::InvalidTitleLength);
+        }
+
+        Ok(Self(value))
+    }
+
+    pub fn as_inner(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Condition an entry must match for the actions of a rule to apply. Empty `And`/`Or` groups are
+/// rejected, since they would silently match every entry or none.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RuleCondition(EntryFilter);
+
+impl RuleCondition {
+    pub fn new(value: EntryFilter) -> Result<Self, RuleError> {
+        if value.has_empty_group() {
+            return Err(RuleError::EmptyCondition);
+        }
+
+        Ok(Self(value))
+    }
+
+    pub fn as_inner(&self) -> &EntryFilter {
+        &self.0
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleActions(Vec<RuleAction>);
+
+impl RuleActions {
+    pub fn new(value: Vec<RuleAction>) -> Result<Self, RuleError> {
+        if value.is_empty() {
+            return Err(RuleError::NoActions);
+        }
+
+        Ok(Self(value))
+    }
+
+    pub fn as_inner(&self) -> &[RuleAction] {
+        &self.0
+    }
+}
+
+/// Applied to each entry matching the condition of a rule
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RuleAction {
+    MarkAsRead,
+    Star,
+    AddTags(Vec<Uuid>),
+    Hide,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum RuleError {
+    #[error("title must be between 1 and {RULE_TITLE_MAX_LENGTH} characters long")]
+    InvalidTitleLength,
+
+    #[error("rule must have at least one action")]
+    NoActions,
+
+    #[error("rule condition must not contain empty groups")]
+    EmptyCondition,
+
+    #[error("rule already exists with title: {0}")]
+    Conflict(String),
+
+    #[error("rule not found with ID: {0}")]
+    NotFound(Uuid),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BooleanOp, EntryBooleanField};
+
+    fn starred() -> EntryFilter {
+        EntryFilter::Boolean {
+            field: EntryBooleanField::Starred,
+            op: BooleanOp::Equals(true),
+        }
+    }
+
+    #[test]
+    fn accepts_non_empty_conditions() {
+        assert!(RuleCondition::new(starred()).is_ok());
+        assert!(
+            RuleCondition::new(EntryFilter::Or(vec![
+                starred(),
+                EntryFilter::Not(Box::new(starred())),
+            ]))
+            .is_ok()
+        );
+    }
+
+    #[test]
+    fn rejects_empty_groups() {
+        for condition in [
+            EntryFilter::And(Vec::new()),
+            EntryFilter::Or(vec![starred(), EntryFilter::And(Vec::new())]),
+            EntryFilter::Not(Box::new(EntryFilter::Or(Vec::new()))),
+        ] {
+            assert!(matches!(
+                RuleCondition::new(condition),
+                Err(RuleError::EmptyCondition)
+            ));
+        }
+    }
+}
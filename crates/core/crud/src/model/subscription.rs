@@ -16,6 +16,7 @@ pub struct Subscription {
     title: SubscriptionTitle,
     description: Option<SubscriptionDescription>,
     feed_id: FeedId,
+    fetch_full_article: bool,
     tags: Vec<TagId>,
     user_id: UserId,
     created_at: DateTime<Utc>,
@@ -36,6 +37,7 @@ impl Subscription {
             title,
             description,
             feed_id,
+            fetch_full_article: false,
             tags: Vec::new(),
             user_id,
             created_at: now,
@@ -80,6 +82,19 @@ impl Subscription {
         self.feed_id
     }
 
+    /// Whether the full article of each entry is downloaded from its link, for feeds that only
+    /// publish a teaser
+    pub fn fetch_full_article(&self) -> bool {
+        self.fetch_full_article
+    }
+
+    pub fn set_fetch_full_article(&mut self, value: bool) {
+        if value != self.fetch_full_article {
+            self.fetch_full_article = value;
+            self.updated_at = Utc::now();
+        }
+    }
+
     pub fn tags(&self) -> &[TagId] {
         &self.tags
     }
@@ -112,6 +127,7 @@ impl Subscription {
         title: String,
         description: Option<String>,
         feed_id: Uuid,
+        fetch_full_article: bool,
         tags: Vec<Uuid>,
         user_id: Uuid,
         created_at: DateTime<Utc>,
@@ -122,6 +138,7 @@ impl Subscription {
             title: SubscriptionTitle(title),
             description: description.map(SubscriptionDescription),
             feed_id: feed_id.into(),
+            fetch_full_article,
             tags: tags.into_iter().map(Into::into).collect(),
             user_id: user_id.into(),
             created_at,
@@ -1,7 +1,9 @@
+use chrono::{DateTime, Utc};
 use colette_authentication::UserId;
 use colette_common::RepositoryError;
+use uuid::Uuid;
 
-use crate::{Entry, EntryId};
+use crate::{Entry, EntryFilter, EntryId};
 
 pub trait EntryRepository: Sync {
     fn find_by_id(
@@ -14,4 +16,28 @@ pub trait EntryRepository: Sync {
         &self,
         data: &Entry,
     ) -> impl std::future::Future<Output = Result<(), RepositoryError>> + Send;
+
+    /// Marks all entries matching the selectors as read in a single statement, and returns the
+    /// number of entries that were unread
+    fn mark_many_as_read(
+        &self,
+        params: EntryMarkManyParams,
+    ) -> impl std::future::Future<Output = Result<u64, RepositoryError>> + Send;
+
+    /// Marks all entries matching the selectors as unread in a single statement, and returns the
+    /// number of entries that were read
+    fn mark_many_as_unread(
+        &self,
+        params: EntryMarkManyParams,
+    ) -> impl std::future::Future<Output = Result<u64, RepositoryError>> + Send;
+}
+
+#[derive(Debug, Clone)]
+pub struct EntryMarkManyParams {
+    pub user_id: UserId,
+    pub subscription_id: Option<Uuid>,
+    pub tags: Option<Vec<Uuid>>,
+    pub filter: Option<EntryFilter>,
+    /// Only entries published before this time are marked
+    pub published_before: Option<DateTime<Utc>>,
 }
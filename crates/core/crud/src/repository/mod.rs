@@ -1,11 +1,13 @@
 pub use bookmark::*;
 pub use collection::*;
 pub use entry::*;
+pub use rule::*;
 pub use subscription::*;
 pub use tag::*;
 
 mod bookmark;
 mod collection;
 mod entry;
+mod rule;
 mod subscription;
 mod tag;
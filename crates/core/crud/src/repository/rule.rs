@@ -0,0 +1,53 @@
+use colette_authentication::UserId;
+use colette_common::RepositoryError;
+use uuid::Uuid;
+
+use crate::{Rule, RuleId};
+
+pub trait RuleRepository: Sync {
+    fn find_by_id(
+        &self,
+        id: RuleId,
+        user_id: UserId,
+    ) -> impl Future<Output = Result<Option<Rule>, RepositoryError>> + Send;
+
+    /// Finds the enabled rules of every user subscribed to a feed
+    fn find_enabled_by_feed(
+        &self,
+        feed_id: Uuid,
+    ) -> impl Future<Output = Result<Vec<Rule>, RepositoryError>> + Send;
+
+    fn save(&self, data: &Rule) -> impl Future<Output = Result<(), RepositoryError>> + Send;
+
+    fn delete_by_id(
+        &self,
+        id: RuleId,
+        user_id: UserId,
+    ) -> impl Future<Output = Result<(), RepositoryError>> + Send;
+
+    /// Applies the actions of a rule to the entries matching its condition, and returns the
+    /// number of matched entries
+    fn apply(
+        &self,
+        data: &Rule,
+        params: RuleApplyParams,
+    ) -> impl Future<Output = Result<u64, RepositoryError>> + Send;
+
+    /// Finds the entries of a feed that the rules of its subscribers haven't been applied to yet
+    fn find_pending_entries(
+        &self,
+        feed_id: Uuid,
+    ) -> impl Future<Output = Result<Vec<Uuid>, RepositoryError>> + Send;
+
+    /// Records that the rules of the subscribers have been applied to the entries
+    fn mark_entries_applied(
+        &self,
+        feed_entry_ids: Vec<Uuid>,
+    ) -> impl Future<Output = Result<(), RepositoryError>> + Send;
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct RuleApplyParams {
+    /// Restricts the rule to these entries, instead of all entries of the user
+    pub feed_entry_ids: Option<Vec<Uuid>>,
+}
@@ -1,12 +1,21 @@
 use std::{fmt, str::FromStr};
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, TimeDelta, Utc};
 use colette_common::uuid_generate_ts;
 use url::Url;
 use uuid::Uuid;
 
 pub const DEFAULT_INTERVAL: u32 = 60;
 
+/// Number of consecutive failed refreshes after which a feed is disabled
+pub const MAX_FAILURE_COUNT: u32 = 10;
+
+/// Number of days of recent entries used to estimate how often a feed publishes
+pub const POSTING_WINDOW_DAYS: i64 = 30;
+
+/// Lease duration, in seconds, requested when subscribing to a WebSub hub
+pub const WEBSUB_LEASE_SECONDS: u32 = 60 * 60 * 24 * 10;
+
 #[derive(Debug, Clone)]
 pub struct Feed {
     id: FeedId,
@@ -18,6 +27,14 @@ pub struct Feed {
     status: FeedStatus,
     refresh_interval_min: u32,
     last_refreshed_at: Option<DateTime<Utc>>,
+    etag: Option<String>,
+    last_modified: Option<String>,
+    failure_count: u32,
+    last_error: Option<String>,
+    hub_url: Option<Url>,
+    topic_url: Option<Url>,
+    websub_secret: Option<String>,
+    websub_lease_expires_at: Option<DateTime<Utc>>,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
 }
@@ -41,6 +58,14 @@ impl Feed {
             refresh_interval_min: DEFAULT_INTERVAL,
             status: FeedStatus::default(),
             last_refreshed_at: None,
+            etag: None,
+            last_modified: None,
+            failure_count: 0,
+            last_error: None,
+            hub_url: None,
+            topic_url: None,
+            websub_secret: None,
+            websub_lease_expires_at: None,
             is_custom,
             created_at: now,
             updated_at: now,
@@ -129,10 +154,158 @@ impl Feed {
         self.refresh_interval_min
     }
 
+    /// Adapts the refresh interval to the mean time between the entries published over the last
+    /// [`POSTING_WINDOW_DAYS`] days, never going below the TTL advertised by the publisher.
+    pub fn adapt_refresh_interval(
+        &mut self,
+        published_ats: impl IntoIterator<Item = DateTime<Utc>>,
+        ttl: Option<u32>,
+        bounds: &RefreshIntervalBounds,
+    ) {
+        let now = Utc::now();
+        let cutoff = now - TimeDelta::days(POSTING_WINDOW_DAYS);
+
+        let recent = published_ats
+            .into_iter()
+            .filter(|e| *e >= cutoff && *e <= now)
+            .collect::<Vec<_>>();
+
+        let mut interval = match recent.iter().min() {
+            Some(oldest) => {
+                let span = (now - *oldest).num_minutes().max(1) as f64;
+
+                (span / recent.len() as f64).round() as u32
+            }
+            None => bounds.max,
+        };
+        if let Some(ttl) = ttl {
+            interval = interval.max(ttl);
+        }
+
+        let value = interval.clamp(bounds.min, bounds.max);
+        if value != self.refresh_interval_min {
+            self.refresh_interval_min = value;
+            self.updated_at = now;
+        }
+    }
+
     pub fn last_refreshed_at(&self) -> Option<DateTime<Utc>> {
         self.last_refreshed_at
     }
 
+    pub fn failure_count(&self) -> u32 {
+        self.failure_count
+    }
+
+    pub fn last_error(&self) -> Option<&str> {
+        self.last_error.as_deref()
+    }
+
+    /// Records a successful refresh, resetting the feed to healthy
+    pub fn record_success(&mut self) {
+        let now = Utc::now();
+
+        if self.status != FeedStatus::Healthy || self.failure_count > 0 {
+            self.status = FeedStatus::Healthy;
+            self.failure_count = 0;
+            self.last_error = None;
+            self.updated_at = now;
+        }
+
+        self.last_refreshed_at = Some(now);
+    }
+
+    /// Records a failed refresh, disabling the feed after [`MAX_FAILURE_COUNT`] consecutive
+    /// failures
+    pub fn record_failure(&mut self, error: String) {
+        let now = Utc::now();
+
+        self.failure_count += 1;
+        self.last_error = Some(error);
+        self.status = if self.failure_count >= MAX_FAILURE_COUNT {
+            FeedStatus::Disabled
+        } else {
+            FeedStatus::Failing
+        };
+        self.last_refreshed_at = Some(now);
+        self.updated_at = now;
+    }
+
+    pub fn etag(&self) -> Option<&str> {
+        self.etag.as_deref()
+    }
+
+    pub fn last_modified(&self) -> Option<&str> {
+        self.last_modified.as_deref()
+    }
+
+    pub fn set_validators(&mut self, etag: Option<String>, last_modified: Option<String>) {
+        if etag != self.etag || last_modified != self.last_modified {
+            self.etag = etag;
+            self.last_modified = last_modified;
+
+            let now = Utc::now();
+            self.last_refreshed_at = Some(now);
+            self.updated_at = now;
+        }
+    }
+
+    pub fn hub_url(&self) -> Option<&Url> {
+        self.hub_url.as_ref()
+    }
+
+    pub fn topic_url(&self) -> Option<&Url> {
+        self.topic_url.as_ref()
+    }
+
+    /// URL identifying the feed to its WebSub hub, falling back to the source URL if the feed
+    /// does not advertise a self link
+    pub fn websub_topic(&self) -> &Url {
+        self.topic_url.as_ref().unwrap_or(&self.source_url)
+    }
+
+    /// Sets the WebSub hub advertised by the feed. Changing the hub or topic invalidates any
+    /// existing subscription.
+    pub fn set_hub(&mut self, hub_url: Option<Url>, topic_url: Option<Url>) {
+        if hub_url != self.hub_url || topic_url != self.topic_url {
+            self.hub_url = hub_url;
+            self.topic_url = topic_url;
+            self.websub_secret = None;
+            self.websub_lease_expires_at = None;
+            self.updated_at = Utc::now();
+        }
+    }
+
+    pub fn websub_secret(&self) -> Option<&str> {
+        self.websub_secret.as_deref()
+    }
+
+    pub fn set_websub_secret(&mut self, value: String) {
+        if self.websub_secret.as_ref().is_none_or(|e| &value != e) {
+            self.websub_secret = Some(value);
+            self.updated_at = Utc::now();
+        }
+    }
+
+    pub fn websub_lease_expires_at(&self) -> Option<DateTime<Utc>> {
+        self.websub_lease_expires_at
+    }
+
+    /// Records a subscription verified by the hub, leased for the given number of seconds
+    pub fn confirm_websub_lease(&mut self, lease_seconds: u32) {
+        let now = Utc::now();
+
+        self.websub_lease_expires_at = Some(now + TimeDelta::seconds(lease_seconds.into()));
+        self.updated_at = now;
+    }
+
+    pub fn remove_websub_lease(&mut self) {
+        if self.websub_lease_expires_at.is_some() {
+            self.websub_lease_expires_at = None;
+            self.updated_at = Utc::now();
+        }
+    }
+
     pub fn created_at(&self) -> DateTime<Utc> {
         self.created_at
     }
@@ -152,6 +325,14 @@ impl Feed {
         status: FeedStatus,
         refresh_interval_min: u32,
         last_refreshed_at: Option<DateTime<Utc>>,
+        etag: Option<String>,
+        last_modified: Option<String>,
+        failure_count: u32,
+        last_error: Option<String>,
+        hub_url: Option<Url>,
+        topic_url: Option<Url>,
+        websub_secret: Option<String>,
+        websub_lease_expires_at: Option<DateTime<Utc>>,
         created_at: DateTime<Utc>,
         updated_at: DateTime<Utc>,
     ) -> Self {
@@ -165,12 +346,36 @@ impl Feed {
             status,
             refresh_interval_min,
             last_refreshed_at,
+            etag,
+            last_modified,
+            failure_count,
+            last_error,
+            hub_url,
+            topic_url,
+            websub_secret,
+            websub_lease_expires_at,
             created_at,
             updated_at,
         }
     }
 }
 
+/// Bounds, in minutes, within which feed refresh intervals are adapted
+#[derive(Debug, Clone)]
+pub struct RefreshIntervalBounds {
+    pub min: u32,
+    pub max: u32,
+}
+
+impl Default for RefreshIntervalBounds {
+    fn default() -> Self {
+        Self {
+            min: 15,
+            max: 60 * 24,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 pub struct FeedId(Uuid);
 
@@ -226,6 +431,27 @@ impl FromStr for FeedStatus {
     }
 }
 
+/// Subscription mode requested by a WebSub hub when verifying intent
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WebSubMode {
+    Subscribe,
+    Unsubscribe,
+    Denied,
+}
+
+impl FromStr for WebSubMode {
+    type Err = FeedError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "subscribe" => Ok(Self::Subscribe),
+            "unsubscribe" => Ok(Self::Unsubscribe),
+            "denied" => Ok(Self::Denied),
+            _ => Err(FeedError::WebSubMode(s.to_owned())),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct FeedDetected {
     pub url: Url,
@@ -238,8 +464,103 @@ pub struct ScrapeFeedJobData {
     pub source_url: Url,
 }
 
+impl ScrapeFeedJobData {
+    /// Key that keeps a feed from being queued for scraping more than once at a time
+    pub fn dedupe_key(&self) -> String {
+        format!("scrape_feed:{}", self.feed_id.as_inner())
+    }
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum FeedError {
     #[error("feed not found with ID: {0}")]
     NotFound(Uuid),
+
+    #[error("feed entry not found with ID: {0}")]
+    EntryNotFound(Uuid),
+
+    #[error("invalid WebSub mode: {0}")]
+    WebSubMode(String),
+
+    #[error("invalid content type: {0}")]
+    ContentType(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn feed() -> Feed {
+        let url = Url::parse("https://example.com/feed.xml").unwrap();
+
+        Feed::new(url.clone(), url, "Example".into(), None, false)
+    }
+
+    fn hours_ago(hours: i64) -> DateTime<Utc> {
+        Utc::now() - TimeDelta::hours(hours)
+    }
+
+    #[test]
+    fn adapts_to_several_posts_per_day() {
+        let mut feed = feed();
+        // One post every 2 hours over the last day
+        let published_ats = (1..=12).map(|e| hours_ago(e * 2));
+
+        feed.adapt_refresh_interval(published_ats, None, &RefreshIntervalBounds::default());
+
+        assert_eq!(feed.refresh_interval_min(), 120);
+    }
+
+    #[test]
+    fn adapts_to_daily_posts() {
+        let mut feed = feed();
+        let published_ats = (1..=7).map(|e| hours_ago(e * 24));
+
+        feed.adapt_refresh_interval(published_ats, None, &RefreshIntervalBounds::default());
+
+        assert_eq!(feed.refresh_interval_min(), 60 * 24);
+    }
+
+    #[test]
+    fn ignores_posts_outside_window() {
+        let mut feed = feed();
+        let published_ats = [hours_ago(24 * (POSTING_WINDOW_DAYS + 1)), hours_ago(-1)];
+
+        feed.adapt_refresh_interval(published_ats, None, &RefreshIntervalBounds::default());
+
+        assert_eq!(
+            feed.refresh_interval_min(),
+            RefreshIntervalBounds::default().max
+        );
+    }
+
+    #[test]
+    fn respects_ttl_and_bounds() {
+        let mut feed = feed();
+        let bounds = RefreshIntervalBounds::default();
+
+        feed.adapt_refresh_interval((1..=100).map(hours_ago), Some(240), &bounds);
+        assert_eq!(feed.refresh_interval_min(), 240);
+
+        let published_ats = (1..=1000).map(|_| hours_ago(0)).collect::<Vec<_>>();
+        feed.adapt_refresh_interval(published_ats, None, &bounds);
+        assert_eq!(feed.refresh_interval_min(), bounds.min);
+    }
+
+    #[test]
+    fn disables_after_max_failures() {
+        let mut feed = feed();
+
+        for _ in 0..MAX_FAILURE_COUNT - 1 {
+            feed.record_failure("timeout".into());
+        }
+        assert_eq!(feed.status(), &FeedStatus::Failing);
+
+        feed.record_failure("timeout".into());
+        assert_eq!(feed.status(), &FeedStatus::Disabled);
+
+        feed.record_success();
+        assert_eq!(feed.status(), &FeedStatus::Healthy);
+        assert_eq!(feed.failure_count(), 0);
+    }
 }
@@ -1,8 +1,13 @@
+use std::{fmt, str::FromStr};
+
 use chrono::{DateTime, Utc};
 use colette_common::uuid_generate_ctx;
+use colette_scraper::feed::{ProcessedEnclosure, ProcessedFeedEntry};
 use url::Url;
 use uuid::{ContextV7, Uuid};
 
+use crate::FeedError;
+
 #[derive(Debug, Clone)]
 pub struct FeedEntry {
     id: FeedEntryId,
@@ -10,8 +15,14 @@ pub struct FeedEntry {
     title: String,
     published_at: DateTime<Utc>,
     description: Option<String>,
+    content: Option<String>,
+    content_type: Option<ContentType>,
+    excerpt: Option<String>,
     author: Option<String>,
     thumbnail_url: Option<Url>,
+    enclosures: Vec<Enclosure>,
+    article_content: Option<String>,
+    article_fetched_at: Option<DateTime<Utc>>,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
 }
@@ -34,13 +45,41 @@ impl FeedEntry {
             title,
             published_at,
             description,
+            content: None,
+            content_type: None,
+            excerpt: None,
             author,
             thumbnail_url,
+            enclosures: Vec::new(),
+            article_content: None,
+            article_fetched_at: None,
             created_at: now,
             updated_at: now,
         }
     }
 
+    /// Creates a feed entry from an entry scraped from a feed document
+    pub fn from_processed(ctx: &ContextV7, value: ProcessedFeedEntry) -> Self {
+        let mut feed_entry = Self::new(
+            ctx,
+            value.link,
+            value.title,
+            value.published,
+            value.description,
+            value.author,
+            value.thumbnail,
+        );
+        if let Some(content) = value.content {
+            feed_entry.set_content(content, value.content_type.into());
+        }
+        if let Some(excerpt) = value.excerpt {
+            feed_entry.set_excerpt(excerpt);
+        }
+        feed_entry.set_enclosures(value.enclosures.into_iter().map(Into::into).collect());
+
+        feed_entry
+    }
+
     pub fn id(&self) -> FeedEntryId {
         self.id
     }
@@ -68,6 +107,52 @@ impl FeedEntry {
         self.description.as_deref()
     }
 
+    /// Full body of the entry, as opposed to the summary in the description
+    pub fn content(&self) -> Option<&str> {
+        self.content.as_deref()
+    }
+
+    pub fn content_type(&self) -> Option<&ContentType> {
+        self.content_type.as_ref()
+    }
+
+    pub fn set_content(&mut self, value: String, content_type: ContentType) {
+        if self.content.as_ref().is_none_or(|e| &value != e)
+            || self.content_type.as_ref() != Some(&content_type)
+        {
+            self.content = Some(value);
+            self.content_type = Some(content_type);
+            self.updated_at = Utc::now();
+        }
+    }
+
+    pub fn remove_content(&mut self) {
+        if self.content.is_some() {
+            self.content = None;
+            self.content_type = None;
+            self.updated_at = Utc::now();
+        }
+    }
+
+    /// Plain-text excerpt of the entry, for use in list views
+    pub fn excerpt(&self) -> Option<&str> {
+        self.excerpt.as_deref()
+    }
+
+    pub fn set_excerpt(&mut self, value: String) {
+        if self.excerpt.as_ref().is_none_or(|e| &value != e) {
+            self.excerpt = Some(value);
+            self.updated_at = Utc::now();
+        }
+    }
+
+    pub fn remove_excerpt(&mut self) {
+        if self.excerpt.is_some() {
+            self.excerpt = None;
+            self.updated_at = Utc::now();
+        }
+    }
+
     pub fn author(&self) -> Option<&str> {
         self.author.as_deref()
     }
@@ -76,6 +161,36 @@ impl FeedEntry {
         self.thumbnail_url.as_ref()
     }
 
+    pub fn enclosures(&self) -> &[Enclosure] {
+        &self.enclosures
+    }
+
+    pub fn set_enclosures(&mut self, value: Vec<Enclosure>) {
+        if value != self.enclosures {
+            self.enclosures = value;
+            self.updated_at = Utc::now();
+        }
+    }
+
+    /// Main article extracted from the webpage the entry links to
+    pub fn article_content(&self) -> Option<&str> {
+        self.article_content.as_deref()
+    }
+
+    pub fn article_fetched_at(&self) -> Option<DateTime<Utc>> {
+        self.article_fetched_at
+    }
+
+    /// Records an attempt to extract the article. The value is `None` if no article could be found
+    /// on the webpage, so that the attempt is not repeated.
+    pub fn set_article(&mut self, value: Option<String>) {
+        let now = Utc::now();
+
+        self.article_content = value;
+        self.article_fetched_at = Some(now);
+        self.updated_at = now;
+    }
+
     pub fn created_at(&self) -> DateTime<Utc> {
         self.created_at
     }
@@ -83,6 +198,108 @@ impl FeedEntry {
     pub fn updated_at(&self) -> DateTime<Utc> {
         self.updated_at
     }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_unchecked(
+        id: Uuid,
+        link: Url,
+        title: String,
+        published_at: DateTime<Utc>,
+        description: Option<String>,
+        content: Option<String>,
+        content_type: Option<ContentType>,
+        excerpt: Option<String>,
+        author: Option<String>,
+        thumbnail_url: Option<Url>,
+        article_content: Option<String>,
+        article_fetched_at: Option<DateTime<Utc>>,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id: FeedEntryId(id),
+            link,
+            title,
+            published_at,
+            description,
+            content,
+            content_type,
+            excerpt,
+            author,
+            thumbnail_url,
+            enclosures: Vec::new(),
+            article_content,
+            article_fetched_at,
+            created_at,
+            updated_at,
+        }
+    }
+}
+
+/// Markup of the full content of a feed entry
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum ContentType {
+    Text,
+    #[default]
+    Html,
+    Xhtml,
+}
+
+impl fmt::Display for ContentType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = match self {
+            Self::Text => "text",
+            Self::Html => "html",
+            Self::Xhtml => "xhtml",
+        };
+
+        write!(f, "{value}")
+    }
+}
+
+impl FromStr for ContentType {
+    type Err = FeedError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Self::Text),
+            "html" => Ok(Self::Html),
+            "xhtml" => Ok(Self::Xhtml),
+            _ => Err(FeedError::ContentType(s.to_owned())),
+        }
+    }
+}
+
+impl From<colette_scraper::feed::ContentType> for ContentType {
+    fn from(value: colette_scraper::feed::ContentType) -> Self {
+        match value {
+            colette_scraper::feed::ContentType::Text => Self::Text,
+            colette_scraper::feed::ContentType::Html => Self::Html,
+            colette_scraper::feed::ContentType::Xhtml => Self::Xhtml,
+        }
+    }
+}
+
+/// Media file attached to a feed entry, such as a podcast episode or video
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Enclosure {
+    pub url: Url,
+    pub mime_type: Option<String>,
+    /// Size of the media file in bytes
+    pub length: Option<u64>,
+    /// Duration of the media file in seconds
+    pub duration_sec: Option<u32>,
+}
+
+impl From<ProcessedEnclosure> for Enclosure {
+    fn from(value: ProcessedEnclosure) -> Self {
+        Self {
+            url: value.url,
+            mime_type: value.mime_type,
+            length: value.length,
+            duration_sec: value.duration,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use colette_common::RepositoryError;
 use url::Url;
 
@@ -19,11 +20,26 @@ pub trait FeedRepository: Sync {
         params: FeedFindOutdatedParams,
     ) -> impl Future<Output = Result<Vec<Feed>, RepositoryError>> + Send;
 
+    fn find_websub_renewable(
+        &self,
+        params: FeedFindWebSubRenewableParams,
+    ) -> impl Future<Output = Result<Vec<Feed>, RepositoryError>> + Send;
+
+    /// Finds the link and publish time of each stored entry of a feed published since the given
+    /// time
+    fn find_entry_history(
+        &self,
+        id: FeedId,
+        since: DateTime<Utc>,
+    ) -> impl Future<Output = Result<Vec<(Url, DateTime<Utc>)>, RepositoryError>> + Send;
+
     fn upsert(&self, data: FeedBatch) -> impl Future<Output = Result<(), RepositoryError>> + Send;
 
-    fn mark_as_failed(
+    /// Stores only the WebSub secret and lease of a feed, since `upsert` keeps the stored values
+    /// so that a concurrent refresh can't overwrite them
+    fn update_websub(
         &self,
-        source_url: Url,
+        data: &Feed,
     ) -> impl Future<Output = Result<(), RepositoryError>> + Send;
 }
 
@@ -40,6 +56,13 @@ pub struct FeedFindOutdatedParams {
     pub limit: Option<usize>,
 }
 
+#[derive(Debug, Clone)]
+pub struct FeedFindWebSubRenewableParams {
+    /// Leases expiring before this time are renewed
+    pub expires_before: DateTime<Utc>,
+    pub limit: Option<usize>,
+}
+
 #[derive(Debug, Clone)]
 pub struct FeedBatch {
     pub feed: Feed,
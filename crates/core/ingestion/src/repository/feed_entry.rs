@@ -0,0 +1,27 @@
+use chrono::{DateTime, Utc};
+use colette_common::RepositoryError;
+
+use crate::{FeedEntry, FeedEntryId};
+
+pub trait FeedEntryRepository: Sync {
+    fn find_by_id(
+        &self,
+        id: FeedEntryId,
+    ) -> impl Future<Output = Result<Option<FeedEntry>, RepositoryError>> + Send;
+
+    fn find_article_pending(
+        &self,
+        params: FeedEntryFindArticlePendingParams,
+    ) -> impl Future<Output = Result<Vec<FeedEntry>, RepositoryError>> + Send;
+
+    /// Saves the extracted article of the entry. The fields parsed from the feed are owned by
+    /// feed refreshes and left untouched, so that a concurrent refresh isn't overwritten.
+    fn save(&self, data: &FeedEntry) -> impl Future<Output = Result<(), RepositoryError>> + Send;
+}
+
+#[derive(Debug, Clone)]
+pub struct FeedEntryFindArticlePendingParams {
+    /// Entries published before this time are skipped
+    pub published_after: DateTime<Utc>,
+    pub limit: Option<usize>,
+}
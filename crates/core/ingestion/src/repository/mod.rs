@@ -1,3 +1,5 @@
 pub use feed::*;
+pub use feed_entry::*;
 
 mod feed;
+mod feed_entry;
@@ -33,13 +33,16 @@ pub struct AtomLink {
     pub rel: AtomRel,
     pub r#type: Option<String>,
     pub href: String,
+    pub length: Option<String>,
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub enum AtomRel {
     #[default]
     Alternate,
     RelSelf,
+    Hub,
+    Enclosure,
 }
 
 impl FromStr for AtomRel {
@@ -47,8 +50,10 @@ impl FromStr for AtomRel {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "alternative" => Ok(AtomRel::Alternate),
+            "alternate" | "alternative" => Ok(AtomRel::Alternate),
             "self" => Ok(AtomRel::RelSelf),
+            "hub" => Ok(AtomRel::Hub),
+            "enclosure" => Ok(AtomRel::Enclosure),
             _ => Ok(AtomRel::default()),
         }
     }
@@ -60,7 +65,7 @@ pub struct AtomText {
     pub text: String,
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub enum AtomTextType {
     #[default]
     Text,
@@ -179,7 +184,7 @@ pub fn from_reader<R: BufRead>(
     Ok(feed)
 }
 
-fn handle_link<'a, R: BufRead>(
+pub(crate) fn handle_link<'a, R: BufRead>(
     reader: &'a Reader<R>,
     e: &'a BytesStart<'a>,
 ) -> Result<AtomLink, Error> {
@@ -196,6 +201,7 @@ fn handle_link<'a, R: BufRead>(
             b"rel" => link.rel = value.parse()?,
             b"type" => link.r#type = Some(value),
             b"href" => link.href = value,
+            b"length" => link.length = Some(value),
             _ => {}
         }
     }
@@ -0,0 +1,147 @@
+use std::{collections::HashMap, io::Read};
+
+use serde_json::Value;
+
+use crate::Error;
+
+/// JSON Feed document, as specified by <https://www.jsonfeed.org/version/1.1/>
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct JsonFeed {
+    pub version: String,
+    pub title: String,
+    pub home_page_url: Option<String>,
+    pub feed_url: Option<String>,
+    pub description: Option<String>,
+    pub icon: Option<String>,
+    pub favicon: Option<String>,
+    pub authors: Vec<JsonAuthor>,
+    /// Deprecated in version 1.1 in favor of `authors`
+    pub author: Option<JsonAuthor>,
+    pub language: Option<String>,
+    pub expired: Option<bool>,
+    pub hubs: Vec<JsonHub>,
+    pub items: Vec<JsonItem>,
+
+    #[serde(flatten)]
+    pub additional_properties: HashMap<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct JsonItem {
+    pub id: Value,
+    pub url: Option<String>,
+    pub external_url: Option<String>,
+    pub title: Option<String>,
+    pub content_html: Option<String>,
+    pub content_text: Option<String>,
+    pub summary: Option<String>,
+    pub image: Option<String>,
+    pub banner_image: Option<String>,
+    pub date_published: Option<String>,
+    pub date_modified: Option<String>,
+    pub authors: Vec<JsonAuthor>,
+    /// Deprecated in version 1.1 in favor of `authors`
+    pub author: Option<JsonAuthor>,
+    pub tags: Vec<String>,
+    pub language: Option<String>,
+    pub attachments: Vec<JsonAttachment>,
+
+    #[serde(flatten)]
+    pub additional_properties: HashMap<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct JsonAuthor {
+    pub name: Option<String>,
+    pub url: Option<String>,
+    pub avatar: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct JsonHub {
+    pub r#type: String,
+    pub url: String,
+}
+
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct JsonAttachment {
+    pub url: String,
+    pub mime_type: String,
+    pub title: Option<String>,
+    pub size_in_bytes: Option<u64>,
+    pub duration_in_seconds: Option<f64>,
+}
+
+pub(crate) fn from_reader<R: Read>(reader: R) -> Result<JsonFeed, Error> {
+    let feed = serde_json::from_reader::<_, JsonFeed>(reader)?;
+    if !feed.version.starts_with("https://jsonfeed.org/version/") {
+        return Err(Error::Unsupported);
+    }
+
+    Ok(feed)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Feed, from_reader};
+
+    #[test]
+    fn parses_json_feed_after_leading_whitespace() {
+        let input = r#"
+            {
+                "version": "https://jsonfeed.org/version/1.1",
+                "title": "Example",
+                "home_page_url": "https://example.com/",
+                "feed_url": "https://example.com/feed.json",
+                "hubs": [{ "type": "WebSub", "url": "https://hub.example.com/" }],
+                "items": [
+                    {
+                        "id": 1,
+                        "url": "https://example.com/1",
+                        "content_text": "Hello",
+                        "date_published": "2024-01-01T00:00:00Z",
+                        "authors": [{ "name": "Alice" }],
+                        "attachments": [
+                            {
+                                "url": "https://example.com/1.mp3",
+                                "mime_type": "audio/mpeg",
+                                "size_in_bytes": 1024
+                            }
+                        ],
+                        "_custom": true
+                    }
+                ]
+            }
+        "#;
+
+        let Feed::Json(feed) = from_reader(input.as_bytes()).unwrap() else {
+            panic!("expected a JSON feed");
+        };
+
+        assert_eq!(feed.title, "Example");
+        assert_eq!(feed.hubs[0].url, "https://hub.example.com/");
+        assert_eq!(feed.items.len(), 1);
+
+        let item = &feed.items[0];
+        assert_eq!(item.url.as_deref(), Some("https://example.com/1"));
+        assert_eq!(item.content_text.as_deref(), Some("Hello"));
+        assert_eq!(item.authors[0].name.as_deref(), Some("Alice"));
+        assert_eq!(item.attachments[0].size_in_bytes, Some(1024));
+        assert!(item.additional_properties.contains_key("_custom"));
+    }
+
+    #[test]
+    fn rejects_json_without_feed_version() {
+        let input = r#"{ "version": "1.0", "title": "Not a feed", "items": [] }"#;
+
+        assert!(matches!(
+            from_reader(input.as_bytes()),
+            Err(crate::Error::Unsupported)
+        ));
+    }
+}
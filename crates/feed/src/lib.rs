@@ -1,14 +1,18 @@
 use std::{io::BufRead, num::ParseIntError};
 
 use atom::AtomFeed;
+use json::JsonFeed;
 use quick_xml::{
     Reader,
     events::{Event, attributes::AttrError},
 };
+use rdf::RdfFeed;
 use rss::RssFeed;
 
 pub mod atom;
 pub mod extension;
+pub mod json;
+pub mod rdf;
 pub mod rss;
 pub mod util;
 
@@ -16,9 +20,18 @@ pub mod util;
 pub enum Feed {
     Atom(AtomFeed),
     Rss(RssFeed),
+    Rdf(RdfFeed),
+    Json(JsonFeed),
 }
 
-pub fn from_reader<R: BufRead>(reader: R) -> Result<Feed, Error> {
+/// Parses an Atom, RSS, RDF, or JSON Feed document, detecting the format from the first
+/// non-whitespace character
+pub fn from_reader<R: BufRead>(mut reader: R) -> Result<Feed, Error> {
+    if is_json(&mut reader)? {
+        let json = json::from_reader(reader)?;
+        return Ok(Feed::Json(json));
+    }
+
     let mut reader = Reader::from_reader(reader);
     reader.config_mut().trim_text(true);
 
@@ -35,6 +48,10 @@ pub fn from_reader<R: BufRead>(reader: R) -> Result<Feed, Error> {
                     let rss = rss::from_reader(&mut reader, &mut buf)?;
                     return Ok(Feed::Rss(rss));
                 }
+                _ if e.local_name().as_ref() == b"RDF" => {
+                    let rdf = rdf::from_reader(&mut reader, &mut buf)?;
+                    return Ok(Feed::Rdf(rdf));
+                }
                 _ => {}
             },
             Ok(Event::Eof) => break,
@@ -47,6 +64,21 @@ pub fn from_reader<R: BufRead>(reader: R) -> Result<Feed, Error> {
     Err(Error::Unsupported)
 }
 
+fn is_json<R: BufRead>(reader: &mut R) -> Result<bool, Error> {
+    loop {
+        let buf = reader.fill_buf()?;
+        let Some(&byte) = buf.first() else {
+            return Ok(false);
+        };
+
+        if byte.is_ascii_whitespace() {
+            reader.consume(1);
+        } else {
+            return Ok(byte == b'{');
+        }
+    }
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
     #[error("feed type not supported")]
@@ -55,6 +87,12 @@ pub enum Error {
     #[error(transparent)]
     Xml(#[from] quick_xml::Error),
 
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
     #[error(transparent)]
     Parse(#[from] ParseError),
 }
@@ -0,0 +1,120 @@
+use std::{collections::HashMap, io::BufRead};
+
+use quick_xml::{Reader, events::Event};
+
+use crate::{
+    Error,
+    util::{Value, handle_properties, parse_value},
+};
+
+#[derive(Debug, Clone, Default)]
+pub struct RdfItem {
+    pub link: String,
+    pub title: String,
+    pub description: Option<String>,
+    pub dc_date: Option<String>,
+    pub dc_creator: Option<String>,
+
+    pub additional_properties: HashMap<String, Value>,
+}
+
+#[derive(Debug, Clone)]
+enum ItemTag {
+    Title,
+    Link,
+    Description,
+    DcDate,
+    DcCreator,
+}
+
+pub(crate) fn from_reader<R: BufRead>(
+    reader: &mut Reader<R>,
+    buf: &mut Vec<u8>,
+) -> Result<RdfItem, Error> {
+    let mut item = RdfItem::default();
+
+    let mut tag_stack: Vec<ItemTag> = Vec::new();
+
+    fn handle_text(tag_stack: &mut Vec<ItemTag>, item: &mut RdfItem, text: String) {
+        match tag_stack.pop() {
+            Some(ItemTag::Title) => {
+                item.title = text;
+            }
+            Some(ItemTag::Link) => {
+                item.link = text;
+            }
+            Some(ItemTag::Description) => {
+                item.description = Some(text);
+            }
+            Some(ItemTag::DcDate) => {
+                item.dc_date = Some(text);
+            }
+            Some(ItemTag::DcCreator) => {
+                item.dc_creator = Some(text);
+            }
+            _ => {}
+        }
+    }
+
+    loop {
+        match reader.read_event_into(buf) {
+            Ok(Event::Start(e)) => {
+                let tag = String::from_utf8_lossy(e.name().0).into_owned();
+
+                if tag == "title" {
+                    tag_stack.push(ItemTag::Title);
+                } else if tag == "link" {
+                    tag_stack.push(ItemTag::Link);
+                } else if tag == "description" {
+                    tag_stack.push(ItemTag::Description);
+                } else if tag == "dc:date" {
+                    tag_stack.push(ItemTag::DcDate);
+                } else if tag == "dc:creator" {
+                    tag_stack.push(ItemTag::DcCreator);
+                } else {
+                    let value = handle_properties(reader, &e)?;
+                    let value = parse_value(reader, buf, tag.clone(), value)?;
+
+                    if let Some(v) = item.additional_properties.get_mut(&tag) {
+                        match v {
+                            Value::Array(arr) => arr.push(value.clone()),
+                            _ => *v = Value::Array(vec![v.clone(), value.clone()]),
+                        }
+                    } else {
+                        item.additional_properties.insert(tag.clone(), value);
+                    }
+                }
+            }
+            Ok(Event::Empty(e)) => {
+                let tag = String::from_utf8_lossy(e.name().0).into_owned();
+
+                let value = handle_properties(reader, &e)?;
+
+                if let Some(v) = item.additional_properties.get_mut(&tag) {
+                    match v {
+                        Value::Array(arr) => arr.push(value.clone()),
+                        _ => *v = Value::Array(vec![v.clone(), value.clone()]),
+                    }
+                } else {
+                    item.additional_properties.insert(tag.clone(), value);
+                }
+            }
+            Ok(Event::Text(e)) => {
+                let text = e.unescape()?.into_owned();
+
+                handle_text(&mut tag_stack, &mut item, text);
+            }
+            Ok(Event::CData(e)) => {
+                let text = String::from_utf8_lossy(&e.into_inner()).into_owned();
+
+                handle_text(&mut tag_stack, &mut item, text);
+            }
+            Ok(Event::End(e)) if e.name().0 == b"item" => break,
+            _ => (),
+        }
+
+        buf.clear();
+    }
+
+    Ok(item)
+}
@@ -0,0 +1,137 @@
+use std::{collections::HashMap, io::BufRead};
+
+pub use channel::RdfChannel;
+pub use item::RdfItem;
+use quick_xml::{Reader, events::Event};
+
+use crate::{
+    Error,
+    util::{Value, handle_properties, parse_value},
+};
+
+mod channel;
+mod item;
+
+/// RDF Site Summary (RSS 1.0) feed, whose items are siblings of the channel rather than children
+#[derive(Debug, Clone, Default)]
+pub struct RdfFeed {
+    pub channel: RdfChannel,
+    pub item: Vec<RdfItem>,
+
+    pub additional_properties: HashMap<String, Value>,
+}
+
+pub(crate) fn from_reader<R: BufRead>(
+    reader: &mut Reader<R>,
+    buf: &mut Vec<u8>,
+) -> Result<RdfFeed, Error> {
+    let mut feed = RdfFeed::default();
+
+    loop {
+        match reader.read_event_into(buf) {
+            Ok(Event::Start(e)) => {
+                let tag = String::from_utf8_lossy(e.name().0).into_owned();
+
+                if tag == "channel" {
+                    let channel = channel::from_reader(reader, buf)?;
+                    feed.channel = channel;
+                } else if tag == "item" {
+                    let item = item::from_reader(reader, buf)?;
+                    feed.item.push(item);
+                } else {
+                    let value = handle_properties(reader, &e)?;
+                    let value = parse_value(reader, buf, tag.clone(), value)?;
+
+                    if let Some(v) = feed.additional_properties.get_mut(&tag) {
+                        match v {
+                            Value::Array(arr) => arr.push(value.clone()),
+                            _ => *v = Value::Array(vec![v.clone(), value.clone()]),
+                        }
+                    } else {
+                        feed.additional_properties.insert(tag.clone(), value);
+                    }
+                }
+            }
+            Ok(Event::Empty(e)) => {
+                let tag = String::from_utf8_lossy(e.name().0).into_owned();
+
+                let value = handle_properties(reader, &e)?;
+
+                if let Some(v) = feed.additional_properties.get_mut(&tag) {
+                    match v {
+                        Value::Array(arr) => arr.push(value.clone()),
+                        _ => *v = Value::Array(vec![v.clone(), value.clone()]),
+                    }
+                } else {
+                    feed.additional_properties.insert(tag.clone(), value);
+                }
+            }
+            Ok(Event::End(e)) if e.local_name().as_ref() == b"RDF" => break,
+            Ok(Event::Eof) => break,
+            _ => (),
+        }
+
+        buf.clear();
+    }
+
+    Ok(feed)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Feed, from_reader};
+
+    #[test]
+    fn parses_items_outside_channel() {
+        let input = r#"<?xml version="1.0"?>
+            <rdf:RDF
+                xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"
+                xmlns:dc="http://purl.org/dc/elements/1.1/"
+                xmlns="http://purl.org/rss/1.0/">
+                <channel rdf:about="https://example.com/rss">
+                    <title>Example</title>
+                    <link>https://example.com/</link>
+                    <description>An RDF feed</description>
+                    <dc:date>2024-01-02T00:00:00Z</dc:date>
+                    <items>
+                        <rdf:Seq>
+                            <rdf:li rdf:resource="https://example.com/1" />
+                        </rdf:Seq>
+                    </items>
+                </channel>
+                <item rdf:about="https://example.com/1">
+                    <title>First</title>
+                    <link>https://example.com/1</link>
+                    <description>First item</description>
+                    <dc:date>2024-01-01T00:00:00Z</dc:date>
+                    <dc:creator>Alice</dc:creator>
+                </item>
+                <item rdf:about="https://example.com/2">
+                    <title>Second</title>
+                    <link>https://example.com/2</link>
+                </item>
+            </rdf:RDF>"#;
+
+        let Feed::Rdf(feed) = from_reader(input.as_bytes()).unwrap() else {
+            panic!("expected an RDF feed");
+        };
+
+        assert_eq!(feed.channel.title, "Example");
+        assert_eq!(feed.channel.link, "https://example.com/");
+        assert_eq!(
+            feed.channel.dc_date.as_deref(),
+            Some("2024-01-02T00:00:00Z")
+        );
+        assert_eq!(feed.item.len(), 2);
+
+        let first = &feed.item[0];
+        assert_eq!(first.title, "First");
+        assert_eq!(first.link, "https://example.com/1");
+        assert_eq!(first.description.as_deref(), Some("First item"));
+        assert_eq!(first.dc_date.as_deref(), Some("2024-01-01T00:00:00Z"));
+        assert_eq!(first.dc_creator.as_deref(), Some("Alice"));
+
+        assert_eq!(feed.item[1].title, "Second");
+        assert_eq!(feed.item[1].description, None);
+    }
+}
@@ -4,6 +4,7 @@ use quick_xml::{Reader, events::Event};
 
 use crate::{
     Error,
+    atom::{self, AtomLink},
     rss::item::{self, RssItem},
     util::{Value, handle_properties, parse_value},
 };
@@ -14,6 +15,10 @@ pub struct RssChannel {
     pub title: String,
     pub description: String,
     pub last_build_date: Option<String>,
+    pub ttl: Option<String>,
+    pub update_period: Option<String>,
+    pub update_frequency: Option<String>,
+    pub atom_link: Vec<AtomLink>,
     pub item: Vec<RssItem>,
 
     pub additional_properties: HashMap<String, Value>,
@@ -25,6 +30,9 @@ enum ChannelTag {
     Link,
     Description,
     LastBuildDate,
+    Ttl,
+    UpdatePeriod,
+    UpdateFrequency,
 }
 
 pub(crate) fn from_reader<R: BufRead>(
@@ -49,6 +57,15 @@ pub(crate) fn from_reader<R: BufRead>(
             Some(ChannelTag::LastBuildDate) => {
                 channel.last_build_date = Some(text);
             }
+            Some(ChannelTag::Ttl) => {
+                channel.ttl = Some(text);
+            }
+            Some(ChannelTag::UpdatePeriod) => {
+                channel.update_period = Some(text);
+            }
+            Some(ChannelTag::UpdateFrequency) => {
+                channel.update_frequency = Some(text);
+            }
             _ => {}
         }
     }
@@ -66,6 +83,12 @@ pub(crate) fn from_reader<R: BufRead>(
                     tag_stack.push(ChannelTag::Description);
                 } else if tag == "lastBuildDate" {
                     tag_stack.push(ChannelTag::LastBuildDate);
+                } else if tag == "ttl" {
+                    tag_stack.push(ChannelTag::Ttl);
+                } else if tag == "sy:updatePeriod" {
+                    tag_stack.push(ChannelTag::UpdatePeriod);
+                } else if tag == "sy:updateFrequency" {
+                    tag_stack.push(ChannelTag::UpdateFrequency);
                 } else if tag == "item" {
                     let item = item::from_reader(reader, buf)?;
                     channel.item.push(item);
@@ -86,15 +109,20 @@ pub(crate) fn from_reader<R: BufRead>(
             Ok(Event::Empty(e)) => {
                 let tag = String::from_utf8_lossy(e.name().0).into_owned();
 
-                let value = handle_properties(reader, &e)?;
+                if e.name().prefix().is_some() && e.local_name().as_ref() == b"link" {
+                    let link = atom::handle_link(reader, &e)?;
+                    channel.atom_link.push(link);
+                } else {
+                    let value = handle_properties(reader, &e)?;
 
-                if let Some(v) = channel.additional_properties.get_mut(&tag) {
-                    match v {
-                        Value::Array(arr) => arr.push(value.clone()),
-                        _ => *v = Value::Array(vec![v.clone(), value.clone()]),
+                    if let Some(v) = channel.additional_properties.get_mut(&tag) {
+                        match v {
+                            Value::Array(arr) => arr.push(value.clone()),
+                            _ => *v = Value::Array(vec![v.clone(), value.clone()]),
+                        }
+                    } else {
+                        channel.additional_properties.insert(tag.clone(), value);
                     }
-                } else {
-                    channel.additional_properties.insert(tag.clone(), value);
                 }
             }
             Ok(Event::Text(e)) => {
@@ -1,6 +1,9 @@
 use std::{collections::HashMap, io::BufRead};
 
-use quick_xml::{Reader, events::Event};
+use quick_xml::{
+    Reader,
+    events::{BytesStart, Event},
+};
 
 use crate::{
     Error,
@@ -12,19 +15,32 @@ pub struct RssItem {
     pub link: String,
     pub title: String,
     pub description: String,
+    pub content_encoded: Option<String>,
     pub pub_date: Option<String>,
     pub author: Option<String>,
+    pub enclosure: Vec<RssEnclosure>,
+    pub itunes_duration: Option<String>,
 
     pub additional_properties: HashMap<String, Value>,
 }
 
+/// Media file attached to an item, such as a podcast episode
+#[derive(Debug, Clone, Default)]
+pub struct RssEnclosure {
+    pub url: String,
+    pub r#type: Option<String>,
+    pub length: Option<String>,
+}
+
 #[derive(Debug, Clone)]
 enum ItemTag {
     Title,
     Link,
     Description,
+    ContentEncoded,
     PubDate,
     Author,
+    ItunesDuration,
 }
 
 pub(crate) fn from_reader<R: BufRead>(
@@ -46,12 +62,18 @@ pub(crate) fn from_reader<R: BufRead>(
             Some(ItemTag::Description) => {
                 item.description = text;
             }
+            Some(ItemTag::ContentEncoded) => {
+                item.content_encoded = Some(text);
+            }
             Some(ItemTag::PubDate) => {
                 item.pub_date = Some(text);
             }
             Some(ItemTag::Author) => {
                 item.author = Some(text);
             }
+            Some(ItemTag::ItunesDuration) => {
+                item.itunes_duration = Some(text);
+            }
             _ => {}
         }
     }
@@ -67,10 +89,14 @@ pub(crate) fn from_reader<R: BufRead>(
                     tag_stack.push(ItemTag::Link);
                 } else if tag == "description" {
                     tag_stack.push(ItemTag::Description);
+                } else if tag == "content:encoded" {
+                    tag_stack.push(ItemTag::ContentEncoded);
                 } else if tag == "pubDate" {
                     tag_stack.push(ItemTag::PubDate);
                 } else if tag == "author" {
                     tag_stack.push(ItemTag::Author);
+                } else if tag == "itunes:duration" {
+                    tag_stack.push(ItemTag::ItunesDuration);
                 } else {
                     let value = handle_properties(reader, &e)?;
                     let value = parse_value(reader, buf, tag.clone(), value)?;
@@ -88,15 +114,20 @@ pub(crate) fn from_reader<R: BufRead>(
             Ok(Event::Empty(e)) => {
                 let tag = String::from_utf8_lossy(e.name().0).into_owned();
 
-                let value = handle_properties(reader, &e)?;
+                if tag == "enclosure" {
+                    let enclosure = handle_enclosure(reader, &e)?;
+                    item.enclosure.push(enclosure);
+                } else {
+                    let value = handle_properties(reader, &e)?;
 
-                if let Some(v) = item.additional_properties.get_mut(&tag) {
-                    match v {
-                        Value::Array(arr) => arr.push(value.clone()),
-                        _ => *v = Value::Array(vec![v.clone(), value.clone()]),
+                    if let Some(v) = item.additional_properties.get_mut(&tag) {
+                        match v {
+                            Value::Array(arr) => arr.push(value.clone()),
+                            _ => *v = Value::Array(vec![v.clone(), value.clone()]),
+                        }
+                    } else {
+                        item.additional_properties.insert(tag.clone(), value);
                     }
-                } else {
-                    item.additional_properties.insert(tag.clone(), value);
                 }
             }
             Ok(Event::Text(e)) => {
@@ -122,3 +153,27 @@ pub(crate) fn from_reader<R: BufRead>(
 
     Ok(item)
 }
+
+fn handle_enclosure<'a, R: BufRead>(
+    reader: &'a Reader<R>,
+    e: &'a BytesStart<'a>,
+) -> Result<RssEnclosure, Error> {
+    let mut enclosure = RssEnclosure::default();
+
+    for attribute in e.attributes() {
+        let attribute = attribute.map_err(|e| Error::Parse(e.into()))?;
+
+        let value = attribute
+            .decode_and_unescape_value(reader.decoder())?
+            .into_owned();
+
+        match attribute.key.local_name().into_inner() {
+            b"url" => enclosure.url = value,
+            b"type" => enclosure.r#type = Some(value),
+            b"length" => enclosure.length = Some(value),
+            _ => {}
+        }
+    }
+
+    Ok(enclosure)
+}
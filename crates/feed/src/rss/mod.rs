@@ -1,7 +1,7 @@
 use std::{collections::HashMap, io::BufRead};
 
 pub use channel::RssChannel;
-pub use item::RssItem;
+pub use item::{RssEnclosure, RssItem};
 use quick_xml::{Reader, events::Event};
 
 use crate::{
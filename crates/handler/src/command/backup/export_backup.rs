@@ -1,12 +1,15 @@
+use std::collections::HashMap;
+
 use bytes::Bytes;
-use colette_archival::{Backup, BackupBookmark, BackupSubscription, BackupTag};
+use colette_archival::{Backup, BackupBookmark, BackupEntry, BackupSubscription, BackupTag};
 use colette_common::RepositoryError;
+use colette_crud::{BooleanOp, EntryBooleanField, EntryFilter, StarStatus};
 use uuid::Uuid;
 
 use crate::{
-    BookmarkDto, BookmarkQueryParams, BookmarkQueryRepository, Handler, SubscriptionDto,
-    SubscriptionQueryParams, SubscriptionQueryRepository, TagDto, TagQueryParams,
-    TagQueryRepository,
+    BookmarkDto, BookmarkQueryParams, BookmarkQueryRepository, EntryDto, EntryQueryParams,
+    EntryQueryRepository, Handler, SubscriptionDto, SubscriptionQueryParams,
+    SubscriptionQueryRepository, TagDto, TagQueryParams, TagQueryRepository,
 };
 
 #[derive(Debug, Clone)]
@@ -16,32 +19,44 @@ pub struct ExportBackupCommand {
 
 pub struct ExportBackupHandler<
     BQR: BookmarkQueryRepository,
+    EQR: EntryQueryRepository,
     SQR: SubscriptionQueryRepository,
     TQR: TagQueryRepository,
 > {
     bookmark_query_repository: BQR,
+    entry_query_repository: EQR,
     subscription_query_repository: SQR,
     tag_query_repository: TQR,
 }
 
-impl<BQR: BookmarkQueryRepository, SQR: SubscriptionQueryRepository, TQR: TagQueryRepository>
-    ExportBackupHandler<BQR, SQR, TQR>
+impl<
+    BQR: BookmarkQueryRepository,
+    EQR: EntryQueryRepository,
+    SQR: SubscriptionQueryRepository,
+    TQR: TagQueryRepository,
+> ExportBackupHandler<BQR, EQR, SQR, TQR>
 {
     pub fn new(
         bookmark_query_repository: BQR,
+        entry_query_repository: EQR,
         subscription_query_repository: SQR,
         tag_query_repository: TQR,
     ) -> Self {
         Self {
             bookmark_query_repository,
+            entry_query_repository,
             subscription_query_repository,
             tag_query_repository,
         }
     }
 }
 
-impl<BQR: BookmarkQueryRepository, SQR: SubscriptionQueryRepository, TQR: TagQueryRepository>
-    Handler<ExportBackupCommand> for ExportBackupHandler<BQR, SQR, TQR>
+impl<
+    BQR: BookmarkQueryRepository,
+    EQR: EntryQueryRepository,
+    SQR: SubscriptionQueryRepository,
+    TQR: TagQueryRepository,
+> Handler<ExportBackupCommand> for ExportBackupHandler<BQR, EQR, SQR, TQR>
 {
     type Response = Bytes;
     type Error = ExportBackupError;
@@ -55,6 +70,32 @@ impl<BQR: BookmarkQueryRepository, SQR: SubscriptionQueryRepository, TQR: TagQue
             })
             .await?;
 
+        let entries = self
+            .entry_query_repository
+            .query(EntryQueryParams {
+                user_id: cmd.user_id,
+                filter: Some(EntryFilter::Or(vec![
+                    EntryFilter::Boolean {
+                        field: EntryBooleanField::Starred,
+                        op: BooleanOp::Equals(true),
+                    },
+                    EntryFilter::Boolean {
+                        field: EntryBooleanField::HasNote,
+                        op: BooleanOp::Equals(true),
+                    },
+                ])),
+                ..Default::default()
+            })
+            .await?;
+
+        let mut entries_by_feed = HashMap::<Uuid, Vec<BackupEntry>>::new();
+        for entry in entries {
+            entries_by_feed
+                .entry(entry.feed_id)
+                .or_default()
+                .push(entry.into());
+        }
+
         let bookmarks = self
             .bookmark_query_repository
             .query(BookmarkQueryParams {
@@ -72,7 +113,17 @@ impl<BQR: BookmarkQueryRepository, SQR: SubscriptionQueryRepository, TQR: TagQue
             .await?;
 
         let backup = Backup {
-            subscriptions: subscriptions.into_iter().map(Into::into).collect(),
+            subscriptions: subscriptions
+                .into_iter()
+                .map(|e| {
+                    let entries = entries_by_feed.remove(&e.feed_id).unwrap_or_default();
+
+                    BackupSubscription {
+                        entries,
+                        ..e.into()
+                    }
+                })
+                .collect(),
             bookmarks: bookmarks.into_iter().map(Into::into).collect(),
             tags: tags.into_iter().map(Into::into).collect(),
         };
@@ -91,12 +142,30 @@ impl From<SubscriptionDto> for BackupSubscription {
             title: value.title,
             description: value.description,
             tags: value.tags.into_iter().map(Into::into).collect(),
+            entries: Vec::new(),
             created_at: value.created_at,
             updated_at: value.updated_at,
         }
     }
 }
 
+impl From<EntryDto> for BackupEntry {
+    fn from(value: EntryDto) -> Self {
+        let starred_at = match value.star_status {
+            StarStatus::Starred(starred_at) => Some(starred_at),
+            StarStatus::Unstarred => None,
+        };
+
+        Self {
+            link: value.link,
+            title: value.title,
+            published_at: value.published_at,
+            note: value.note,
+            starred_at,
+        }
+    }
+}
+
 impl From<BookmarkDto> for BackupBookmark {
     fn from(value: BookmarkDto) -> Self {
         Self {
@@ -70,7 +70,8 @@ impl<BR: BookmarkRepository, JP: JobProducer> Handler<CreateBookmarkCommand>
                 archived_path: None,
                 bookmark_id: bookmark.id(),
             };
-            let job = Job::create("archive_thumbnail", data)?;
+            let mut job = Job::create("archive_thumbnail", data)?;
+            job.user_id = Some(cmd.user_id.as_inner());
 
             let mut producer = self.archive_thumbnail_producer.lock().await;
 
@@ -94,7 +94,8 @@ impl<BR: BookmarkRepository, JP: JobProducer> Handler<ImportBookmarksCommand>
         let data = ImportBookmarksJobData {
             user_id: cmd.user_id,
         };
-        let job = Job::create("import_bookmarks", data)?;
+        let mut job = Job::create("import_bookmarks", data)?;
+        job.user_id = Some(cmd.user_id.as_inner());
 
         let mut producer = self.import_bookmarks_producer.lock().await;
 
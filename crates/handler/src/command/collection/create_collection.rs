@@ -1,7 +1,7 @@
 use colette_authentication::UserId;
 use colette_common::RepositoryError;
 use colette_crud::{
-    BookmarkFilter, Collection, CollectionError, CollectionRepository, CollectionTitle,
+    BookmarkFilter, Collection, CollectionError, CollectionRepository, CollectionTitle, EntryFilter,
 };
 
 use crate::Handler;
@@ -10,6 +10,7 @@ use crate::Handler;
 pub struct CreateCollectionCommand {
     pub title: String,
     pub filter: BookmarkFilter,
+    pub entry_filter: Option<EntryFilter>,
     pub user_id: UserId,
 }
 
@@ -32,7 +33,7 @@ impl<CR: CollectionRepository> Handler<CreateCollectionCommand> for CreateCollec
     async fn handle(&self, cmd: CreateCollectionCommand) -> Result<Self::Response, Self::Error> {
         let title = CollectionTitle::new(cmd.title.clone())?;
 
-        let collection = Collection::new(title, cmd.filter, cmd.user_id);
+        let collection = Collection::new(title, cmd.filter, cmd.entry_filter, cmd.user_id);
 
         self.collection_repository
             .save(&collection)
@@ -2,7 +2,7 @@ use colette_authentication::UserId;
 use colette_common::RepositoryError;
 use colette_crud::{
     BookmarkFilter, Collection, CollectionError, CollectionId, CollectionRepository,
-    CollectionTitle,
+    CollectionTitle, EntryFilter,
 };
 
 use crate::Handler;
@@ -12,6 +12,7 @@ pub struct UpdateCollectionCommand {
     pub id: CollectionId,
     pub title: Option<String>,
     pub filter: Option<BookmarkFilter>,
+    pub entry_filter: Option<Option<EntryFilter>>,
     pub user_id: UserId,
 }
 
@@ -44,6 +45,13 @@ impl<CR: CollectionRepository> Handler<UpdateCollectionCommand> for UpdateCollec
         if let Some(filter) = cmd.filter {
             collection.set_filter(filter);
         }
+        if let Some(entry_filter) = cmd.entry_filter {
+            if let Some(entry_filter) = entry_filter {
+                collection.set_entry_filter(entry_filter);
+            } else {
+                collection.remove_entry_filter();
+            }
+        }
 
         self.collection_repository.save(&collection).await?;
 
@@ -0,0 +1,217 @@
+use chrono::{DateTime, Utc};
+use colette_authentication::UserId;
+use colette_common::RepositoryError;
+use colette_crud::{CollectionError, EntryMarkManyParams, EntryRepository};
+use uuid::Uuid;
+
+use crate::{CollectionQueryRepository, Handler};
+
+#[derive(Debug, Clone)]
+pub struct MarkEntriesCommand {
+    pub collection_id: Option<Uuid>,
+    pub subscription_id: Option<Uuid>,
+    pub tags: Option<Vec<Uuid>>,
+    pub published_before: Option<DateTime<Utc>>,
+    /// Whether the entries are marked as read or unread
+    pub has_read: bool,
+    pub user_id: UserId,
+}
+
+pub struct MarkEntriesHandler<ER: EntryRepository, CQR: CollectionQueryRepository> {
+    entry_repository: ER,
+    collection_query_repository: CQR,
+}
+
+impl<ER: EntryRepository, CQR: CollectionQueryRepository> MarkEntriesHandler<ER, CQR> {
+    pub fn new(entry_repository: ER, collection_query_repository: CQR) -> Self {
+        Self {
+            entry_repository,
+            collection_query_repository,
+        }
+    }
+}
+
+impl<ER: EntryRepository, CQR: CollectionQueryRepository> Handler<MarkEntriesCommand>
+    for MarkEntriesHandler<ER, CQR>
+{
+    type Response = u64;
+    type Error = MarkEntriesError;
+
+    async fn handle(&self, cmd: MarkEntriesCommand) -> Result<Self::Response, Self::Error> {
+        let mut filter = None;
+        if let Some(collection_id) = cmd.collection_id {
+            let collection = self
+                .collection_query_repository
+                .query_by_id(collection_id, cmd.user_id.as_inner())
+                .await?
+                .ok_or(CollectionError::NotFound(collection_id))?;
+            // A collection without an entry filter lists no entries, so it marks none either
+            let Some(entry_filter) = collection.entry_filter else {
+                return Ok(0);
+            };
+
+            filter = Some(entry_filter);
+        }
+
+        let params = EntryMarkManyParams {
+            user_id: cmd.user_id,
+            subscription_id: cmd.subscription_id,
+            tags: cmd.tags,
+            filter,
+            published_before: cmd.published_before,
+        };
+
+        let count = if cmd.has_read {
+            self.entry_repository.mark_many_as_read(params).await?
+        } else {
+            self.entry_repository.mark_many_as_unread(params).await?
+        };
+
+        Ok(count)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum MarkEntriesError {
+    #[error(transparent)]
+    Collection(#[from] CollectionError),
+
+    #[error(transparent)]
+    Repository(#[from] RepositoryError),
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        Mutex,
+        atomic::{AtomicBool, Ordering},
+    };
+
+    use chrono::Utc;
+    use colette_crud::{BookmarkFilter, BooleanOp, Entry, EntryBooleanField, EntryFilter, EntryId};
+
+    use super::*;
+    use crate::{CollectionDto, CollectionQueryParams};
+
+    #[derive(Default)]
+    struct FakeEntryRepository {
+        called: AtomicBool,
+        filter: Mutex<Option<EntryFilter>>,
+    }
+
+    impl EntryRepository for FakeEntryRepository {
+        async fn find_by_id(
+            &self,
+            _id: EntryId,
+            _user_id: UserId,
+        ) -> Result<Option<Entry>, RepositoryError> {
+            unimplemented!()
+        }
+
+        async fn save(&self, _data: &Entry) -> Result<(), RepositoryError> {
+            unimplemented!()
+        }
+
+        async fn mark_many_as_read(
+            &self,
+            params: EntryMarkManyParams,
+        ) -> Result<u64, RepositoryError> {
+            self.called.store(true, Ordering::SeqCst);
+            *self.filter.lock().unwrap() = params.filter;
+
+            Ok(3)
+        }
+
+        async fn mark_many_as_unread(
+            &self,
+            params: EntryMarkManyParams,
+        ) -> Result<u64, RepositoryError> {
+            self.mark_many_as_read(params).await
+        }
+    }
+
+    struct FakeCollectionQueryRepository(Vec<CollectionDto>);
+
+    impl CollectionQueryRepository for FakeCollectionQueryRepository {
+        async fn query(
+            &self,
+            params: CollectionQueryParams,
+        ) -> Result<Vec<CollectionDto>, RepositoryError> {
+            Ok(self
+                .0
+                .iter()
+                .filter(|e| params.id.is_none_or(|id| id == e.id))
+                .cloned()
+                .collect())
+        }
+    }
+
+    fn collection(entry_filter: Option<EntryFilter>) -> CollectionDto {
+        CollectionDto {
+            id: Uuid::now_v7(),
+            title: "Collection".into(),
+            filter: BookmarkFilter::And(Vec::new()),
+            entry_filter,
+            entry_counts: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn command(collection_id: Uuid) -> MarkEntriesCommand {
+        MarkEntriesCommand {
+            collection_id: Some(collection_id),
+            subscription_id: None,
+            tags: None,
+            published_before: None,
+            has_read: true,
+            user_id: Uuid::now_v7().into(),
+        }
+    }
+
+    #[tokio::test]
+    async fn marks_nothing_for_collection_without_entry_filter() {
+        let collection = collection(None);
+        let id = collection.id;
+        let handler = MarkEntriesHandler::new(
+            FakeEntryRepository::default(),
+            FakeCollectionQueryRepository(vec![collection]),
+        );
+
+        assert_eq!(handler.handle(command(id)).await.unwrap(), 0);
+        assert!(!handler.entry_repository.called.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn marks_entries_matching_collection_filter() {
+        let entry_filter = EntryFilter::Boolean {
+            field: EntryBooleanField::Starred,
+            op: BooleanOp::Equals(true),
+        };
+        let collection = collection(Some(entry_filter.clone()));
+        let id = collection.id;
+        let handler = MarkEntriesHandler::new(
+            FakeEntryRepository::default(),
+            FakeCollectionQueryRepository(vec![collection]),
+        );
+
+        assert_eq!(handler.handle(command(id)).await.unwrap(), 3);
+        assert_eq!(
+            *handler.entry_repository.filter.lock().unwrap(),
+            Some(entry_filter)
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_unknown_collection() {
+        let handler = MarkEntriesHandler::new(
+            FakeEntryRepository::default(),
+            FakeCollectionQueryRepository(Vec::new()),
+        );
+
+        assert!(matches!(
+            handler.handle(command(Uuid::now_v7())).await,
+            Err(MarkEntriesError::Collection(CollectionError::NotFound(_)))
+        ));
+    }
+}
@@ -1,5 +1,13 @@
+pub use mark_entries::*;
 pub use mark_entry_as_read::*;
 pub use mark_entry_as_unread::*;
+pub use star_entry::*;
+pub use unstar_entry::*;
+pub use update_entry::*;
 
+mod mark_entries;
 mod mark_entry_as_read;
 mod mark_entry_as_unread;
+mod star_entry;
+mod unstar_entry;
+mod update_entry;
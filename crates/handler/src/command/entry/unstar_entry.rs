@@ -0,0 +1,49 @@
+use colette_authentication::UserId;
+use colette_common::RepositoryError;
+use colette_crud::{EntryError, EntryId, EntryRepository};
+
+use crate::Handler;
+
+#[derive(Debug, Clone)]
+pub struct UnstarEntryCommand {
+    pub id: EntryId,
+    pub user_id: UserId,
+}
+
+pub struct UnstarEntryHandler<ER: EntryRepository> {
+    entry_repository: ER,
+}
+
+impl<ER: EntryRepository> UnstarEntryHandler<ER> {
+    pub fn new(entry_repository: ER) -> Self {
+        Self { entry_repository }
+    }
+}
+
+impl<ER: EntryRepository> Handler<UnstarEntryCommand> for UnstarEntryHandler<ER> {
+    type Response = ();
+    type Error = UnstarEntryError;
+
+    async fn handle(&self, cmd: UnstarEntryCommand) -> Result<Self::Response, Self::Error> {
+        let mut entry = self
+            .entry_repository
+            .find_by_id(cmd.id, cmd.user_id)
+            .await?
+            .ok_or(EntryError::NotFound(cmd.id.as_inner()))?;
+
+        entry.unstar()?;
+
+        self.entry_repository.save(&entry).await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum UnstarEntryError {
+    #[error(transparent)]
+    Entry(#[from] EntryError),
+
+    #[error(transparent)]
+    Repository(#[from] RepositoryError),
+}
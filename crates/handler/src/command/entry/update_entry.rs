@@ -0,0 +1,56 @@
+use colette_authentication::UserId;
+use colette_common::RepositoryError;
+use colette_crud::{EntryError, EntryId, EntryRepository};
+
+use crate::Handler;
+
+#[derive(Debug, Clone)]
+pub struct UpdateEntryCommand {
+    pub id: EntryId,
+    pub note: Option<Option<String>>,
+    pub user_id: UserId,
+}
+
+pub struct UpdateEntryHandler<ER: EntryRepository> {
+    entry_repository: ER,
+}
+
+impl<ER: EntryRepository> UpdateEntryHandler<ER> {
+    pub fn new(entry_repository: ER) -> Self {
+        Self { entry_repository }
+    }
+}
+
+impl<ER: EntryRepository> Handler<UpdateEntryCommand> for UpdateEntryHandler<ER> {
+    type Response = ();
+    type Error = UpdateEntryError;
+
+    async fn handle(&self, cmd: UpdateEntryCommand) -> Result<Self::Response, Self::Error> {
+        let mut entry = self
+            .entry_repository
+            .find_by_id(cmd.id, cmd.user_id)
+            .await?
+            .ok_or(EntryError::NotFound(cmd.id.as_inner()))?;
+
+        if let Some(note) = cmd.note {
+            if let Some(note) = note {
+                entry.set_note(note);
+            } else {
+                entry.remove_note();
+            }
+        }
+
+        self.entry_repository.save(&entry).await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum UpdateEntryError {
+    #[error(transparent)]
+    Entry(#[from] EntryError),
+
+    #[error(transparent)]
+    Repository(#[from] RepositoryError),
+}
@@ -4,7 +4,8 @@ use bytes::Buf;
 use colette_common::RepositoryError;
 use colette_http::HttpClient;
 use colette_ingestion::FeedDetected;
-use colette_scraper::feed::FeedScraper;
+use colette_scraper::{charset::to_utf8, feed::FeedScraper};
+use http::header::CONTENT_TYPE;
 use url::Url;
 
 use crate::Handler;
@@ -32,18 +33,27 @@ impl<HC: HttpClient> Handler<DetectFeedsCommand> for DetectFeedsHandler<HC> {
     type Response = Vec<FeedDetected>;
     type Error = DetectFeedsError;
 
-    async fn handle(&self, mut cmd: DetectFeedsCommand) -> Result<Self::Response, Self::Error> {
-        match self.feed_scraper.scrape(&mut cmd.url).await {
+    async fn handle(&self, cmd: DetectFeedsCommand) -> Result<Self::Response, Self::Error> {
+        // Plugins rewrite the URL in place, but feed detection must read the original page
+        let mut feed_url = cmd.url.clone();
+
+        match self.feed_scraper.scrape(&mut feed_url).await {
             Ok(processed) => {
                 let detected = vec![FeedDetected {
-                    url: cmd.url,
+                    url: feed_url,
                     title: processed.title,
                 }];
 
                 Ok(detected)
             }
             Err(colette_scraper::feed::FeedError::Unsupported) => {
-                let body = self.http_client.get(&cmd.url).await?;
+                let resp = self.http_client.get_response(&cmd.url).await?;
+                let content_type = resp
+                    .headers()
+                    .get(CONTENT_TYPE)
+                    .and_then(|e| e.to_str().ok())
+                    .map(ToOwned::to_owned);
+                let body = to_utf8(resp.into_body(), content_type.as_deref());
 
                 let metadata = colette_meta::parse_metadata(body.reader())
                     .map_err(|_| colette_scraper::feed::FeedError::Unsupported)?;
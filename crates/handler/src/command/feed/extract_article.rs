@@ -0,0 +1,91 @@
+use colette_common::RepositoryError;
+use colette_http::HttpClient;
+use colette_ingestion::{FeedEntryId, FeedEntryRepository, FeedError};
+use colette_meta::readability;
+use colette_scraper::{charset::to_utf8, sanitize::sanitize_html};
+use http::{StatusCode, header::CONTENT_TYPE};
+
+use crate::Handler;
+
+#[derive(Debug, Clone)]
+pub struct ExtractArticleCommand {
+    pub id: FeedEntryId,
+}
+
+pub struct ExtractArticleHandler<FER: FeedEntryRepository, HC: HttpClient> {
+    feed_entry_repository: FER,
+    http_client: HC,
+}
+
+impl<FER: FeedEntryRepository, HC: HttpClient> ExtractArticleHandler<FER, HC> {
+    pub fn new(feed_entry_repository: FER, http_client: HC) -> Self {
+        Self {
+            feed_entry_repository,
+            http_client,
+        }
+    }
+}
+
+impl<FER: FeedEntryRepository, HC: HttpClient> Handler<ExtractArticleCommand>
+    for ExtractArticleHandler<FER, HC>
+{
+    type Response = ();
+    type Error = ExtractArticleError;
+
+    async fn handle(&self, cmd: ExtractArticleCommand) -> Result<Self::Response, Self::Error> {
+        let mut feed_entry = self.feed_entry_repository.find_by_id(cmd.id).await?.ok_or(
+            ExtractArticleError::Feed(FeedError::EntryNotFound(cmd.id.as_inner())),
+        )?;
+
+        let result = self.http_client.get_response(feed_entry.link()).await;
+
+        // Rate limits and server errors are temporary, so the entry is left pending and retried
+        // on the next run
+        if let Ok(resp) = &result
+            && (resp.status() == StatusCode::TOO_MANY_REQUESTS || resp.status().is_server_error())
+        {
+            return Err(ExtractArticleError::Http(colette_http::Error::Status(
+                resp.status(),
+            )));
+        }
+
+        // Other failed downloads are recorded as well, so that broken links are not retried
+        // forever
+        let result = result.and_then(|resp| {
+            if resp.status().is_success() {
+                Ok(resp)
+            } else {
+                Err(colette_http::Error::Status(resp.status()))
+            }
+        });
+        let article = result.as_ref().ok().and_then(|resp| {
+            let content_type = resp
+                .headers()
+                .get(CONTENT_TYPE)
+                .and_then(|e| e.to_str().ok());
+            let body = to_utf8(resp.body().clone(), content_type);
+            let html = String::from_utf8_lossy(&body);
+
+            readability::extract_article(&html).map(|e| sanitize_html(&e, feed_entry.link()))
+        });
+
+        feed_entry.set_article(article);
+        self.feed_entry_repository.save(&feed_entry).await?;
+
+        result?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ExtractArticleError {
+    #[error(transparent)]
+    Feed(#[from] FeedError),
+
+    #[error(transparent)]
+    Http(#[from] colette_http::Error),
+
+    #[error(transparent)]
+    Repository(#[from] RepositoryError),
+}
@@ -1,7 +1,15 @@
 pub use detect_feeds::*;
+pub use extract_article::*;
+pub use receive_websub::*;
 pub use refresh_feed::*;
 pub use scrape_feed::*;
+pub use subscribe_websub::*;
+pub use verify_websub::*;
 
 mod detect_feeds;
+mod extract_article;
+mod receive_websub;
 mod refresh_feed;
 mod scrape_feed;
+mod subscribe_websub;
+mod verify_websub;
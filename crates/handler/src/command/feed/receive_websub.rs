@@ -0,0 +1,106 @@
+use bytes::Bytes;
+use colette_common::RepositoryError;
+use colette_crud::RuleRepository;
+use colette_ingestion::{FeedBatch, FeedEntry, FeedError, FeedId, FeedRepository};
+use colette_util::{
+    hex_decode, hmac_sha1_verify, hmac_sha256_verify, hmac_sha384_verify, hmac_sha512_verify,
+};
+use uuid::ContextV7;
+
+use crate::{Handler, apply_feed_rules};
+
+#[derive(Debug, Clone)]
+pub struct ReceiveWebSubCommand {
+    pub id: FeedId,
+    /// Value of the `X-Hub-Signature` header
+    pub signature: Option<String>,
+    pub content_type: Option<String>,
+    pub body: Bytes,
+}
+
+pub struct ReceiveWebSubHandler<FR: FeedRepository, RR: RuleRepository> {
+    feed_repository: FR,
+    rule_repository: RR,
+}
+
+impl<FR: FeedRepository, RR: RuleRepository> ReceiveWebSubHandler<FR, RR> {
+    pub fn new(feed_repository: FR, rule_repository: RR) -> Self {
+        Self {
+            feed_repository,
+            rule_repository,
+        }
+    }
+}
+
+impl<FR: FeedRepository, RR: RuleRepository> Handler<ReceiveWebSubCommand>
+    for ReceiveWebSubHandler<FR, RR>
+{
+    type Response = ();
+    type Error = ReceiveWebSubError;
+
+    async fn handle(&self, cmd: ReceiveWebSubCommand) -> Result<Self::Response, Self::Error> {
+        let mut feed =
+            self.feed_repository
+                .find_by_id(cmd.id)
+                .await?
+                .ok_or(ReceiveWebSubError::Feed(FeedError::NotFound(
+                    cmd.id.as_inner(),
+                )))?;
+
+        let Some(secret) = feed.websub_secret() else {
+            return Err(ReceiveWebSubError::Signature);
+        };
+        let Some((method, signature)) = cmd.signature.as_deref().and_then(|e| e.split_once('='))
+        else {
+            return Err(ReceiveWebSubError::Signature);
+        };
+        let signature = hex_decode(signature).map_err(|_| ReceiveWebSubError::Signature)?;
+
+        let verify = match method {
+            "sha1" => hmac_sha1_verify,
+            "sha256" => hmac_sha256_verify,
+            "sha384" => hmac_sha384_verify,
+            "sha512" => hmac_sha512_verify,
+            _ => return Err(ReceiveWebSubError::Signature),
+        };
+        if !verify(secret.as_bytes(), &cmd.body, &signature) {
+            return Err(ReceiveWebSubError::Signature);
+        }
+
+        let processed = colette_scraper::feed::parse_feed(cmd.body, cmd.content_type.as_deref())?;
+
+        feed.record_success();
+
+        let uuid_ctx = ContextV7::new();
+        let feed_entries: Vec<FeedEntry> = processed
+            .entries
+            .into_iter()
+            .map(|e| FeedEntry::from_processed(&uuid_ctx, e))
+            .collect();
+
+        let feed_id = feed.id().as_inner();
+
+        self.feed_repository
+            .upsert(FeedBatch { feed, feed_entries })
+            .await?;
+
+        apply_feed_rules(&self.rule_repository, feed_id).await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ReceiveWebSubError {
+    #[error("invalid WebSub signature")]
+    Signature,
+
+    #[error(transparent)]
+    Feed(#[from] FeedError),
+
+    #[error(transparent)]
+    Scraper(#[from] colette_scraper::feed::FeedError),
+
+    #[error(transparent)]
+    Repository(#[from] RepositoryError),
+}
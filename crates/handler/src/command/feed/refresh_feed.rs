@@ -1,36 +1,50 @@
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
+use chrono::{TimeDelta, Utc};
 use colette_common::RepositoryError;
-use colette_http::HttpClient;
-use colette_ingestion::{FeedBatch, FeedEntry, FeedError, FeedId, FeedRepository};
-use colette_scraper::feed::FeedScraper;
+use colette_crud::RuleRepository;
+use colette_http::{HttpClient, Validators};
+use colette_ingestion::{
+    FeedBatch, FeedEntry, FeedError, FeedId, FeedRepository, POSTING_WINDOW_DAYS,
+    RefreshIntervalBounds,
+};
+use colette_scraper::feed::{ConditionalScrape, FeedScraper};
 use uuid::ContextV7;
 
-use crate::Handler;
+use crate::{Handler, apply_feed_rules};
 
 #[derive(Debug, Clone)]
 pub struct RefreshFeedCommand {
     pub id: FeedId,
 }
 
-pub struct RefreshFeedHandler<FR: FeedRepository, HC: HttpClient> {
+pub struct RefreshFeedHandler<FR: FeedRepository, RR: RuleRepository, HC: HttpClient> {
     feed_repository: FR,
+    rule_repository: RR,
 
     feed_scraper: Arc<FeedScraper<HC>>,
+    refresh_interval_bounds: RefreshIntervalBounds,
 }
 
-impl<FR: FeedRepository, HC: HttpClient> RefreshFeedHandler<FR, HC> {
-    pub fn new(feed_repository: FR, feed_scraper: Arc<FeedScraper<HC>>) -> Self {
+impl<FR: FeedRepository, RR: RuleRepository, HC: HttpClient> RefreshFeedHandler<FR, RR, HC> {
+    pub fn new(
+        feed_repository: FR,
+        rule_repository: RR,
+        feed_scraper: Arc<FeedScraper<HC>>,
+        refresh_interval_bounds: RefreshIntervalBounds,
+    ) -> Self {
         Self {
             feed_repository,
+            rule_repository,
 
             feed_scraper,
+            refresh_interval_bounds,
         }
     }
 }
 
-impl<FR: FeedRepository, HC: HttpClient> Handler<RefreshFeedCommand>
-    for RefreshFeedHandler<FR, HC>
+impl<FR: FeedRepository, RR: RuleRepository, HC: HttpClient> Handler<RefreshFeedCommand>
+    for RefreshFeedHandler<FR, RR, HC>
 {
     type Response = ();
     type Error = RefreshFeedError;
@@ -45,18 +59,88 @@ impl<FR: FeedRepository, HC: HttpClient> Handler<RefreshFeedCommand>
                 )))?;
 
         let mut source_url = feed.source_url().to_owned();
-
-        let processed = match self.feed_scraper.scrape(&mut source_url).await {
-            Ok(processed) => Ok(processed),
+        let validators = Validators {
+            etag: feed.etag().map(ToOwned::to_owned),
+            last_modified: feed.last_modified().map(ToOwned::to_owned),
+        };
+
+        let scraped = match self
+            .feed_scraper
+            .scrape_if_modified(&mut source_url, &validators)
+            .await
+        {
+            Ok(scraped) => scraped,
+            // The failure is recorded on the feed rather than returned, so that retries of the job
+            // don't count a single outage more than once
             Err(e) => {
+                feed.record_failure(e.to_string());
+
+                let feed_id = feed.id().as_inner();
+
                 self.feed_repository
-                    .mark_as_failed(source_url.clone())
+                    .upsert(FeedBatch {
+                        feed,
+                        feed_entries: Vec::new(),
+                    })
                     .await?;
 
-                Err(e)
+                // Entries saved by a previous attempt may still be waiting for rules
+                apply_feed_rules(&self.rule_repository, feed_id).await?;
+
+                return Ok(());
             }
-        }?;
+        };
+
+        feed.record_success();
+
+        let mut history = self
+            .feed_repository
+            .find_entry_history(feed.id(), Utc::now() - TimeDelta::days(POSTING_WINDOW_DAYS))
+            .await?
+            .into_iter()
+            .collect::<HashMap<_, _>>();
+
+        let processed = match scraped {
+            ConditionalScrape::Modified(processed) => processed,
+            ConditionalScrape::NotModified { max_age } => {
+                feed.adapt_refresh_interval(
+                    history.into_values(),
+                    max_age,
+                    &self.refresh_interval_bounds,
+                );
+
+                let feed_id = feed.id().as_inner();
 
+                self.feed_repository
+                    .upsert(FeedBatch {
+                        feed,
+                        feed_entries: Vec::new(),
+                    })
+                    .await?;
+
+                // Entries saved by a previous attempt may still be waiting for rules
+                apply_feed_rules(&self.rule_repository, feed_id).await?;
+
+                return Ok(());
+            }
+        };
+
+        feed.set_validators(
+            processed.validators.etag,
+            processed.validators.last_modified,
+        );
+        history.extend(
+            processed
+                .entries
+                .iter()
+                .map(|e| (e.link.clone(), e.published)),
+        );
+        feed.adapt_refresh_interval(
+            history.into_values(),
+            processed.ttl,
+            &self.refresh_interval_bounds,
+        );
+        feed.set_hub(processed.hub_url, processed.self_url);
         feed.set_link(processed.link);
         feed.set_title(processed.title);
         if let Some(description) = processed.description {
@@ -66,26 +150,20 @@ impl<FR: FeedRepository, HC: HttpClient> Handler<RefreshFeedCommand>
         }
 
         let uuid_ctx = ContextV7::new();
-        let feed_entries = processed
+        let feed_entries: Vec<FeedEntry> = processed
             .entries
             .into_iter()
-            .map(|e| {
-                FeedEntry::new(
-                    &uuid_ctx,
-                    e.link,
-                    e.title,
-                    e.published,
-                    e.description,
-                    e.author,
-                    e.thumbnail,
-                )
-            })
+            .map(|e| FeedEntry::from_processed(&uuid_ctx, e))
             .collect();
 
+        let feed_id = feed.id().as_inner();
+
         self.feed_repository
             .upsert(FeedBatch { feed, feed_entries })
             .await?;
 
+        apply_feed_rules(&self.rule_repository, feed_id).await?;
+
         Ok(())
     }
 }
@@ -95,9 +173,6 @@ pub enum RefreshFeedError {
     #[error(transparent)]
     Feed(#[from] FeedError),
 
-    #[error(transparent)]
-    Scraper(#[from] colette_scraper::feed::FeedError),
-
     #[error(transparent)]
     Repository(#[from] RepositoryError),
 }
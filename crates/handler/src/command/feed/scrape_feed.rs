@@ -36,40 +36,28 @@ impl<FR: FeedRepository, HC: HttpClient> Handler<ScrapeFeedCommand> for ScrapeFe
         let feed = match self.feed_repository.find_by_source_url(&cmd.url).await? {
             Some(feed) => feed,
             None => {
-                let processed = match self.feed_scraper.scrape(&mut cmd.url).await {
-                    Ok(processed) => Ok(processed),
-                    Err(e) => {
-                        self.feed_repository.mark_as_failed(cmd.url.clone()).await?;
-
-                        Err(e)
-                    }
-                }?;
+                let processed = self.feed_scraper.scrape(&mut cmd.url).await?;
 
                 let is_custom = processed.link == cmd.url;
 
-                let feed = Feed::new(
+                let mut feed = Feed::new(
                     cmd.url,
                     processed.link,
                     processed.title,
                     processed.description,
                     is_custom,
                 );
+                feed.set_validators(
+                    processed.validators.etag,
+                    processed.validators.last_modified,
+                );
+                feed.set_hub(processed.hub_url, processed.self_url);
 
                 let uuid_ctx = ContextV7::new();
                 let feed_entries = processed
                     .entries
                     .into_iter()
-                    .map(|e| {
-                        FeedEntry::new(
-                            &uuid_ctx,
-                            e.link,
-                            e.title,
-                            e.published,
-                            e.description,
-                            e.author,
-                            e.thumbnail,
-                        )
-                    })
+                    .map(|e| FeedEntry::from_processed(&uuid_ctx, e))
                     .collect();
 
                 let created = feed.clone();
@@ -0,0 +1,150 @@
+use colette_common::RepositoryError;
+use colette_http::HttpClient;
+use colette_ingestion::{FeedError, FeedId, FeedRepository, WEBSUB_LEASE_SECONDS};
+use colette_util::{hex_encode, random_generate};
+use http::{Request, header};
+use url::{Url, form_urlencoded};
+
+use crate::Handler;
+
+#[derive(Debug, Clone)]
+pub struct SubscribeWebSubCommand {
+    pub id: FeedId,
+}
+
+pub struct SubscribeWebSubHandler<FR: FeedRepository, HC: HttpClient> {
+    feed_repository: FR,
+    http_client: HC,
+    callback_base_url: Url,
+}
+
+impl<FR: FeedRepository, HC: HttpClient> SubscribeWebSubHandler<FR, HC> {
+    pub fn new(feed_repository: FR, http_client: HC, callback_base_url: Url) -> Self {
+        Self {
+            feed_repository,
+            http_client,
+            callback_base_url,
+        }
+    }
+}
+
+impl<FR: FeedRepository, HC: HttpClient> Handler<SubscribeWebSubCommand>
+    for SubscribeWebSubHandler<FR, HC>
+{
+    type Response = ();
+    type Error = SubscribeWebSubError;
+
+    async fn handle(&self, cmd: SubscribeWebSubCommand) -> Result<Self::Response, Self::Error> {
+        let mut feed =
+            self.feed_repository
+                .find_by_id(cmd.id)
+                .await?
+                .ok_or(SubscribeWebSubError::Feed(FeedError::NotFound(
+                    cmd.id.as_inner(),
+                )))?;
+
+        let Some(hub_url) = feed.hub_url().cloned() else {
+            return Err(SubscribeWebSubError::HubNotFound);
+        };
+
+        let secret = match feed.websub_secret() {
+            Some(secret) => secret.to_owned(),
+            None => hex_encode(&random_generate(32)),
+        };
+        let topic = feed.websub_topic().to_owned();
+        let callback =
+            callback_url(&self.callback_base_url, cmd.id).ok_or(SubscribeWebSubError::Callback)?;
+
+        // The hub may verify intent before responding, so the secret must be stored first
+        feed.set_websub_secret(secret.clone());
+        self.feed_repository.update_websub(&feed).await?;
+
+        let body = form_urlencoded::Serializer::new(String::new())
+            .append_pair("hub.mode", "subscribe")
+            .append_pair("hub.topic", topic.as_str())
+            .append_pair("hub.callback", callback.as_str())
+            .append_pair("hub.secret", &secret)
+            .append_pair("hub.lease_seconds", &WEBSUB_LEASE_SECONDS.to_string())
+            .finish();
+
+        let request = Request::post(hub_url.as_str())
+            .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded")
+            .body(body.into())
+            .map_err(colette_http::Error::from)?;
+
+        let resp = self.http_client.send(request).await?;
+        if !resp.status().is_success() {
+            return Err(SubscribeWebSubError::Hub(resp.status().as_u16()));
+        }
+
+        Ok(())
+    }
+}
+
+/// Appends the callback path to the base URL, keeping any path the base URL already has
+fn callback_url(base: &Url, id: FeedId) -> Option<Url> {
+    let mut url = base.clone();
+    url.path_segments_mut().ok()?.pop_if_empty().extend([
+        "api",
+        "feeds",
+        &id.as_inner().to_string(),
+        "websub",
+    ]);
+
+    Some(url)
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum SubscribeWebSubError {
+    #[error("feed does not advertise a WebSub hub")]
+    HubNotFound,
+
+    #[error("could not build WebSub callback URL")]
+    Callback,
+
+    #[error("WebSub hub rejected subscription with status: {0}")]
+    Hub(u16),
+
+    #[error(transparent)]
+    Feed(#[from] FeedError),
+
+    #[error(transparent)]
+    Http(#[from] colette_http::Error),
+
+    #[error(transparent)]
+    Repository(#[from] RepositoryError),
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use super::*;
+
+    #[test]
+    fn keeps_base_url_path() {
+        let id = FeedId::from(Uuid::nil());
+        let expected = "/api/feeds/00000000-0000-0000-0000-000000000000/websub";
+
+        for (base, prefix) in [
+            ("https://example.com", "https://example.com"),
+            ("https://example.com/", "https://example.com"),
+            ("https://example.com/colette", "https://example.com/colette"),
+            (
+                "https://example.com/colette/",
+                "https://example.com/colette",
+            ),
+        ] {
+            let url = callback_url(&base.parse().unwrap(), id).unwrap();
+
+            assert_eq!(url.as_str(), format!("{prefix}{expected}"));
+        }
+    }
+
+    #[test]
+    fn rejects_base_url_without_path() {
+        let base = "mailto:colette@example.com".parse().unwrap();
+
+        assert_eq!(callback_url(&base, FeedId::from(Uuid::nil())), None);
+    }
+}
@@ -0,0 +1,84 @@
+use colette_common::RepositoryError;
+use colette_ingestion::{FeedError, FeedId, FeedRepository, WEBSUB_LEASE_SECONDS, WebSubMode};
+use url::Url;
+
+use crate::Handler;
+
+#[derive(Debug, Clone)]
+pub struct VerifyWebSubCommand {
+    pub id: FeedId,
+    pub mode: WebSubMode,
+    pub topic: Url,
+    pub challenge: Option<String>,
+    pub lease_seconds: Option<u32>,
+}
+
+pub struct VerifyWebSubHandler<FR: FeedRepository> {
+    feed_repository: FR,
+}
+
+impl<FR: FeedRepository> VerifyWebSubHandler<FR> {
+    pub fn new(feed_repository: FR) -> Self {
+        Self { feed_repository }
+    }
+}
+
+impl<FR: FeedRepository> Handler<VerifyWebSubCommand> for VerifyWebSubHandler<FR> {
+    type Response = String;
+    type Error = VerifyWebSubError;
+
+    async fn handle(&self, cmd: VerifyWebSubCommand) -> Result<Self::Response, Self::Error> {
+        let mut feed =
+            self.feed_repository
+                .find_by_id(cmd.id)
+                .await?
+                .ok_or(VerifyWebSubError::Feed(FeedError::NotFound(
+                    cmd.id.as_inner(),
+                )))?;
+
+        let is_subscribed =
+            feed.websub_secret().is_some() && feed.websub_topic().as_str() == cmd.topic.as_str();
+
+        match cmd.mode {
+            WebSubMode::Subscribe => {
+                if !is_subscribed {
+                    return Err(VerifyWebSubError::Unexpected);
+                }
+
+                feed.confirm_websub_lease(cmd.lease_seconds.unwrap_or(WEBSUB_LEASE_SECONDS));
+            }
+            WebSubMode::Unsubscribe => {
+                if is_subscribed {
+                    return Err(VerifyWebSubError::Unexpected);
+                }
+
+                feed.remove_websub_lease();
+            }
+            // A denial is only honoured for the current topic, so that a stale or forged denial
+            // can't drop the lease of the active subscription
+            WebSubMode::Denied => {
+                if feed.websub_topic().as_str() != cmd.topic.as_str() {
+                    return Err(VerifyWebSubError::Unexpected);
+                }
+
+                feed.remove_websub_lease();
+            }
+        }
+
+        self.feed_repository.update_websub(&feed).await?;
+
+        Ok(cmd.challenge.unwrap_or_default())
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum VerifyWebSubError {
+    #[error("verification does not match a pending subscription")]
+    Unexpected,
+
+    #[error(transparent)]
+    Feed(#[from] FeedError),
+
+    #[error(transparent)]
+    Repository(#[from] RepositoryError),
+}
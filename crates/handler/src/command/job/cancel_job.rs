@@ -0,0 +1,44 @@
+use colette_authentication::UserId;
+use colette_queue::JobStore;
+use uuid::Uuid;
+
+use crate::Handler;
+
+#[derive(Debug, Clone)]
+pub struct CancelJobCommand {
+    pub id: Uuid,
+    pub user_id: UserId,
+}
+
+pub struct CancelJobHandler<JS: JobStore> {
+    job_store: JS,
+}
+
+impl<JS: JobStore> CancelJobHandler<JS> {
+    pub fn new(job_store: JS) -> Self {
+        Self { job_store }
+    }
+}
+
+impl<JS: JobStore> Handler<CancelJobCommand> for CancelJobHandler<JS> {
+    type Response = ();
+    type Error = CancelJobError;
+
+    async fn handle(&self, cmd: CancelJobCommand) -> Result<Self::Response, Self::Error> {
+        self.job_store
+            .find_by_id(cmd.id)
+            .await?
+            .filter(|e| e.user_id == Some(cmd.user_id.as_inner()))
+            .ok_or(colette_queue::Error::NotFound(cmd.id))?;
+
+        self.job_store.cancel(cmd.id).await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CancelJobError {
+    #[error(transparent)]
+    Queue(#[from] colette_queue::Error),
+}
@@ -0,0 +1,5 @@
+pub use cancel_job::*;
+pub use retry_job::*;
+
+mod cancel_job;
+mod retry_job;
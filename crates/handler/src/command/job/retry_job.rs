@@ -0,0 +1,44 @@
+use colette_authentication::UserId;
+use colette_queue::JobStore;
+use uuid::Uuid;
+
+use crate::Handler;
+
+#[derive(Debug, Clone)]
+pub struct RetryJobCommand {
+    pub id: Uuid,
+    pub user_id: UserId,
+}
+
+pub struct RetryJobHandler<JS: JobStore> {
+    job_store: JS,
+}
+
+impl<JS: JobStore> RetryJobHandler<JS> {
+    pub fn new(job_store: JS) -> Self {
+        Self { job_store }
+    }
+}
+
+impl<JS: JobStore> Handler<RetryJobCommand> for RetryJobHandler<JS> {
+    type Response = ();
+    type Error = RetryJobError;
+
+    async fn handle(&self, cmd: RetryJobCommand) -> Result<Self::Response, Self::Error> {
+        self.job_store
+            .find_by_id(cmd.id)
+            .await?
+            .filter(|e| e.user_id == Some(cmd.user_id.as_inner()))
+            .ok_or(colette_queue::Error::NotFound(cmd.id))?;
+
+        self.job_store.retry(cmd.id).await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum RetryJobError {
+    #[error(transparent)]
+    Queue(#[from] colette_queue::Error),
+}
@@ -4,7 +4,9 @@ pub use bookmark::*;
 pub use collection::*;
 pub use entry::*;
 pub use feed::*;
+pub use job::*;
 pub use pat::*;
+pub use rule::*;
 pub use subscription::*;
 pub use tag::*;
 
@@ -14,6 +16,8 @@ mod bookmark;
 mod collection;
 mod entry;
 mod feed;
+mod job;
 mod pat;
+mod rule;
 mod subscription;
 mod tag;
@@ -0,0 +1,58 @@
+use colette_authentication::UserId;
+use colette_common::RepositoryError;
+use colette_crud::{
+    EntryFilter, Rule, RuleAction, RuleActions, RuleCondition, RuleError, RuleRepository, RuleTitle,
+};
+
+use crate::Handler;
+
+#[derive(Debug, Clone)]
+pub struct CreateRuleCommand {
+    pub title: String,
+    pub condition: EntryFilter,
+    pub actions: Vec<RuleAction>,
+    pub enabled: bool,
+    pub user_id: UserId,
+}
+
+pub struct CreateRuleHandler<RR: RuleRepository> {
+    rule_repository: RR,
+}
+
+impl<RR: RuleRepository> CreateRuleHandler<RR> {
+    pub fn new(rule_repository: RR) -> Self {
+        Self { rule_repository }
+    }
+}
+
+impl<RR: RuleRepository> Handler<CreateRuleCommand> for CreateRuleHandler<RR> {
+    type Response = Rule;
+    type Error = CreateRuleError;
+
+    async fn handle(&self, cmd: CreateRuleCommand) -> Result<Self::Response, Self::Error> {
+        let title = RuleTitle::new(cmd.title.clone())?;
+        let condition = RuleCondition::new(cmd.condition)?;
+        let actions = RuleActions::new(cmd.actions)?;
+
+        let rule = Rule::new(title, condition, actions, cmd.enabled, cmd.user_id);
+
+        self.rule_repository
+            .save(&rule)
+            .await
+            .map_err(|e| match e {
+                RepositoryError::Duplicate => CreateRuleError::Rule(RuleError::Conflict(cmd.title)),
+                _ => CreateRuleError::Repository(e),
+            })?;
+
+        Ok(rule)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CreateRuleError {
+    #[error(transparent)]
+    Rule(#[from] RuleError),
+
+    #[error(transparent)]
+    Repository(#[from] RepositoryError),
+}
@@ -0,0 +1,49 @@
+use colette_authentication::UserId;
+use colette_common::RepositoryError;
+use colette_crud::{RuleError, RuleId, RuleRepository};
+
+use crate::Handler;
+
+#[derive(Debug, Clone)]
+pub struct DeleteRuleCommand {
+    pub id: RuleId,
+    pub user_id: UserId,
+}
+
+pub struct DeleteRuleHandler<RR: RuleRepository> {
+    rule_repository: RR,
+}
+
+impl<RR: RuleRepository> DeleteRuleHandler<RR> {
+    pub fn new(rule_repository: RR) -> Self {
+        Self { rule_repository }
+    }
+}
+
+impl<RR: RuleRepository> Handler<DeleteRuleCommand> for DeleteRuleHandler<RR> {
+    type Response = ();
+    type Error = DeleteRuleError;
+
+    async fn handle(&self, cmd: DeleteRuleCommand) -> Result<Self::Response, Self::Error> {
+        self.rule_repository
+            .delete_by_id(cmd.id, cmd.user_id)
+            .await
+            .map_err(|e| match e {
+                RepositoryError::NotFound => {
+                    DeleteRuleError::Rule(RuleError::NotFound(cmd.id.as_inner()))
+                }
+                _ => DeleteRuleError::Repository(e),
+            })?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum DeleteRuleError {
+    #[error(transparent)]
+    Rule(#[from] RuleError),
+
+    #[error(transparent)]
+    Repository(#[from] RepositoryError),
+}
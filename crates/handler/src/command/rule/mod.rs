@@ -0,0 +1,9 @@
+pub use create_rule::*;
+pub use delete_rule::*;
+pub use run_rule::*;
+pub use update_rule::*;
+
+mod create_rule;
+mod delete_rule;
+mod run_rule;
+mod update_rule;
@@ -0,0 +1,201 @@
+use colette_authentication::UserId;
+use colette_common::RepositoryError;
+use colette_crud::{RuleApplyParams, RuleError, RuleId, RuleRepository};
+use uuid::Uuid;
+
+use crate::Handler;
+
+/// Runs a rule against all existing entries of the user, regardless of whether it is enabled
+#[derive(Debug, Clone)]
+pub struct RunRuleCommand {
+    pub id: RuleId,
+    pub user_id: UserId,
+}
+
+pub struct RunRuleHandler<RR: RuleRepository> {
+    rule_repository: RR,
+}
+
+impl<RR: RuleRepository> RunRuleHandler<RR> {
+    pub fn new(rule_repository: RR) -> Self {
+        Self { rule_repository }
+    }
+}
+
+impl<RR: RuleRepository> Handler<RunRuleCommand> for RunRuleHandler<RR> {
+    type Response = u64;
+    type Error = RunRuleError;
+
+    async fn handle(&self, cmd: RunRuleCommand) -> Result<Self::Response, Self::Error> {
+        let rule = self
+            .rule_repository
+            .find_by_id(cmd.id, cmd.user_id)
+            .await?
+            .ok_or(RuleError::NotFound(cmd.id.as_inner()))?;
+
+        let matched = self
+            .rule_repository
+            .apply(&rule, RuleApplyParams::default())
+            .await?;
+
+        Ok(matched)
+    }
+}
+
+/// Applies the enabled rules of every subscriber of a feed to the entries they haven't been applied
+/// to yet. Pending entries are tracked in the database rather than passed in, so that a retried
+/// refresh still applies the rules to entries saved by the failed attempt. A failing rule doesn't
+/// keep the other rules from running, but the entries stay pending and the first error is returned,
+/// so that the job is retried.
+pub(crate) async fn apply_feed_rules<RR: RuleRepository>(
+    rule_repository: &RR,
+    feed_id: Uuid,
+) -> Result<(), RepositoryError> {
+    let feed_entry_ids = rule_repository.find_pending_entries(feed_id).await?;
+    if feed_entry_ids.is_empty() {
+        return Ok(());
+    }
+
+    let mut error = None;
+    for rule in rule_repository.find_enabled_by_feed(feed_id).await? {
+        if let Err(e) = rule_repository
+            .apply(
+                &rule,
+                RuleApplyParams {
+                    feed_entry_ids: Some(feed_entry_ids.clone()),
+                },
+            )
+            .await
+        {
+            tracing::error!("failed to apply rule {}: {e}", rule.id().as_inner());
+            error.get_or_insert(e);
+        }
+    }
+
+    if let Some(e) = error {
+        return Err(e);
+    }
+
+    rule_repository.mark_entries_applied(feed_entry_ids).await
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum RunRuleError {
+    #[error(transparent)]
+    Rule(#[from] RuleError),
+
+    #[error(transparent)]
+    Repository(#[from] RepositoryError),
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+    use colette_crud::{
+        BooleanOp, EntryBooleanField, EntryFilter, Rule, RuleAction, RuleActions, RuleCondition,
+        RuleTitle,
+    };
+
+    use super::*;
+
+    struct FakeRuleRepository {
+        rules: Vec<Rule>,
+        failing: Option<RuleId>,
+        applied: AtomicUsize,
+        marked: AtomicBool,
+    }
+
+    impl FakeRuleRepository {
+        fn new(rules: Vec<Rule>, failing: Option<RuleId>) -> Self {
+            Self {
+                rules,
+                failing,
+                applied: AtomicUsize::new(0),
+                marked: AtomicBool::new(false),
+            }
+        }
+    }
+
+    impl RuleRepository for FakeRuleRepository {
+        async fn find_by_id(
+            &self,
+            _id: RuleId,
+            _user_id: UserId,
+        ) -> Result<Option<Rule>, RepositoryError> {
+            unimplemented!()
+        }
+
+        async fn find_enabled_by_feed(&self, _feed_id: Uuid) -> Result<Vec<Rule>, RepositoryError> {
+            Ok(self.rules.clone())
+        }
+
+        async fn save(&self, _data: &Rule) -> Result<(), RepositoryError> {
+            unimplemented!()
+        }
+
+        async fn delete_by_id(&self, _id: RuleId, _user_id: UserId) -> Result<(), RepositoryError> {
+            unimplemented!()
+        }
+
+        async fn apply(
+            &self,
+            data: &Rule,
+            _params: RuleApplyParams,
+        ) -> Result<u64, RepositoryError> {
+            self.applied.fetch_add(1, Ordering::SeqCst);
+            if self.failing == Some(data.id()) {
+                return Err(RepositoryError::NotFound);
+            }
+
+            Ok(1)
+        }
+
+        async fn find_pending_entries(&self, _feed_id: Uuid) -> Result<Vec<Uuid>, RepositoryError> {
+            Ok(vec![Uuid::now_v7()])
+        }
+
+        async fn mark_entries_applied(
+            &self,
+            _feed_entry_ids: Vec<Uuid>,
+        ) -> Result<(), RepositoryError> {
+            self.marked.store(true, Ordering::SeqCst);
+
+            Ok(())
+        }
+    }
+
+    fn rule() -> Rule {
+        Rule::new(
+            RuleTitle::new("Rule".into()).unwrap(),
+            RuleCondition::new(EntryFilter::Boolean {
+                field: EntryBooleanField::Starred,
+                op: BooleanOp::Equals(false),
+            })
+            .unwrap(),
+            RuleActions::new(vec![RuleAction::MarkAsRead]).unwrap(),
+            true,
+            Uuid::now_v7().into(),
+        )
+    }
+
+    #[tokio::test]
+    async fn marks_entries_applied_when_every_rule_succeeds() {
+        let repository = FakeRuleRepository::new(vec![rule(), rule()], None);
+
+        apply_feed_rules(&repository, Uuid::now_v7()).await.unwrap();
+
+        assert_eq!(repository.applied.load(Ordering::SeqCst), 2);
+        assert!(repository.marked.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn keeps_entries_pending_when_a_rule_fails() {
+        let failing = rule();
+        let repository = FakeRuleRepository::new(vec![failing.clone(), rule()], Some(failing.id()));
+
+        assert!(apply_feed_rules(&repository, Uuid::now_v7()).await.is_err());
+        assert_eq!(repository.applied.load(Ordering::SeqCst), 2);
+        assert!(!repository.marked.load(Ordering::SeqCst));
+    }
+}
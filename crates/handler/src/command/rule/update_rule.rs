@@ -0,0 +1,75 @@
+use colette_authentication::UserId;
+use colette_common::RepositoryError;
+use colette_crud::{
+    EntryFilter, Rule, RuleAction, RuleActions, RuleCondition, RuleError, RuleId, RuleRepository,
+    RuleTitle,
+};
+
+use crate::Handler;
+
+#[derive(Debug, Clone)]
+pub struct UpdateRuleCommand {
+    pub id: RuleId,
+    pub title: Option<String>,
+    pub condition: Option<EntryFilter>,
+    pub actions: Option<Vec<RuleAction>>,
+    pub enabled: Option<bool>,
+    pub user_id: UserId,
+}
+
+pub struct UpdateRuleHandler<RR: RuleRepository> {
+    rule_repository: RR,
+}
+
+impl<RR: RuleRepository> UpdateRuleHandler<RR> {
+    pub fn new(rule_repository: RR) -> Self {
+        Self { rule_repository }
+    }
+}
+
+impl<RR: RuleRepository> Handler<UpdateRuleCommand> for UpdateRuleHandler<RR> {
+    type Response = Rule;
+    type Error = UpdateRuleError;
+
+    async fn handle(&self, cmd: UpdateRuleCommand) -> Result<Self::Response, Self::Error> {
+        let mut rule = self
+            .rule_repository
+            .find_by_id(cmd.id, cmd.user_id)
+            .await?
+            .ok_or(RuleError::NotFound(cmd.id.as_inner()))?;
+
+        if let Some(title) = cmd.title.clone().map(RuleTitle::new).transpose()? {
+            rule.set_title(title);
+        }
+        if let Some(condition) = cmd.condition.map(RuleCondition::new).transpose()? {
+            rule.set_condition(condition);
+        }
+        if let Some(actions) = cmd.actions.map(RuleActions::new).transpose()? {
+            rule.set_actions(actions);
+        }
+        if let Some(enabled) = cmd.enabled {
+            rule.set_enabled(enabled);
+        }
+
+        self.rule_repository
+            .save(&rule)
+            .await
+            .map_err(|e| match e {
+                RepositoryError::Duplicate => {
+                    UpdateRuleError::Rule(RuleError::Conflict(rule.title().as_inner().to_owned()))
+                }
+                _ => UpdateRuleError::Repository(e),
+            })?;
+
+        Ok(rule)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum UpdateRuleError {
+    #[error(transparent)]
+    Rule(#[from] RuleError),
+
+    #[error(transparent)]
+    Repository(#[from] RepositoryError),
+}
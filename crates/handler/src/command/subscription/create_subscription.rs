@@ -13,6 +13,7 @@ pub struct CreateSubscriptionCommand {
     pub title: String,
     pub description: Option<String>,
     pub feed_id: FeedId,
+    pub fetch_full_article: bool,
     pub user_id: UserId,
 }
 
@@ -41,7 +42,8 @@ impl<SR: SubscriptionRepository> Handler<CreateSubscriptionCommand>
             .map(SubscriptionDescription::new)
             .transpose()?;
 
-        let subscription = Subscription::new(title, description, cmd.feed_id, cmd.user_id);
+        let mut subscription = Subscription::new(title, description, cmd.feed_id, cmd.user_id);
+        subscription.set_fetch_full_article(cmd.fetch_full_article);
 
         self.subscription_repository
             .save(&subscription)
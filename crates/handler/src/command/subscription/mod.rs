@@ -2,10 +2,12 @@ pub use create_subscription::*;
 pub use delete_subscription::*;
 pub use import_subscriptions::*;
 pub use link_subscription_tags::*;
+pub use refresh_subscription::*;
 pub use update_subscription::*;
 
 mod create_subscription;
 mod delete_subscription;
 mod import_subscriptions;
 mod link_subscription_tags;
+mod refresh_subscription;
 mod update_subscription;
@@ -0,0 +1,72 @@
+use colette_authentication::UserId;
+use colette_common::RepositoryError;
+use colette_crud::{SubscriptionError, SubscriptionId};
+use colette_ingestion::ScrapeFeedJobData;
+use colette_queue::{Job, JobProducer};
+use tokio::sync::Mutex;
+
+use crate::{Handler, SubscriptionQueryRepository};
+
+#[derive(Debug, Clone)]
+pub struct RefreshSubscriptionCommand {
+    pub id: SubscriptionId,
+    pub user_id: UserId,
+}
+
+/// Queues the feed of a subscription for an immediate refresh. A successful refresh also resets
+/// the health of a failing or disabled feed.
+pub struct RefreshSubscriptionHandler<SQR: SubscriptionQueryRepository, JP: JobProducer> {
+    subscription_query_repository: SQR,
+    scrape_feed_producer: Mutex<JP>,
+}
+
+impl<SQR: SubscriptionQueryRepository, JP: JobProducer> RefreshSubscriptionHandler<SQR, JP> {
+    pub fn new(subscription_query_repository: SQR, scrape_feed_producer: JP) -> Self {
+        Self {
+            subscription_query_repository,
+            scrape_feed_producer: Mutex::new(scrape_feed_producer),
+        }
+    }
+}
+
+impl<SQR: SubscriptionQueryRepository, JP: JobProducer> Handler<RefreshSubscriptionCommand>
+    for RefreshSubscriptionHandler<SQR, JP>
+{
+    type Response = ();
+    type Error = RefreshSubscriptionError;
+
+    async fn handle(&self, cmd: RefreshSubscriptionCommand) -> Result<Self::Response, Self::Error> {
+        let subscription = self
+            .subscription_query_repository
+            .query_by_id(cmd.id.as_inner(), cmd.user_id.as_inner())
+            .await?
+            .ok_or(SubscriptionError::NotFound(cmd.id.as_inner()))?;
+
+        let data = ScrapeFeedJobData {
+            feed_id: subscription.feed_id.into(),
+            source_url: subscription.source_url,
+        };
+        let dedupe_key = data.dedupe_key();
+        let mut job = Job::create("scrape_feed", data)?;
+        job.user_id = Some(cmd.user_id.as_inner());
+        job.dedupe_key = Some(dedupe_key);
+
+        let mut producer = self.scrape_feed_producer.lock().await;
+
+        producer.push(job).await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum RefreshSubscriptionError {
+    #[error(transparent)]
+    Subscription(#[from] SubscriptionError),
+
+    #[error(transparent)]
+    Queue(#[from] colette_queue::Error),
+
+    #[error(transparent)]
+    Repository(#[from] RepositoryError),
+}
@@ -12,6 +12,7 @@ pub struct UpdateSubscriptionCommand {
     pub id: SubscriptionId,
     pub title: Option<String>,
     pub description: Option<Option<String>>,
+    pub fetch_full_article: Option<bool>,
     pub user_id: UserId,
 }
 
@@ -50,6 +51,9 @@ impl<SR: SubscriptionRepository> Handler<UpdateSubscriptionCommand>
                 subscription.remove_description();
             }
         }
+        if let Some(fetch_full_article) = cmd.fetch_full_article {
+            subscription.set_fetch_full_article(fetch_full_article);
+        }
 
         self.subscription_repository.save(&subscription).await?;
 
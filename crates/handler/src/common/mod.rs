@@ -1,5 +1,7 @@
 pub use auth::*;
 pub use pagination::*;
+pub use search::*;
 
 mod auth;
 mod pagination;
+mod search;
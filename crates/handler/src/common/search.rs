@@ -0,0 +1,5 @@
+#[derive(Debug, Clone)]
+pub struct SearchMatch {
+    pub rank: f32,
+    pub snippet: Option<String>,
+}
@@ -11,6 +11,7 @@ use crate::{
 pub struct ListBookmarksQuery {
     pub collection_id: Option<Uuid>,
     pub tags: Option<Vec<Uuid>>,
+    pub search: Option<String>,
     pub cursor: Option<BookmarkCursor>,
     pub limit: Option<usize>,
     pub user_id: Uuid,
@@ -56,7 +57,8 @@ impl<BQR: BookmarkQueryRepository, CQR: CollectionQueryRepository> Handler<ListB
                 user_id: query.user_id,
                 filter,
                 tags: query.tags,
-                cursor: query.cursor.map(|e| e.created_at),
+                search: query.search,
+                cursor: query.cursor.map(|e| (e.rank, e.created_at)),
                 limit: query.limit.map(|e| e + 1),
                 ..Default::default()
             })
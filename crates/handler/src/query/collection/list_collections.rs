@@ -2,30 +2,35 @@ use colette_common::RepositoryError;
 use uuid::Uuid;
 
 use crate::{
-    CollectionCursor, CollectionDto, CollectionQueryParams, CollectionQueryRepository, Handler,
-    Paginated, paginate,
+    CollectionCursor, CollectionDto, CollectionQueryParams, CollectionQueryRepository,
+    EntryQueryParams, EntryQueryRepository, Handler, Paginated, paginate,
 };
 
 #[derive(Debug, Clone)]
 pub struct ListCollectionsQuery {
     pub cursor: Option<CollectionCursor>,
     pub limit: Option<usize>,
+    pub with_counts: bool,
     pub user_id: Uuid,
 }
 
-pub struct ListCollectionsHandler<CQR: CollectionQueryRepository> {
+pub struct ListCollectionsHandler<CQR: CollectionQueryRepository, EQR: EntryQueryRepository> {
     collection_query_repository: CQR,
+    entry_query_repository: EQR,
 }
 
-impl<CQR: CollectionQueryRepository> ListCollectionsHandler<CQR> {
-    pub fn new(collection_query_repository: CQR) -> Self {
+impl<CQR: CollectionQueryRepository, EQR: EntryQueryRepository> ListCollectionsHandler<CQR, EQR> {
+    pub fn new(collection_query_repository: CQR, entry_query_repository: EQR) -> Self {
         Self {
             collection_query_repository,
+            entry_query_repository,
         }
     }
 }
 
-impl<CQR: CollectionQueryRepository> Handler<ListCollectionsQuery> for ListCollectionsHandler<CQR> {
+impl<CQR: CollectionQueryRepository, EQR: EntryQueryRepository> Handler<ListCollectionsQuery>
+    for ListCollectionsHandler<CQR, EQR>
+{
     type Response = Paginated<CollectionDto, CollectionCursor>;
     type Error = ListCollectionsError;
 
@@ -40,14 +45,38 @@ impl<CQR: CollectionQueryRepository> Handler<ListCollectionsQuery> for ListColle
             })
             .await?;
 
-        if let Some(limit) = query.limit {
-            Ok(paginate(collections, limit))
+        let mut collections = if let Some(limit) = query.limit {
+            paginate(collections, limit)
         } else {
-            Ok(Paginated {
+            Paginated {
                 items: collections,
                 ..Default::default()
-            })
+            }
+        };
+
+        if query.with_counts {
+            // Each collection has its own filter, so the counts can't be computed in a single
+            // grouped query like for subscriptions and tags. Collections without an entry filter
+            // list no entries, so they have no counts.
+            for collection in collections.items.iter_mut() {
+                let Some(entry_filter) = collection.entry_filter.clone() else {
+                    continue;
+                };
+
+                let counts = self
+                    .entry_query_repository
+                    .count(EntryQueryParams {
+                        user_id: query.user_id,
+                        filter: Some(entry_filter),
+                        ..Default::default()
+                    })
+                    .await?;
+
+                collection.entry_counts = Some(counts);
+            }
         }
+
+        Ok(collections)
     }
 }
 
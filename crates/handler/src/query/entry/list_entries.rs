@@ -1,5 +1,4 @@
 use colette_common::RepositoryError;
-use colette_crud::EntryFilter;
 use uuid::Uuid;
 
 use crate::{
@@ -13,6 +12,7 @@ pub struct ListEntriesQuery {
     pub subscription_id: Option<Uuid>,
     pub has_read: Option<bool>,
     pub tags: Option<Vec<Uuid>>,
+    pub search: Option<String>,
     pub cursor: Option<EntryCursor>,
     pub limit: Option<usize>,
     pub user_id: Uuid,
@@ -39,17 +39,20 @@ impl<EQR: EntryQueryRepository, CQR: CollectionQueryRepository> Handler<ListEntr
     type Error = ListEntriesError;
 
     async fn handle(&self, query: ListEntriesQuery) -> Result<Self::Response, Self::Error> {
-        let filter = Option::<EntryFilter>::None;
+        let mut filter = None;
         if let Some(collection_id) = query.collection_id {
-            let Some(_) = self
+            let Some(collection) = self
                 .collection_query_repository
                 .query_by_id(collection_id, query.user_id)
                 .await?
             else {
                 return Ok(Paginated::default());
             };
+            let Some(entry_filter) = collection.entry_filter else {
+                return Ok(Paginated::default());
+            };
 
-            // filter = Some(collection.filter);
+            filter = Some(entry_filter);
         }
 
         let entries = self
@@ -60,7 +63,8 @@ impl<EQR: EntryQueryRepository, CQR: CollectionQueryRepository> Handler<ListEntr
                 has_read: query.has_read,
                 tags: query.tags,
                 filter,
-                cursor: query.cursor.map(|e| (e.published_at, e.id)),
+                search: query.search,
+                cursor: query.cursor.map(|e| (e.rank, e.published_at, e.id)),
                 limit: query.limit.map(|e| e + 1),
                 ..Default::default()
             })
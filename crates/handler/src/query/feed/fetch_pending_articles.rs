@@ -0,0 +1,51 @@
+use chrono::{TimeDelta, Utc};
+use colette_common::RepositoryError;
+use colette_ingestion::{FeedEntry, FeedEntryFindArticlePendingParams, FeedEntryRepository};
+
+use crate::{FETCH_LIMIT, Handler};
+
+/// Number of days after publication during which the full article of an entry is fetched
+pub const ARTICLE_MAX_AGE_DAYS: i64 = 7;
+
+#[derive(Debug, Clone, Default)]
+pub struct FetchPendingArticlesQuery {}
+
+pub struct FetchPendingArticlesHandler<FER: FeedEntryRepository> {
+    feed_entry_repository: FER,
+}
+
+impl<FER: FeedEntryRepository> FetchPendingArticlesHandler<FER> {
+    pub fn new(feed_entry_repository: FER) -> Self {
+        Self {
+            feed_entry_repository,
+        }
+    }
+}
+
+impl<FER: FeedEntryRepository> Handler<FetchPendingArticlesQuery>
+    for FetchPendingArticlesHandler<FER>
+{
+    type Response = Vec<FeedEntry>;
+    type Error = FetchPendingArticlesError;
+
+    async fn handle(
+        &self,
+        _query: FetchPendingArticlesQuery,
+    ) -> Result<Self::Response, Self::Error> {
+        let feed_entries = self
+            .feed_entry_repository
+            .find_article_pending(FeedEntryFindArticlePendingParams {
+                published_after: Utc::now() - TimeDelta::days(ARTICLE_MAX_AGE_DAYS),
+                limit: Some(FETCH_LIMIT),
+            })
+            .await?;
+
+        Ok(feed_entries)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum FetchPendingArticlesError {
+    #[error(transparent)]
+    Repository(#[from] RepositoryError),
+}
@@ -0,0 +1,42 @@
+use chrono::{TimeDelta, Utc};
+use colette_ingestion::{Feed, FeedFindWebSubRenewableParams, FeedRepository};
+
+use crate::{FETCH_LIMIT, Handler, ListFeedsError};
+
+/// Number of hours before expiry at which WebSub leases are renewed
+pub const RENEWAL_WINDOW_HOURS: i64 = 24;
+
+#[derive(Debug, Clone, Default)]
+pub struct FetchWebSubRenewableFeedsQuery {}
+
+pub struct FetchWebSubRenewableFeedsHandler<FR: FeedRepository> {
+    feed_repository: FR,
+}
+
+impl<FR: FeedRepository> FetchWebSubRenewableFeedsHandler<FR> {
+    pub fn new(feed_repository: FR) -> Self {
+        Self { feed_repository }
+    }
+}
+
+impl<FR: FeedRepository> Handler<FetchWebSubRenewableFeedsQuery>
+    for FetchWebSubRenewableFeedsHandler<FR>
+{
+    type Response = Vec<Feed>;
+    type Error = ListFeedsError;
+
+    async fn handle(
+        &self,
+        _query: FetchWebSubRenewableFeedsQuery,
+    ) -> Result<Self::Response, Self::Error> {
+        let feeds = self
+            .feed_repository
+            .find_websub_renewable(FeedFindWebSubRenewableParams {
+                expires_before: Utc::now() + TimeDelta::hours(RENEWAL_WINDOW_HOURS),
+                limit: Some(FETCH_LIMIT),
+            })
+            .await?;
+
+        Ok(feeds)
+    }
+}
@@ -1,3 +1,7 @@
 pub use fetch_outdated_feeds::*;
+pub use fetch_pending_articles::*;
+pub use fetch_websub_renewable_feeds::*;
 
 mod fetch_outdated_feeds;
+mod fetch_pending_articles;
+mod fetch_websub_renewable_feeds;
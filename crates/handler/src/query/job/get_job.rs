@@ -0,0 +1,42 @@
+use colette_queue::{Job, JobStore};
+use uuid::Uuid;
+
+use crate::Handler;
+
+#[derive(Debug, Clone)]
+pub struct GetJobQuery {
+    pub id: Uuid,
+    pub user_id: Uuid,
+}
+
+pub struct GetJobHandler<JS: JobStore> {
+    job_store: JS,
+}
+
+impl<JS: JobStore> GetJobHandler<JS> {
+    pub fn new(job_store: JS) -> Self {
+        Self { job_store }
+    }
+}
+
+impl<JS: JobStore> Handler<GetJobQuery> for GetJobHandler<JS> {
+    type Response = Job;
+    type Error = GetJobError;
+
+    async fn handle(&self, query: GetJobQuery) -> Result<Self::Response, Self::Error> {
+        let job = self
+            .job_store
+            .find_by_id(query.id)
+            .await?
+            .filter(|e| e.user_id == Some(query.user_id))
+            .ok_or(colette_queue::Error::NotFound(query.id))?;
+
+        Ok(job)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetJobError {
+    #[error(transparent)]
+    Queue(#[from] colette_queue::Error),
+}
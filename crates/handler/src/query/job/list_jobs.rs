@@ -0,0 +1,73 @@
+use chrono::{DateTime, Utc};
+use colette_queue::{Job, JobFindParams, JobStatus, JobStore};
+use uuid::Uuid;
+
+use crate::{Cursor, Handler, Paginated, paginate};
+
+#[derive(Debug, Clone)]
+pub struct ListJobsQuery {
+    pub job_type: Option<String>,
+    pub status: Option<JobStatus>,
+    pub cursor: Option<JobCursor>,
+    pub limit: Option<usize>,
+    pub user_id: Uuid,
+}
+
+pub struct ListJobsHandler<JS: JobStore> {
+    job_store: JS,
+}
+
+impl<JS: JobStore> ListJobsHandler<JS> {
+    pub fn new(job_store: JS) -> Self {
+        Self { job_store }
+    }
+}
+
+impl<JS: JobStore> Handler<ListJobsQuery> for ListJobsHandler<JS> {
+    type Response = Paginated<Job, JobCursor>;
+    type Error = ListJobsError;
+
+    async fn handle(&self, query: ListJobsQuery) -> Result<Self::Response, Self::Error> {
+        let jobs = self
+            .job_store
+            .find(JobFindParams {
+                user_id: Some(query.user_id),
+                job_type: query.job_type,
+                status: query.status,
+                cursor: query.cursor.map(|e| e.created_at),
+                limit: query.limit.map(|e| e + 1),
+                ..Default::default()
+            })
+            .await?;
+
+        if let Some(limit) = query.limit {
+            Ok(paginate(jobs, limit))
+        } else {
+            Ok(Paginated {
+                items: jobs,
+                ..Default::default()
+            })
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct JobCursor {
+    pub created_at: DateTime<Utc>,
+}
+
+impl Cursor for Job {
+    type Data = JobCursor;
+
+    fn to_cursor(&self) -> Self::Data {
+        Self::Data {
+            created_at: self.created_at,
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ListJobsError {
+    #[error(transparent)]
+    Queue(#[from] colette_queue::Error),
+}
@@ -0,0 +1,5 @@
+pub use get_job::*;
+pub use list_jobs::*;
+
+mod get_job;
+mod list_jobs;
@@ -0,0 +1,47 @@
+use colette_common::RepositoryError;
+use colette_crud::RuleError;
+use uuid::Uuid;
+
+use crate::{Handler, RuleDto, RuleQueryRepository};
+
+#[derive(Debug, Clone)]
+pub struct GetRuleQuery {
+    pub id: Uuid,
+    pub user_id: Uuid,
+}
+
+pub struct GetRuleHandler<RQR: RuleQueryRepository> {
+    rule_query_repository: RQR,
+}
+
+impl<RQR: RuleQueryRepository> GetRuleHandler<RQR> {
+    pub fn new(rule_query_repository: RQR) -> Self {
+        Self {
+            rule_query_repository,
+        }
+    }
+}
+
+impl<RQR: RuleQueryRepository> Handler<GetRuleQuery> for GetRuleHandler<RQR> {
+    type Response = RuleDto;
+    type Error = GetRuleError;
+
+    async fn handle(&self, query: GetRuleQuery) -> Result<Self::Response, Self::Error> {
+        let rule = self
+            .rule_query_repository
+            .query_by_id(query.id, query.user_id)
+            .await?
+            .ok_or(RuleError::NotFound(query.id))?;
+
+        Ok(rule)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetRuleError {
+    #[error(transparent)]
+    Rule(#[from] RuleError),
+
+    #[error(transparent)]
+    Repository(#[from] RepositoryError),
+}
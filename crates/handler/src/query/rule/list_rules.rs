@@ -0,0 +1,57 @@
+use colette_common::RepositoryError;
+use uuid::Uuid;
+
+use crate::{
+    Handler, Paginated, RuleCursor, RuleDto, RuleQueryParams, RuleQueryRepository, paginate,
+};
+
+#[derive(Debug, Clone)]
+pub struct ListRulesQuery {
+    pub cursor: Option<RuleCursor>,
+    pub limit: Option<usize>,
+    pub user_id: Uuid,
+}
+
+pub struct ListRulesHandler<RQR: RuleQueryRepository> {
+    rule_query_repository: RQR,
+}
+
+impl<RQR: RuleQueryRepository> ListRulesHandler<RQR> {
+    pub fn new(rule_query_repository: RQR) -> Self {
+        Self {
+            rule_query_repository,
+        }
+    }
+}
+
+impl<RQR: RuleQueryRepository> Handler<ListRulesQuery> for ListRulesHandler<RQR> {
+    type Response = Paginated<RuleDto, RuleCursor>;
+    type Error = ListRulesError;
+
+    async fn handle(&self, query: ListRulesQuery) -> Result<Self::Response, Self::Error> {
+        let rules = self
+            .rule_query_repository
+            .query(RuleQueryParams {
+                user_id: query.user_id,
+                cursor: query.cursor.map(|e| e.title),
+                limit: query.limit.map(|e| e + 1),
+                ..Default::default()
+            })
+            .await?;
+
+        if let Some(limit) = query.limit {
+            Ok(paginate(rules, limit))
+        } else {
+            Ok(Paginated {
+                items: rules,
+                ..Default::default()
+            })
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ListRulesError {
+    #[error(transparent)]
+    Repository(#[from] RepositoryError),
+}
@@ -0,0 +1,5 @@
+pub use get_rule::*;
+pub use list_rules::*;
+
+mod get_rule;
+mod list_rules;
@@ -11,6 +11,7 @@ pub struct ListSubscriptionsQuery {
     pub tags: Option<Vec<Uuid>>,
     pub cursor: Option<SubscriptionCursor>,
     pub limit: Option<usize>,
+    pub with_counts: bool,
     pub user_id: Uuid,
 }
 
@@ -40,6 +41,7 @@ impl<SQR: SubscriptionQueryRepository> Handler<ListSubscriptionsQuery>
                 tags: query.tags,
                 cursor: query.cursor.map(|e| (e.title, e.id)),
                 limit: query.limit.map(|e| e + 1),
+                with_counts: query.with_counts,
                 ..Default::default()
             })
             .await?;
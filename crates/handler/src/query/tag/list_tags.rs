@@ -7,6 +7,7 @@ use crate::{Handler, Paginated, TagCursor, TagDto, TagQueryParams, TagQueryRepos
 pub struct ListTagsQuery {
     pub cursor: Option<TagCursor>,
     pub limit: Option<usize>,
+    pub with_counts: bool,
     pub user_id: Uuid,
 }
 
@@ -33,6 +34,7 @@ impl<TQR: TagQueryRepository> Handler<ListTagsQuery> for ListTagsHandler<TQR> {
                 user_id: query.user_id,
                 cursor: query.cursor.map(|e| e.title),
                 limit: query.limit.map(|e| e + 1),
+                with_counts: query.with_counts,
                 ..Default::default()
             })
             .await?;
@@ -4,7 +4,7 @@ use colette_crud::BookmarkFilter;
 use url::Url;
 use uuid::Uuid;
 
-use crate::{Cursor, TagDto};
+use crate::{Cursor, SearchMatch, TagDto};
 
 #[derive(Debug, Clone)]
 pub struct BookmarkDto {
@@ -16,12 +16,15 @@ pub struct BookmarkDto {
     pub author: Option<String>,
     pub archived_path: Option<String>,
     pub tags: Vec<TagDto>,
+    pub search_match: Option<SearchMatch>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct BookmarkCursor {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rank: Option<f32>,
     pub created_at: DateTime<Utc>,
 }
 
@@ -30,6 +33,7 @@ impl Cursor for BookmarkDto {
 
     fn to_cursor(&self) -> Self::Data {
         Self::Data {
+            rank: self.search_match.as_ref().map(|e| e.rank),
             created_at: self.created_at,
         }
     }
@@ -69,6 +73,7 @@ pub struct BookmarkQueryParams {
     pub id: Option<Uuid>,
     pub filter: Option<BookmarkFilter>,
     pub tags: Option<Vec<Uuid>>,
-    pub cursor: Option<DateTime<Utc>>,
+    pub search: Option<String>,
+    pub cursor: Option<(Option<f32>, DateTime<Utc>)>,
     pub limit: Option<usize>,
 }
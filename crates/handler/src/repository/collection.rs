@@ -1,15 +1,17 @@
 use chrono::{DateTime, Utc};
 use colette_common::RepositoryError;
-use colette_crud::BookmarkFilter;
+use colette_crud::{BookmarkFilter, EntryFilter};
 use uuid::Uuid;
 
-use crate::Cursor;
+use crate::{Cursor, EntryCounts};
 
 #[derive(Debug, Clone)]
 pub struct CollectionDto {
     pub id: Uuid,
     pub title: String,
     pub filter: BookmarkFilter,
+    pub entry_filter: Option<EntryFilter>,
+    pub entry_counts: Option<EntryCounts>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -1,10 +1,11 @@
 use chrono::{DateTime, Utc};
 use colette_common::RepositoryError;
-use colette_crud::{EntryFilter, ReadStatus};
+use colette_crud::{EntryFilter, ReadStatus, StarStatus};
+use colette_ingestion::ContentType;
 use url::Url;
 use uuid::Uuid;
 
-use crate::Cursor;
+use crate::{Cursor, SearchMatch};
 
 #[derive(Debug, Clone)]
 pub struct EntryDto {
@@ -13,14 +14,39 @@ pub struct EntryDto {
     pub title: String,
     pub published_at: DateTime<Utc>,
     pub description: Option<String>,
+    pub content: Option<String>,
+    pub content_type: Option<ContentType>,
+    pub excerpt: Option<String>,
+    pub article_content: Option<String>,
     pub author: Option<String>,
     pub thumbnail_url: Option<Url>,
+    pub enclosures: Vec<EnclosureDto>,
     pub read_status: ReadStatus,
+    pub star_status: StarStatus,
+    pub note: Option<String>,
     pub feed_id: Uuid,
+    pub search_match: Option<SearchMatch>,
+}
+
+/// Aggregate counts of the entries associated with a subscription, tag or collection
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EntryCounts {
+    pub total: i64,
+    pub unread: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct EnclosureDto {
+    pub url: Url,
+    pub mime_type: Option<String>,
+    pub length: Option<u64>,
+    pub duration_sec: Option<u32>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct EntryCursor {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rank: Option<f32>,
     pub published_at: DateTime<Utc>,
     pub id: Uuid,
 }
@@ -30,6 +56,7 @@ impl Cursor for EntryDto {
 
     fn to_cursor(&self) -> Self::Data {
         Self::Data {
+            rank: self.search_match.as_ref().map(|e| e.rank),
             published_at: self.published_at,
             id: self.id,
         }
@@ -42,6 +69,12 @@ pub trait EntryQueryRepository: Sync {
         params: EntryQueryParams,
     ) -> impl Future<Output = Result<Vec<EntryDto>, RepositoryError>> + Send;
 
+    /// Counts the entries matching the parameters, ignoring the cursor and limit
+    fn count(
+        &self,
+        params: EntryQueryParams,
+    ) -> impl Future<Output = Result<EntryCounts, RepositoryError>> + Send;
+
     fn query_by_id(
         &self,
         id: Uuid,
@@ -72,6 +105,7 @@ pub struct EntryQueryParams {
     pub has_read: Option<bool>,
     pub tags: Option<Vec<Uuid>>,
     pub filter: Option<EntryFilter>,
-    pub cursor: Option<(DateTime<Utc>, Uuid)>,
+    pub search: Option<String>,
+    pub cursor: Option<(Option<f32>, DateTime<Utc>, Uuid)>,
     pub limit: Option<usize>,
 }
@@ -2,6 +2,7 @@ pub use bookmark::*;
 pub use collection::*;
 pub use entry::*;
 pub use pat::*;
+pub use rule::*;
 pub use subscription::*;
 pub use tag::*;
 
@@ -9,5 +10,6 @@ mod bookmark;
 mod collection;
 mod entry;
 mod pat;
+mod rule;
 mod subscription;
 mod tag;
@@ -0,0 +1,68 @@
+use chrono::{DateTime, Utc};
+use colette_common::RepositoryError;
+use colette_crud::{EntryFilter, RuleAction};
+use uuid::Uuid;
+
+use crate::Cursor;
+
+#[derive(Debug, Clone)]
+pub struct RuleDto {
+    pub id: Uuid,
+    pub title: String,
+    pub condition: EntryFilter,
+    pub actions: Vec<RuleAction>,
+    pub enabled: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RuleCursor {
+    pub title: String,
+}
+
+impl Cursor for RuleDto {
+    type Data = RuleCursor;
+
+    fn to_cursor(&self) -> Self::Data {
+        Self::Data {
+            title: self.title.clone(),
+        }
+    }
+}
+
+pub trait RuleQueryRepository: Sync {
+    fn query(
+        &self,
+        params: RuleQueryParams,
+    ) -> impl Future<Output = Result<Vec<RuleDto>, RepositoryError>> + Send;
+
+    fn query_by_id(
+        &self,
+        id: Uuid,
+        user_id: Uuid,
+    ) -> impl Future<Output = Result<Option<RuleDto>, RepositoryError>> + Send {
+        async move {
+            let mut rules = self
+                .query(RuleQueryParams {
+                    user_id,
+                    id: Some(id),
+                    ..Default::default()
+                })
+                .await?;
+            if rules.is_empty() {
+                return Ok(None);
+            }
+
+            Ok(Some(rules.swap_remove(0)))
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct RuleQueryParams {
+    pub user_id: Uuid,
+    pub id: Option<Uuid>,
+    pub cursor: Option<String>,
+    pub limit: Option<usize>,
+}
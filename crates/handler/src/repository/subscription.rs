@@ -1,9 +1,10 @@
 use chrono::{DateTime, Utc};
 use colette_common::RepositoryError;
+use colette_ingestion::FeedStatus;
 use url::Url;
 use uuid::Uuid;
 
-use crate::{Cursor, TagDto};
+use crate::{Cursor, EntryCounts, TagDto};
 
 #[derive(Debug, Clone)]
 pub struct SubscriptionDto {
@@ -13,8 +14,13 @@ pub struct SubscriptionDto {
     pub title: String,
     pub description: Option<String>,
     pub feed_id: Uuid,
+    pub fetch_full_article: bool,
+    pub feed_status: FeedStatus,
+    pub feed_failure_count: u32,
+    pub feed_last_error: Option<String>,
     pub tags: Vec<TagDto>,
     pub unread_count: i64,
+    pub entry_counts: Option<EntryCounts>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -71,4 +77,5 @@ pub struct SubscriptionQueryParams {
     pub tags: Option<Vec<Uuid>>,
     pub cursor: Option<(String, Uuid)>,
     pub limit: Option<usize>,
+    pub with_counts: bool,
 }
@@ -2,12 +2,13 @@ use chrono::{DateTime, Utc};
 use colette_common::RepositoryError;
 use uuid::Uuid;
 
-use crate::Cursor;
+use crate::{Cursor, EntryCounts};
 
 #[derive(Debug, Clone)]
 pub struct TagDto {
     pub id: Uuid,
     pub title: String,
+    pub entry_counts: Option<EntryCounts>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -61,4 +62,5 @@ pub struct TagQueryParams {
     pub id: Option<Uuid>,
     pub cursor: Option<String>,
     pub limit: Option<usize>,
+    pub with_counts: bool,
 }
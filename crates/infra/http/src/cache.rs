@@ -0,0 +1,536 @@
+use std::{
+    collections::HashMap,
+    fs,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use bytes::Bytes;
+use chrono::{DateTime, Utc};
+use http::{
+    HeaderMap, HeaderName, HeaderValue, Method, Request, Response, StatusCode,
+    header::{
+        AGE, AUTHORIZATION, CACHE_CONTROL, DATE, EXPIRES, IF_MODIFIED_SINCE, IF_NONE_MATCH, VARY,
+    },
+};
+use http_body_util::BodyExt;
+use reqwest::Body;
+
+use crate::{Error, HttpClient, max_age};
+
+#[derive(Debug, Clone)]
+pub struct CacheOptions {
+    /// Maximum number of responses kept in memory
+    pub capacity: usize,
+    /// Responses with larger bodies are never cached
+    pub max_body_size: usize,
+    /// Freshness lifetime of responses without `Cache-Control` or `Expires` headers
+    pub default_ttl: Duration,
+    /// Directory where cached responses are also persisted, so that they survive restarts and
+    /// are shared between processes
+    pub dir: Option<PathBuf>,
+}
+
+/// Wraps an [`HttpClient`] to serve repeated GET requests from an in-memory LRU cache, backed by
+/// an optional on-disk store. Freshness is determined by `Cache-Control` and `Expires`, and a
+/// cached response is only reused for requests that match it on the headers named by `Vary`.
+#[derive(Debug, Clone)]
+pub struct CachingClient<C> {
+    inner: C,
+    options: Arc<CacheOptions>,
+    entries: Arc<Mutex<Lru>>,
+}
+
+impl<C: HttpClient> CachingClient<C> {
+    pub fn new(inner: C, options: CacheOptions) -> Self {
+        Self {
+            inner,
+            options: Arc::new(options),
+            entries: Default::default(),
+        }
+    }
+
+    async fn load(&self, key: &str) -> Option<CachedResponse> {
+        if let Some(cached) = self.entries.lock().unwrap().get(key) {
+            return Some(cached);
+        }
+
+        let path = self.options.dir.as_ref()?.join(key);
+        let cached = tokio::task::spawn_blocking(move || {
+            let raw = fs::read(&path).ok()?;
+            let cached = CachedResponse::decode(raw.into());
+            if cached.as_ref().is_none_or(|e| !e.is_fresh()) {
+                let _ = fs::remove_file(&path);
+            }
+
+            cached
+        })
+        .await
+        .ok()
+        .flatten()
+        .filter(CachedResponse::is_fresh)?;
+
+        self.entries
+            .lock()
+            .unwrap()
+            .insert(key.to_owned(), cached.clone(), self.options.capacity);
+
+        Some(cached)
+    }
+
+    async fn store(&self, key: &str, cached: CachedResponse) {
+        self.entries
+            .lock()
+            .unwrap()
+            .insert(key.to_owned(), cached.clone(), self.options.capacity);
+
+        if let Some(ref dir) = self.options.dir {
+            let dir = dir.to_owned();
+            let path = dir.join(key);
+
+            let _ = tokio::task::spawn_blocking(move || {
+                fs::create_dir_all(&dir)?;
+                fs::write(path, cached.encode())
+            })
+            .await;
+        }
+    }
+}
+
+impl<C: HttpClient> HttpClient for CachingClient<C> {
+    async fn send(&self, request: Request<Bytes>) -> Result<Response<Body>, Error> {
+        if !is_cacheable_request(&request) {
+            return self.inner.send(request).await;
+        }
+
+        let key = colette_util::hex_encode(&colette_util::sha256_hash(&request.uri().to_string()));
+        if let Some(cached) = self.load(&key).await
+            && cached.matches(request.headers())
+        {
+            return Ok(cached.into_response());
+        }
+
+        let request_headers = request.headers().clone();
+
+        let resp = self.inner.send(request).await?;
+        if resp.status() != StatusCode::OK {
+            return Ok(resp);
+        }
+
+        let Some(expires_at) = expires_at(resp.headers(), self.options.default_ttl) else {
+            return Ok(resp);
+        };
+        let Some(vary) = vary(resp.headers(), &request_headers) else {
+            return Ok(resp);
+        };
+
+        let (parts, body) = resp.into_parts();
+        let body = body.collect().await?.to_bytes();
+
+        let cached = CachedResponse {
+            status: parts.status,
+            headers: parts.headers,
+            body,
+            expires_at,
+            vary,
+        };
+        if cached.body.len() <= self.options.max_body_size {
+            self.store(&key, cached.clone()).await;
+        }
+
+        Ok(cached.into_response())
+    }
+}
+
+#[derive(Debug, Default)]
+struct Lru {
+    entries: HashMap<String, (CachedResponse, u64)>,
+    tick: u64,
+}
+
+impl Lru {
+    fn get(&mut self, key: &str) -> Option<CachedResponse> {
+        self.tick += 1;
+
+        let (cached, last_used) = self.entries.get_mut(key)?;
+        if !cached.is_fresh() {
+            self.entries.remove(key);
+            return None;
+        }
+        *last_used = self.tick;
+
+        Some(cached.clone())
+    }
+
+    fn insert(&mut self, key: String, cached: CachedResponse, capacity: usize) {
+        self.tick += 1;
+
+        if !self.entries.contains_key(&key) && self.entries.len() >= capacity {
+            self.entries.retain(|_, (e, _)| e.is_fresh());
+
+            if self.entries.len() >= capacity
+                && let Some(oldest) = self
+                    .entries
+                    .iter()
+                    .min_by_key(|(_, (_, last_used))| *last_used)
+                    .map(|(k, _)| k.to_owned())
+            {
+                self.entries.remove(&oldest);
+            }
+        }
+
+        if capacity > 0 {
+            self.entries.insert(key, (cached, self.tick));
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct CachedResponse {
+    status: StatusCode,
+    headers: HeaderMap,
+    body: Bytes,
+    expires_at: DateTime<Utc>,
+    /// Values of the request headers named by `Vary`, when the response was cached
+    vary: Vec<(HeaderName, Option<HeaderValue>)>,
+}
+
+impl CachedResponse {
+    fn is_fresh(&self) -> bool {
+        self.expires_at > Utc::now()
+    }
+
+    fn matches(&self, request_headers: &HeaderMap) -> bool {
+        self.vary
+            .iter()
+            .all(|(name, value)| request_headers.get(name) == value.as_ref())
+    }
+
+    fn into_response(self) -> Response<Body> {
+        let mut resp = Response::new(Body::from(self.body));
+        *resp.status_mut() = self.status;
+        *resp.headers_mut() = self.headers;
+
+        resp
+    }
+
+    /// Serializes the response as a JSON header line followed by the raw body
+    fn encode(&self) -> Vec<u8> {
+        let headers = self
+            .headers
+            .iter()
+            .filter_map(|(k, v)| v.to_str().ok().map(|v| (k.as_str(), v)))
+            .collect::<Vec<_>>();
+
+        let vary = self
+            .vary
+            .iter()
+            .map(|(k, v)| (k.as_str(), v.as_ref().and_then(|e| e.to_str().ok())))
+            .collect::<Vec<_>>();
+
+        let mut raw = serde_json::to_vec(&serde_json::json!({
+            "status": self.status.as_u16(),
+            "headers": headers,
+            "expires_at": self.expires_at.timestamp(),
+            "vary": vary,
+        }))
+        .unwrap_or_default();
+        raw.push(b'\n');
+        raw.extend_from_slice(&self.body);
+
+        raw
+    }
+
+    fn decode(raw: Bytes) -> Option<Self> {
+        #[derive(serde::Deserialize)]
+        struct Head {
+            status: u16,
+            headers: Vec<(String, String)>,
+            expires_at: i64,
+            #[serde(default)]
+            vary: Vec<(String, Option<String>)>,
+        }
+
+        let index = raw.iter().position(|e| *e == b'\n')?;
+        let head = serde_json::from_slice::<Head>(&raw[..index]).ok()?;
+
+        let mut headers = HeaderMap::new();
+        for (k, v) in head.headers {
+            headers.append(
+                HeaderName::try_from(k).ok()?,
+                HeaderValue::try_from(v).ok()?,
+            );
+        }
+
+        let mut vary = Vec::new();
+        for (k, v) in head.vary {
+            vary.push((
+                HeaderName::try_from(k).ok()?,
+                v.map(HeaderValue::try_from).transpose().ok()?,
+            ));
+        }
+
+        Some(Self {
+            status: StatusCode::from_u16(head.status).ok()?,
+            headers,
+            body: raw.slice(index + 1..),
+            expires_at: DateTime::from_timestamp(head.expires_at, 0)?,
+            vary,
+        })
+    }
+}
+
+/// Only plain GET requests are cached. Conditional requests are already cheap, and authorized
+/// requests may return per-user content.
+fn is_cacheable_request(request: &Request<Bytes>) -> bool {
+    let headers = request.headers();
+
+    request.method() == Method::GET
+        && !headers.contains_key(AUTHORIZATION)
+        && !headers.contains_key(IF_NONE_MATCH)
+        && !headers.contains_key(IF_MODIFIED_SINCE)
+        && !has_directive(headers, &["no-cache", "no-store"])
+}
+
+/// Computes when a response stops being fresh, or `None` if it must not be cached. Responses
+/// marked `private` are meant for a single user, so they are never shared through the cache.
+fn expires_at(headers: &HeaderMap, default_ttl: Duration) -> Option<DateTime<Utc>> {
+    if has_directive(headers, &["no-cache", "no-store", "private"]) {
+        return None;
+    }
+
+    let now = Utc::now();
+
+    let ttl = match max_age(headers) {
+        Some(max_age) => max_age,
+        None => match header_date(headers, EXPIRES) {
+            Some(expires) => {
+                let date = header_date(headers, DATE).unwrap_or(now);
+                (expires - date).to_std().unwrap_or_default()
+            }
+            None => default_ttl,
+        },
+    };
+
+    let age = headers
+        .get(AGE)
+        .and_then(|e| e.to_str().ok())
+        .and_then(|e| e.trim().parse().ok())
+        .map(Duration::from_secs)
+        .unwrap_or_default();
+
+    let ttl = ttl.checked_sub(age).filter(|e| !e.is_zero())?;
+
+    Some(now + ttl)
+}
+
+/// Collects the request headers named by the `Vary` header of a response, or `None` if the
+/// response varies on something other than request headers
+fn vary(
+    headers: &HeaderMap,
+    request_headers: &HeaderMap,
+) -> Option<Vec<(HeaderName, Option<HeaderValue>)>> {
+    let mut vary = Vec::<(HeaderName, Option<HeaderValue>)>::new();
+
+    for name in headers
+        .get_all(VARY)
+        .iter()
+        .filter_map(|e| e.to_str().ok())
+        .flat_map(|e| e.split(','))
+        .map(str::trim)
+        .filter(|e| !e.is_empty())
+    {
+        if name == "*" {
+            return None;
+        }
+
+        let name = HeaderName::try_from(name).ok()?;
+        if !vary.iter().any(|(k, _)| *k == name) {
+            let value = request_headers.get(&name).cloned();
+            vary.push((name, value));
+        }
+    }
+
+    Some(vary)
+}
+
+/// Checks for a `Cache-Control` directive, ignoring its arguments
+fn has_directive(headers: &HeaderMap, directives: &[&str]) -> bool {
+    headers
+        .get_all(CACHE_CONTROL)
+        .iter()
+        .filter_map(|e| e.to_str().ok())
+        .flat_map(|e| e.split(','))
+        .filter_map(|e| e.split('=').next())
+        .any(|e| directives.contains(&e.trim().to_lowercase().as_str()))
+}
+
+fn header_date(headers: &HeaderMap, name: HeaderName) -> Option<DateTime<Utc>> {
+    let value = headers.get(name)?.to_str().ok()?;
+
+    DateTime::parse_from_rfc2822(value.trim())
+        .ok()
+        .map(|e| e.with_timezone(&Utc))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use http::header::ACCEPT_LANGUAGE;
+
+    use super::*;
+
+    #[derive(Debug, Clone, Default)]
+    struct FakeClient {
+        headers: Vec<(HeaderName, &'static str)>,
+        calls: Arc<AtomicUsize>,
+    }
+
+    impl HttpClient for FakeClient {
+        async fn send(&self, _request: Request<Bytes>) -> Result<Response<Body>, Error> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+
+            let mut resp = Response::new(Body::from("body"));
+            for (k, v) in self.headers.iter() {
+                resp.headers_mut()
+                    .append(k.to_owned(), HeaderValue::from_static(v));
+            }
+
+            Ok(resp)
+        }
+    }
+
+    fn client(headers: Vec<(HeaderName, &'static str)>) -> (CachingClient<FakeClient>, FakeClient) {
+        let inner = FakeClient {
+            headers,
+            ..Default::default()
+        };
+        let client = CachingClient::new(
+            inner.clone(),
+            CacheOptions {
+                capacity: 8,
+                max_body_size: 1024,
+                default_ttl: Duration::from_secs(60),
+                dir: None,
+            },
+        );
+
+        (client, inner)
+    }
+
+    fn request(language: Option<&'static str>) -> Request<Bytes> {
+        let mut builder = Request::get("https://example.com/feed.xml");
+        if let Some(language) = language {
+            builder = builder.header(ACCEPT_LANGUAGE, language);
+        }
+
+        builder.body(Bytes::new()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn serves_fresh_responses_from_memory() {
+        let (client, inner) = client(vec![(CACHE_CONTROL, "max-age=300")]);
+
+        for _ in 0..2 {
+            let resp = client.execute(request(None)).await.unwrap();
+            assert_eq!(resp.into_body(), "body");
+        }
+
+        assert_eq!(inner.calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn skips_private_and_uncacheable_responses() {
+        for value in ["private, max-age=300", "no-store", "private=\"set-cookie\""] {
+            let (client, inner) = client(vec![(CACHE_CONTROL, value)]);
+
+            client.execute(request(None)).await.unwrap();
+            client.execute(request(None)).await.unwrap();
+
+            assert_eq!(inner.calls.load(Ordering::SeqCst), 2, "{value}");
+        }
+    }
+
+    #[tokio::test]
+    async fn matches_cached_responses_on_vary_headers() {
+        let (client, inner) = client(vec![
+            (CACHE_CONTROL, "max-age=300"),
+            (VARY, "Accept-Language"),
+        ]);
+
+        client.execute(request(Some("en"))).await.unwrap();
+        client.execute(request(Some("en"))).await.unwrap();
+        assert_eq!(inner.calls.load(Ordering::SeqCst), 1);
+
+        client.execute(request(Some("de"))).await.unwrap();
+        client.execute(request(None)).await.unwrap();
+        assert_eq!(inner.calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn never_caches_vary_star() {
+        let (client, inner) = client(vec![(CACHE_CONTROL, "max-age=300"), (VARY, "*")]);
+
+        client.execute(request(None)).await.unwrap();
+        client.execute(request(None)).await.unwrap();
+
+        assert_eq!(inner.calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn computes_expiry_from_max_age_and_age() {
+        let mut headers = HeaderMap::new();
+        headers.insert(CACHE_CONTROL, HeaderValue::from_static("max-age=120"));
+        headers.insert(AGE, HeaderValue::from_static("60"));
+
+        let ttl = expires_at(&headers, Duration::ZERO).unwrap() - Utc::now();
+        assert!((55..=60).contains(&ttl.num_seconds()));
+
+        headers.insert(AGE, HeaderValue::from_static("120"));
+        assert!(expires_at(&headers, Duration::ZERO).is_none());
+    }
+
+    #[test]
+    fn round_trips_through_the_disk_format() {
+        let mut headers = HeaderMap::new();
+        headers.insert(VARY, HeaderValue::from_static("accept-language"));
+
+        let cached = CachedResponse {
+            status: StatusCode::OK,
+            headers,
+            body: Bytes::from_static(b"line\nbody"),
+            expires_at: DateTime::from_timestamp(4_000_000_000, 0).unwrap(),
+            vary: vec![(ACCEPT_LANGUAGE, Some(HeaderValue::from_static("en")))],
+        };
+
+        let decoded = CachedResponse::decode(cached.encode().into()).unwrap();
+        assert_eq!(decoded.status, cached.status);
+        assert_eq!(decoded.headers, cached.headers);
+        assert_eq!(decoded.body, cached.body);
+        assert_eq!(decoded.expires_at, cached.expires_at);
+        assert_eq!(decoded.vary, cached.vary);
+    }
+
+    #[test]
+    fn evicts_the_least_recently_used_entry() {
+        let cached = CachedResponse {
+            status: StatusCode::OK,
+            headers: HeaderMap::new(),
+            body: Bytes::new(),
+            expires_at: Utc::now() + Duration::from_secs(60),
+            vary: Vec::new(),
+        };
+
+        let mut lru = Lru::default();
+        lru.insert("a".into(), cached.clone(), 2);
+        lru.insert("b".into(), cached.clone(), 2);
+        lru.get("a");
+        lru.insert("c".into(), cached, 2);
+
+        assert!(lru.get("a").is_some());
+        assert!(lru.get("b").is_none());
+        assert!(lru.get("c").is_some());
+    }
+}
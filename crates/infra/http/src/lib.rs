@@ -1,8 +1,18 @@
+use std::time::Duration;
+
 use bytes::Bytes;
-use http::{Request, Response};
+pub use cache::{CacheOptions, CachingClient};
+use http::{
+    HeaderMap, Request, Response, StatusCode,
+    header::{CACHE_CONTROL, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED},
+};
 use http_body_util::BodyExt;
+pub use polite::{PoliteClient, PolitenessOptions};
 use reqwest::{Body, Client, Url};
 
+mod cache;
+mod polite;
+
 pub trait HttpClient: Send + Sync {
     fn send(
         &self,
@@ -10,18 +20,92 @@ pub trait HttpClient: Send + Sync {
     ) -> impl Future<Output = Result<Response<Body>, Error>> + Send;
 
     fn get(&self, url: &Url) -> impl Future<Output = Result<Bytes, Error>> + Send {
+        async { Ok(self.get_response(url).await?.into_body()) }
+    }
+
+    /// Sends a GET request, keeping the response headers alongside the collected body
+    fn get_response(
+        &self,
+        url: &Url,
+    ) -> impl Future<Output = Result<Response<Bytes>, Error>> + Send {
+        async {
+            self.execute(Request::get(url.as_str()).body(Default::default())?)
+                .await
+        }
+    }
+
+    /// Sends a request and collects the body of the response
+    fn execute(
+        &self,
+        request: Request<Bytes>,
+    ) -> impl Future<Output = Result<Response<Bytes>, Error>> + Send {
         async {
-            let resp = self
-                .send(Request::get(url.as_str()).body(Default::default())?)
-                .await?;
+            let resp = self.send(request).await?;
+
+            let (parts, body) = resp.into_parts();
+            let body = body.collect().await?.to_bytes();
+
+            Ok(Response::from_parts(parts, body))
+        }
+    }
+
+    /// Sends a conditional GET request using the validators from a previous response. If the
+    /// resource is unchanged, the response has status `304 Not Modified` and an empty body, but
+    /// still carries the caching headers of the server.
+    fn get_if_modified(
+        &self,
+        url: &Url,
+        validators: &Validators,
+    ) -> impl Future<Output = Result<Response<Bytes>, Error>> + Send {
+        async move {
+            let mut builder = Request::get(url.as_str());
+            if let Some(ref etag) = validators.etag {
+                builder = builder.header(IF_NONE_MATCH, etag);
+            }
+            if let Some(ref last_modified) = validators.last_modified {
+                builder = builder.header(IF_MODIFIED_SINCE, last_modified);
+            }
+
+            self.execute(builder.body(Default::default())?).await
+        }
+    }
+}
 
-            let body = resp.into_body().collect().await?.to_bytes();
+/// Cache validators returned by a server, echoed back on subsequent requests for the same resource
+#[derive(Debug, Clone, Default)]
+pub struct Validators {
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+}
+
+impl Validators {
+    pub fn from_headers(headers: &HeaderMap) -> Self {
+        let header = |name| {
+            headers
+                .get(name)
+                .and_then(|e| e.to_str().ok())
+                .map(ToOwned::to_owned)
+        };
 
-            Ok(body)
+        Self {
+            etag: header(ETAG),
+            last_modified: header(LAST_MODIFIED),
         }
     }
 }
 
+/// Parses the `max-age` directive of the `Cache-Control` header
+pub fn max_age(headers: &HeaderMap) -> Option<Duration> {
+    headers
+        .get_all(CACHE_CONTROL)
+        .iter()
+        .filter_map(|e| e.to_str().ok())
+        .flat_map(|e| e.split(','))
+        .find_map(|e| e.trim().strip_prefix("max-age="))
+        .and_then(|e| e.trim_matches('"').parse().ok())
+        .map(Duration::from_secs)
+}
+
 #[derive(Debug, Clone)]
 pub struct ReqwestClient {
     client: Client,
@@ -48,4 +132,13 @@ pub enum Error {
 
     #[error(transparent)]
     Http(#[from] http::Error),
+
+    #[error("unexpected response status: {0}")]
+    Status(StatusCode),
+
+    #[error("disallowed by robots.txt: {0}")]
+    Disallowed(String),
+
+    #[error("rate limited by host, retry in {0:?}")]
+    RateLimited(Duration),
 }
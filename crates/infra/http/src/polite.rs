@@ -0,0 +1,472 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use bytes::Bytes;
+use chrono::{DateTime, Utc};
+use http::{
+    HeaderMap, HeaderValue, Request, Response, StatusCode,
+    header::{RETRY_AFTER, USER_AGENT},
+    uri::Authority,
+};
+use http_body_util::BodyExt;
+use reqwest::Body;
+use tokio::{
+    sync::Semaphore,
+    time::{Instant, sleep_until},
+};
+
+use crate::{Error, HttpClient};
+
+/// How long a fetched robots.txt is trusted for
+const ROBOTS_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// How long a robots.txt that couldn't be fetched is treated as allowing everything
+const ROBOTS_ERROR_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// How long the state of a host without requests is kept, including its robots.txt
+const HOST_IDLE_TTL: Duration = ROBOTS_TTL;
+
+/// Upper bound for the `Crawl-delay` directive, so that a single host can't stall a worker
+const MAX_CRAWL_DELAY: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone)]
+pub struct PolitenessOptions {
+    /// Sent with every request that doesn't set its own `User-Agent`
+    pub user_agent: String,
+    /// Maximum number of in-flight requests per host
+    pub max_concurrency_per_host: usize,
+    /// Minimum delay between the start of two requests to the same host
+    pub min_request_interval: Duration,
+    /// Longest `Retry-After` delay that is waited out. Requests to a host that asked for a longer
+    /// delay fail with [`Error::RateLimited`] until the delay has passed.
+    pub max_retry_delay: Duration,
+    pub respect_robots_txt: bool,
+}
+
+/// Wraps an [`HttpClient`] to throttle requests per host, honor robots.txt and `Retry-After`, and
+/// identify itself with a configurable user agent
+#[derive(Debug, Clone)]
+pub struct PoliteClient<C> {
+    inner: C,
+    options: Arc<PolitenessOptions>,
+    user_agent: HeaderValue,
+    hosts: Arc<Mutex<HashMap<Authority, Arc<HostState>>>>,
+}
+
+impl<C: HttpClient> PoliteClient<C> {
+    pub fn new(inner: C, options: PolitenessOptions) -> Result<Self, Error> {
+        let user_agent =
+            HeaderValue::try_from(options.user_agent.as_str()).map_err(http::Error::from)?;
+
+        Ok(Self {
+            inner,
+            options: Arc::new(options),
+            user_agent,
+            hosts: Default::default(),
+        })
+    }
+
+    fn host(&self, authority: &Authority) -> Arc<HostState> {
+        let mut hosts = self.hosts.lock().unwrap();
+
+        // Idle hosts are swept whenever a new one is added, so that the map doesn't grow with
+        // every host ever contacted
+        if !hosts.contains_key(authority) {
+            evict_idle_hosts(&mut hosts, Instant::now());
+        }
+
+        hosts
+            .entry(authority.to_owned())
+            .or_insert_with(|| {
+                Arc::new(HostState {
+                    permits: Semaphore::new(self.options.max_concurrency_per_host.max(1)),
+                    schedule: Default::default(),
+                    robots: Default::default(),
+                })
+            })
+            .clone()
+    }
+
+    async fn robots(&self, scheme: &str, authority: &Authority, host: &HostState) -> Arc<Robots> {
+        let mut cached = host.robots.lock().await;
+        if let Some((ref robots, expires_at)) = *cached
+            && expires_at > Instant::now()
+        {
+            return robots.clone();
+        }
+
+        let request = Request::get(format!("{scheme}://{authority}/robots.txt"))
+            .header(USER_AGENT, self.user_agent.clone())
+            .body(Bytes::new());
+
+        let fetched = match request {
+            Ok(request) => match self.inner.send(request).await {
+                Ok(resp) if resp.status().is_success() => {
+                    resp.into_body().collect().await.ok().map(|e| {
+                        Robots::parse(
+                            &String::from_utf8_lossy(&e.to_bytes()),
+                            &self.options.user_agent,
+                        )
+                    })
+                }
+                // A missing robots.txt allows everything
+                Ok(resp) if resp.status().is_client_error() => Some(Robots::default()),
+                _ => None,
+            },
+            Err(_) => None,
+        };
+
+        let (robots, ttl) = match fetched {
+            Some(robots) => (Arc::new(robots), ROBOTS_TTL),
+            None => (Arc::new(Robots::default()), ROBOTS_ERROR_TTL),
+        };
+        *cached = Some((robots.clone(), Instant::now() + ttl));
+
+        robots
+    }
+
+    /// Waits for the host's next free slot, and reserves the slot after it
+    async fn wait_for_slot(&self, host: &HostState, crawl_delay: Duration) -> Result<(), Error> {
+        let now = Instant::now();
+        let start = {
+            let mut schedule = host.schedule.lock().unwrap();
+
+            if let Some(blocked_until) = schedule.blocked_until
+                && blocked_until > now + self.options.max_retry_delay
+            {
+                return Err(Error::RateLimited(blocked_until - now));
+            }
+
+            let start = [Some(now), schedule.next_request, schedule.blocked_until]
+                .into_iter()
+                .flatten()
+                .max()
+                .unwrap_or(now);
+            schedule.next_request =
+                Some(start + self.options.min_request_interval.max(crawl_delay));
+
+            start
+        };
+
+        sleep_until(start).await;
+
+        Ok(())
+    }
+}
+
+impl<C: HttpClient> HttpClient for PoliteClient<C> {
+    async fn send(&self, mut request: Request<Bytes>) -> Result<Response<Body>, Error> {
+        let Some(authority) = request.uri().authority().cloned() else {
+            return self.inner.send(request).await;
+        };
+        let scheme = request.uri().scheme_str().unwrap_or("https").to_owned();
+
+        if !request.headers().contains_key(USER_AGENT) {
+            request
+                .headers_mut()
+                .insert(USER_AGENT, self.user_agent.clone());
+        }
+
+        let host = self.host(&authority);
+
+        let robots = if self.options.respect_robots_txt {
+            let robots = self.robots(&scheme, &authority, &host).await;
+
+            let path = request
+                .uri()
+                .path_and_query()
+                .map(|e| e.as_str())
+                .unwrap_or("/");
+            if !robots.is_allowed(path) {
+                return Err(Error::Disallowed(request.uri().to_string()));
+            }
+
+            Some(robots)
+        } else {
+            None
+        };
+        let crawl_delay = robots
+            .and_then(|e| e.crawl_delay)
+            .unwrap_or_default()
+            .min(MAX_CRAWL_DELAY);
+
+        let _permit = host.permits.acquire().await.unwrap();
+
+        let mut retried = false;
+
+        loop {
+            self.wait_for_slot(&host, crawl_delay).await?;
+
+            let resp = self.inner.send(copy_request(&request)).await?;
+            if !matches!(
+                resp.status(),
+                StatusCode::TOO_MANY_REQUESTS | StatusCode::SERVICE_UNAVAILABLE
+            ) {
+                return Ok(resp);
+            }
+
+            let Some(delay) = retry_after(resp.headers()) else {
+                return Ok(resp);
+            };
+            host.schedule.lock().unwrap().blocked_until = Some(Instant::now() + delay);
+
+            if retried || delay > self.options.max_retry_delay {
+                return Ok(resp);
+            }
+            retried = true;
+        }
+    }
+}
+
+#[derive(Debug)]
+struct HostState {
+    permits: Semaphore,
+    schedule: Mutex<Schedule>,
+    robots: tokio::sync::Mutex<Option<(Arc<Robots>, Instant)>>,
+}
+
+impl HostState {
+    /// Whether the host has had no requests scheduled for [`HOST_IDLE_TTL`], and isn't blocked
+    fn is_idle(&self, now: Instant) -> bool {
+        let schedule = self.schedule.lock().unwrap();
+
+        [schedule.next_request, schedule.blocked_until]
+            .into_iter()
+            .flatten()
+            .all(|e| e + HOST_IDLE_TTL <= now)
+    }
+}
+
+#[derive(Debug, Default)]
+struct Schedule {
+    next_request: Option<Instant>,
+    blocked_until: Option<Instant>,
+}
+
+/// The rules of a robots.txt group that apply to this client
+#[derive(Debug, Default)]
+struct Robots {
+    rules: Vec<(bool, String)>,
+    crawl_delay: Option<Duration>,
+}
+
+impl Robots {
+    /// Parses a robots.txt, keeping the group that names the product token of the user agent, or
+    /// the `*` group if there is none
+    fn parse(raw: &str, user_agent: &str) -> Self {
+        let token = user_agent
+            .split(['/', ' '])
+            .next()
+            .unwrap_or_default()
+            .to_lowercase();
+
+        let mut specific = None::<Self>;
+        let mut wildcard = None::<Self>;
+
+        let mut agents = Vec::<String>::new();
+        let mut group = Self::default();
+        let mut in_rules = false;
+
+        let mut flush = |agents: &mut Vec<String>, group: &mut Self| {
+            let group = std::mem::take(group);
+            if !token.is_empty() && agents.contains(&token) {
+                specific.get_or_insert_default().merge(group);
+            } else if agents.iter().any(|e| e == "*") {
+                wildcard.get_or_insert_default().merge(group);
+            }
+            agents.clear();
+        };
+
+        for line in raw.lines() {
+            let line = line.split('#').next().unwrap_or_default().trim();
+            let Some((key, value)) = line.split_once(':') else {
+                continue;
+            };
+            let value = value.trim();
+
+            match key.trim().to_lowercase().as_str() {
+                "user-agent" => {
+                    if in_rules {
+                        flush(&mut agents, &mut group);
+                        in_rules = false;
+                    }
+                    agents.push(value.to_lowercase());
+                }
+                "allow" | "disallow" if !value.is_empty() => {
+                    in_rules = true;
+                    group
+                        .rules
+                        .push((key.trim().eq_ignore_ascii_case("allow"), value.to_owned()));
+                }
+                "crawl-delay" => {
+                    in_rules = true;
+                    group.crawl_delay = value.parse::<f64>().ok().map(Duration::from_secs_f64);
+                }
+                _ => {}
+            }
+        }
+        flush(&mut agents, &mut group);
+
+        specific.or(wildcard).unwrap_or_default()
+    }
+
+    fn merge(&mut self, other: Self) {
+        self.rules.extend(other.rules);
+        self.crawl_delay = self.crawl_delay.or(other.crawl_delay);
+    }
+
+    /// Applies the longest matching rule, with `Allow` winning ties
+    fn is_allowed(&self, path: &str) -> bool {
+        self.rules
+            .iter()
+            .filter(|(_, pattern)| matches_pattern(pattern, path))
+            .max_by_key(|(allow, pattern)| (pattern.len(), *allow))
+            .is_none_or(|(allow, _)| *allow)
+    }
+}
+
+/// Matches a robots.txt path pattern, which may contain `*` wildcards and a trailing `$` anchor
+fn matches_pattern(pattern: &str, path: &str) -> bool {
+    let (pattern, anchored) = match pattern.strip_suffix('$') {
+        Some(pattern) => (pattern, true),
+        None => (pattern, false),
+    };
+
+    let mut parts = pattern.split('*');
+    let Some(first) = parts.next() else {
+        return true;
+    };
+    let Some(mut rest) = path.strip_prefix(first) else {
+        return false;
+    };
+
+    let parts = parts.collect::<Vec<_>>();
+    for (i, part) in parts.iter().enumerate() {
+        if anchored && i == parts.len() - 1 {
+            return rest.ends_with(part);
+        }
+        match rest.find(part) {
+            Some(index) => rest = &rest[index + part.len()..],
+            None => return false,
+        }
+    }
+
+    !anchored || rest.is_empty()
+}
+
+/// Drops the hosts that are idle and have no requests in flight
+fn evict_idle_hosts(hosts: &mut HashMap<Authority, Arc<HostState>>, now: Instant) {
+    hosts.retain(|_, e| Arc::strong_count(e) > 1 || !e.is_idle(now));
+}
+
+/// Parses the `Retry-After` header, given either in seconds or as an HTTP date
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let date = DateTime::parse_from_rfc2822(value).ok()?;
+
+    Some(
+        (date.with_timezone(&Utc) - Utc::now())
+            .to_std()
+            .unwrap_or_default(),
+    )
+}
+
+fn copy_request(request: &Request<Bytes>) -> Request<Bytes> {
+    let mut copy = Request::new(request.body().clone());
+    *copy.method_mut() = request.method().clone();
+    *copy.uri_mut() = request.uri().clone();
+    *copy.version_mut() = request.version();
+    *copy.headers_mut() = request.headers().clone();
+
+    copy
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn host_state(next_request: Option<Instant>) -> Arc<HostState> {
+        Arc::new(HostState {
+            permits: Semaphore::new(1),
+            schedule: Mutex::new(Schedule {
+                next_request,
+                blocked_until: None,
+            }),
+            robots: Default::default(),
+        })
+    }
+
+    #[test]
+    fn parses_the_matching_robots_group() {
+        let raw = "\
+User-agent: *
+Disallow: /
+
+User-agent: colette
+User-agent: other
+Disallow: /private # comment
+Allow: /private/public
+Crawl-delay: 2.5
+";
+
+        let robots = Robots::parse(raw, "Colette/1.0 (+https://example.com)");
+        assert!(robots.is_allowed("/feed.xml"));
+        assert!(!robots.is_allowed("/private/feed.xml"));
+        assert!(robots.is_allowed("/private/public/feed.xml"));
+        assert_eq!(robots.crawl_delay, Some(Duration::from_millis(2500)));
+
+        let robots = Robots::parse(raw, "Other");
+        assert!(robots.is_allowed("/feed.xml"));
+
+        let robots = Robots::parse(raw, "Unknown/1.0");
+        assert!(!robots.is_allowed("/feed.xml"));
+    }
+
+    #[test]
+    fn matches_wildcards_and_anchors() {
+        assert!(matches_pattern("/feeds/", "/feeds/all.xml"));
+        assert!(matches_pattern("/*.xml$", "/feeds/all.xml"));
+        assert!(!matches_pattern("/*.xml$", "/feeds/all.xml?page=2"));
+        assert!(matches_pattern("/*/all", "/feeds/all.xml"));
+        assert!(!matches_pattern("/feeds/", "/blog/"));
+    }
+
+    #[test]
+    fn parses_retry_after_in_seconds() {
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("120"));
+
+        assert_eq!(retry_after(&headers), Some(Duration::from_secs(120)));
+    }
+
+    #[test]
+    fn evicts_idle_hosts_without_requests_in_flight() {
+        let now = Instant::now();
+        let later = now + HOST_IDLE_TTL + Duration::from_secs(1);
+
+        let in_flight = host_state(Some(now));
+        let mut hosts = HashMap::from([
+            (Authority::from_static("idle.test"), host_state(Some(now))),
+            (Authority::from_static("new.test"), host_state(None)),
+            (
+                Authority::from_static("recent.test"),
+                host_state(Some(later)),
+            ),
+            (Authority::from_static("busy.test"), in_flight.clone()),
+        ]);
+
+        evict_idle_hosts(&mut hosts, later);
+
+        let mut remaining = hosts.keys().map(|e| e.as_str()).collect::<Vec<_>>();
+        remaining.sort();
+        assert_eq!(remaining, ["busy.test", "recent.test"]);
+    }
+}
@@ -0,0 +1,8 @@
+UPDATE jobs
+SET
+  status = 'cancelled',
+  completed_at = now(),
+  updated_at = now()
+WHERE
+  id = $1
+  AND status = 'pending'
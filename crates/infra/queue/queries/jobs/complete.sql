@@ -0,0 +1,9 @@
+UPDATE jobs
+SET
+  status = 'completed',
+  completed_at = now(),
+  updated_at = now()
+WHERE
+  id = $1
+  AND status = 'running'
+  AND attempts = $2
@@ -0,0 +1,18 @@
+UPDATE jobs
+SET
+  status = CASE
+    WHEN attempts >= max_attempts THEN 'dead'
+    ELSE 'pending'
+  END,
+  message = $2,
+  run_at = now() + (
+    backoff_secs * power(2, LEAST(GREATEST(attempts - 1, 0), 10)) * INTERVAL '1 second'
+  ),
+  completed_at = CASE
+    WHEN attempts >= max_attempts THEN now()
+  END,
+  updated_at = now()
+WHERE
+  id = $1
+  AND status = 'running'
+  AND attempts = $3
@@ -0,0 +1,42 @@
+SELECT
+  id,
+  job_type,
+  data_json,
+  status,
+  message,
+  attempts,
+  max_attempts,
+  backoff_secs,
+  run_at,
+  started_at,
+  completed_at,
+  user_id,
+  dedupe_key,
+  created_at
+FROM
+  jobs
+WHERE
+  (
+    $1::UUID IS NULL
+    OR id = $1
+  )
+  AND (
+    $2::UUID IS NULL
+    OR user_id = $2
+  )
+  AND (
+    $3::TEXT IS NULL
+    OR job_type = $3
+  )
+  AND (
+    $4::TEXT IS NULL
+    OR status = $4
+  )
+  AND (
+    $5::TIMESTAMPTZ IS NULL
+    OR created_at < $5
+  )
+ORDER BY
+  created_at DESC
+LIMIT
+  $6
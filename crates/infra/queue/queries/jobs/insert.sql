@@ -0,0 +1,21 @@
+INSERT INTO
+  jobs (
+    id,
+    job_type,
+    data_json,
+    status,
+    message,
+    attempts,
+    max_attempts,
+    backoff_secs,
+    run_at,
+    user_id,
+    dedupe_key,
+    created_at,
+    updated_at
+  )
+VALUES
+  ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $12)
+ON CONFLICT (dedupe_key)
+WHERE
+  status IN ('pending', 'running') DO NOTHING
@@ -0,0 +1,51 @@
+WITH
+  next_job AS (
+    SELECT
+      id
+    FROM
+      jobs
+    WHERE
+      job_type = $1
+      AND (
+        (
+          status = 'pending'
+          AND run_at <= now()
+        )
+        OR (
+          status = 'running'
+          AND started_at < now() - ($2 * INTERVAL '1 second')
+        )
+      )
+    ORDER BY
+      run_at ASC,
+      created_at ASC
+    LIMIT
+      1
+    FOR UPDATE
+      SKIP LOCKED
+  )
+UPDATE jobs j
+SET
+  status = 'running',
+  attempts = j.attempts + 1,
+  started_at = now(),
+  updated_at = now()
+FROM
+  next_job
+WHERE
+  j.id = next_job.id
+RETURNING
+  j.id,
+  j.job_type,
+  j.data_json,
+  j.status,
+  j.message,
+  j.attempts,
+  j.max_attempts,
+  j.backoff_secs,
+  j.run_at,
+  j.started_at,
+  j.completed_at,
+  j.user_id,
+  j.dedupe_key,
+  j.created_at
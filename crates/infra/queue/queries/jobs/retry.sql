@@ -0,0 +1,21 @@
+UPDATE jobs
+SET
+  status = 'pending',
+  message = NULL,
+  attempts = 0,
+  run_at = now(),
+  started_at = NULL,
+  completed_at = NULL,
+  updated_at = now()
+WHERE
+  id = $1
+  AND status IN ('dead', 'cancelled')
+  AND NOT EXISTS (
+    SELECT
+      1
+    FROM
+      jobs j
+    WHERE
+      j.dedupe_key = jobs.dedupe_key
+      AND j.status IN ('pending', 'running')
+  )
@@ -1,16 +1,35 @@
+use std::{fmt, str::FromStr, time::Duration};
+
 use chrono::{DateTime, Utc};
 use colette_common::uuid_generate_ts;
+pub use postgres::*;
 use serde::Serialize;
 use serde_json::Value;
 use tokio::sync::mpsc::{self, Receiver, Sender, error::SendError};
 use uuid::Uuid;
 
+mod postgres;
+
+pub const DEFAULT_MAX_ATTEMPTS: u32 = 5;
+pub const DEFAULT_BACKOFF: Duration = Duration::from_secs(30);
+
 #[derive(Debug, Clone)]
 pub struct Job {
     pub id: Uuid,
     pub job_type: String,
     pub data: Value,
+    pub status: JobStatus,
     pub message: Option<String>,
+    pub attempts: u32,
+    pub max_attempts: u32,
+    pub backoff: Duration,
+    pub run_at: DateTime<Utc>,
+    pub started_at: Option<DateTime<Utc>>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub user_id: Option<Uuid>,
+    /// Key that prevents another job with the same key from being queued while this one is
+    /// pending or running
+    pub dedupe_key: Option<String>,
     pub created_at: DateTime<Utc>,
 }
 
@@ -25,10 +44,62 @@ impl Job {
             id: uuid_generate_ts(now),
             job_type: job_type.into(),
             data,
+            status: JobStatus::default(),
             message: None,
+            attempts: 0,
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            backoff: DEFAULT_BACKOFF,
+            run_at: now,
+            started_at: None,
+            completed_at: None,
+            user_id: None,
+            dedupe_key: None,
             created_at: now,
         })
     }
+
+    pub fn is_exhausted(&self) -> bool {
+        self.attempts >= self.max_attempts
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum JobStatus {
+    #[default]
+    Pending,
+    Running,
+    Completed,
+    Dead,
+    Cancelled,
+}
+
+impl fmt::Display for JobStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = match self {
+            Self::Pending => "pending",
+            Self::Running => "running",
+            Self::Completed => "completed",
+            Self::Dead => "dead",
+            Self::Cancelled => "cancelled",
+        };
+
+        write!(f, "{value}")
+    }
+}
+
+impl FromStr for JobStatus {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pending" => Ok(Self::Pending),
+            "running" => Ok(Self::Running),
+            "completed" => Ok(Self::Completed),
+            "dead" => Ok(Self::Dead),
+            "cancelled" => Ok(Self::Cancelled),
+            _ => Err(Error::UnknownStatus(s.into())),
+        }
+    }
 }
 
 pub trait JobProducer: Send + Sync {
@@ -37,6 +108,57 @@ pub trait JobProducer: Send + Sync {
 
 pub trait JobConsumer: Sync {
     fn pop(&mut self) -> impl Future<Output = Result<Option<Job>, Error>> + Send;
+
+    /// Marks a job as completed. `attempts` identifies the run that popped the job, so that a run
+    /// whose job has since been picked up again as stale gets [`Error::LostOwnership`] instead.
+    fn complete(
+        &mut self,
+        job_id: Uuid,
+        attempts: u32,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+
+    /// Schedules a retry of a job, or moves it to the dead-letter queue once its attempts are
+    /// exhausted. Ownership is checked the same way as in [`JobConsumer::complete`].
+    fn fail(
+        &mut self,
+        job_id: Uuid,
+        attempts: u32,
+        message: String,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+}
+
+pub trait JobStore: Sync {
+    fn find(&self, params: JobFindParams) -> impl Future<Output = Result<Vec<Job>, Error>> + Send;
+
+    fn find_by_id(&self, id: Uuid) -> impl Future<Output = Result<Option<Job>, Error>> + Send {
+        async move {
+            let mut jobs = self
+                .find(JobFindParams {
+                    id: Some(id),
+                    ..Default::default()
+                })
+                .await?;
+            if jobs.is_empty() {
+                return Ok(None);
+            }
+
+            Ok(Some(jobs.swap_remove(0)))
+        }
+    }
+
+    fn retry(&self, id: Uuid) -> impl Future<Output = Result<(), Error>> + Send;
+
+    fn cancel(&self, id: Uuid) -> impl Future<Output = Result<(), Error>> + Send;
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct JobFindParams {
+    pub id: Option<Uuid>,
+    pub user_id: Option<Uuid>,
+    pub job_type: Option<String>,
+    pub status: Option<JobStatus>,
+    pub cursor: Option<DateTime<Utc>>,
+    pub limit: Option<usize>,
 }
 
 #[derive(Debug, Clone)]
@@ -63,6 +185,14 @@ impl JobConsumer for TokioJobConsumer {
 
         Ok(next)
     }
+
+    async fn complete(&mut self, _job_id: Uuid, _attempts: u32) -> Result<(), Error> {
+        Ok(())
+    }
+
+    async fn fail(&mut self, _job_id: Uuid, _attempts: u32, _message: String) -> Result<(), Error> {
+        Ok(())
+    }
 }
 
 #[derive(Debug)]
@@ -107,4 +237,22 @@ pub enum Error {
 
     #[error(transparent)]
     Serialize(#[from] serde_json::Error),
+
+    #[error(transparent)]
+    Database(#[from] sqlx::Error),
+
+    #[error("job not found with ID: {0}")]
+    NotFound(Uuid),
+
+    #[error("job {0} is neither dead nor cancelled")]
+    NotRetryable(Uuid),
+
+    #[error("job {0} is no longer pending")]
+    NotCancellable(Uuid),
+
+    #[error("job {0} is no longer owned by this run")]
+    LostOwnership(Uuid),
+
+    #[error("unknown job status: {0}")]
+    UnknownStatus(String),
 }
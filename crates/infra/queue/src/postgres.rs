@@ -0,0 +1,206 @@
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{Error, Job, JobConsumer, JobFindParams, JobProducer, JobStore};
+
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+const STALE_TIMEOUT: Duration = Duration::from_secs(30 * 60);
+
+#[derive(Debug, Clone)]
+pub struct PostgresJobProducer {
+    pool: PgPool,
+}
+
+impl PostgresJobProducer {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl JobProducer for PostgresJobProducer {
+    async fn push(&mut self, job: Job) -> Result<(), Error> {
+        sqlx::query_file!(
+            "queries/jobs/insert.sql",
+            job.id,
+            job.job_type,
+            job.data,
+            job.status.to_string(),
+            job.message,
+            job.attempts as i32,
+            job.max_attempts as i32,
+            job.backoff.as_secs() as i32,
+            job.run_at,
+            job.user_id,
+            job.dedupe_key,
+            job.created_at
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PostgresJobConsumer {
+    pool: PgPool,
+    job_type: String,
+}
+
+impl PostgresJobConsumer {
+    pub fn new<T: Into<String>>(pool: PgPool, job_type: T) -> Self {
+        Self {
+            pool,
+            job_type: job_type.into(),
+        }
+    }
+}
+
+impl JobConsumer for PostgresJobConsumer {
+    async fn pop(&mut self) -> Result<Option<Job>, Error> {
+        loop {
+            let row = sqlx::query_file_as!(
+                JobRow,
+                "queries/jobs/pop.sql",
+                self.job_type,
+                STALE_TIMEOUT.as_secs_f64()
+            )
+            .fetch_optional(&self.pool)
+            .await?;
+
+            if let Some(row) = row {
+                return row.try_into().map(Some);
+            }
+
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+    }
+
+    async fn complete(&mut self, job_id: Uuid, attempts: u32) -> Result<(), Error> {
+        let result = sqlx::query_file!("queries/jobs/complete.sql", job_id, attempts as i32)
+            .execute(&self.pool)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(Error::LostOwnership(job_id));
+        }
+
+        Ok(())
+    }
+
+    async fn fail(&mut self, job_id: Uuid, attempts: u32, message: String) -> Result<(), Error> {
+        let result = sqlx::query_file!("queries/jobs/fail.sql", job_id, message, attempts as i32)
+            .execute(&self.pool)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(Error::LostOwnership(job_id));
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PostgresJobStore {
+    pool: PgPool,
+}
+
+impl PostgresJobStore {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl JobStore for PostgresJobStore {
+    async fn find(&self, params: JobFindParams) -> Result<Vec<Job>, Error> {
+        let rows = sqlx::query_file_as!(
+            JobRow,
+            "queries/jobs/find.sql",
+            params.id,
+            params.user_id,
+            params.job_type,
+            params.status.map(|e| e.to_string()),
+            params.cursor,
+            params.limit.map(|e| e as i64)
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(TryInto::try_into).collect()
+    }
+
+    async fn retry(&self, id: Uuid) -> Result<(), Error> {
+        let result = sqlx::query_file!("queries/jobs/retry.sql", id)
+            .execute(&self.pool)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return match self.find_by_id(id).await? {
+                Some(_) => Err(Error::NotRetryable(id)),
+                None => Err(Error::NotFound(id)),
+            };
+        }
+
+        Ok(())
+    }
+
+    async fn cancel(&self, id: Uuid) -> Result<(), Error> {
+        let result = sqlx::query_file!("queries/jobs/cancel.sql", id)
+            .execute(&self.pool)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return match self.find_by_id(id).await? {
+                Some(_) => Err(Error::NotCancellable(id)),
+                None => Err(Error::NotFound(id)),
+            };
+        }
+
+        Ok(())
+    }
+}
+
+struct JobRow {
+    id: Uuid,
+    job_type: String,
+    data_json: Value,
+    status: String,
+    message: Option<String>,
+    attempts: i32,
+    max_attempts: i32,
+    backoff_secs: i32,
+    run_at: DateTime<Utc>,
+    started_at: Option<DateTime<Utc>>,
+    completed_at: Option<DateTime<Utc>>,
+    user_id: Option<Uuid>,
+    dedupe_key: Option<String>,
+    created_at: DateTime<Utc>,
+}
+
+impl TryFrom<JobRow> for Job {
+    type Error = Error;
+
+    fn try_from(value: JobRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: value.id,
+            job_type: value.job_type,
+            data: value.data_json,
+            status: value.status.parse()?,
+            message: value.message,
+            attempts: value.attempts as u32,
+            max_attempts: value.max_attempts as u32,
+            backoff: Duration::from_secs(value.backoff_secs as u64),
+            run_at: value.run_at,
+            started_at: value.started_at,
+            completed_at: value.completed_at,
+            user_id: value.user_id,
+            dedupe_key: value.dedupe_key,
+            created_at: value.created_at,
+        })
+    }
+}
@@ -75,6 +75,28 @@ WITH
     FROM
       unnest($20::TEXT[], $21::TEXT[]) AS t (bookmark_link, tag_title)
   ),
+  input_entries AS (
+    SELECT
+      *
+    FROM
+      unnest(
+        $22::UUID[],
+        $23::TEXT[],
+        $24::TEXT[],
+        $25::TEXT[],
+        $26::TIMESTAMPTZ[],
+        $27::TEXT[],
+        $28::TIMESTAMPTZ[]
+      ) AS t (
+        id,
+        feed_source_url,
+        link,
+        title,
+        published_at,
+        note,
+        starred_at
+      )
+  ),
   s AS (
     INSERT INTO
       subscriptions (title, feed_id, user_id)
@@ -92,13 +114,50 @@ WITH
       id,
       feed_id
   ),
+  new_fe AS (
+    INSERT INTO
+      feed_entries (
+        id,
+        link,
+        title,
+        published_at,
+        feed_id,
+        created_at,
+        updated_at
+      )
+    SELECT
+      i.id,
+      i.link,
+      i.title,
+      i.published_at,
+      f.id,
+      now(),
+      now()
+    FROM
+      input_entries i
+      JOIN f ON f.source_url = i.feed_source_url
+    ON CONFLICT (feed_id, link) DO NOTHING
+    RETURNING
+      id,
+      link,
+      feed_id
+  ),
   fe AS (
     SELECT
       fe.id,
-      fe.feed_id
+      fe.link,
+      f.source_url
     FROM
       feed_entries fe
       JOIN f ON f.id = fe.feed_id
+    UNION ALL
+    SELECT
+      fe.id,
+      fe.link,
+      f.source_url
+    FROM
+      new_fe fe
+      JOIN f ON f.id = fe.feed_id
   ),
   orphaned_subscriptions AS (
     DELETE FROM subscriptions old USING s
@@ -186,6 +245,43 @@ WITH
       JOIN s ON s.feed_id = f.id
       JOIN t ON t.title = i.tag_title
     ON CONFLICT (subscription_id, tag_id) DO NOTHING
+  ),
+  se AS (
+    INSERT INTO
+      starred_entries (feed_entry_id, user_id, created_at)
+    SELECT
+      fe.id,
+      $1,
+      i.starred_at
+    FROM
+      input_entries i
+      JOIN fe ON fe.source_url = i.feed_source_url
+      AND fe.link = i.link
+    WHERE
+      i.starred_at IS NOT NULL
+    ON CONFLICT (user_id, feed_entry_id) DO UPDATE
+    SET
+      created_at = EXCLUDED.created_at
+  ),
+  en AS (
+    INSERT INTO
+      entry_notes (feed_entry_id, user_id, content, created_at, updated_at)
+    SELECT
+      fe.id,
+      $1,
+      i.note,
+      coalesce(i.starred_at, now()),
+      coalesce(i.starred_at, now())
+    FROM
+      input_entries i
+      JOIN fe ON fe.source_url = i.feed_source_url
+      AND fe.link = i.link
+    WHERE
+      i.note IS NOT NULL
+    ON CONFLICT (user_id, feed_entry_id) DO UPDATE
+    SET
+      content = EXCLUDED.content,
+      updated_at = EXCLUDED.updated_at
   )
 INSERT INTO
   bookmark_tags (bookmark_id, tag_id)
@@ -1,12 +1,31 @@
 SELECT
   b.id,
-  b.link AS "link: DbUrl",
+  b.link,
   b.title,
-  b.thumbnail_url AS "thumbnail_url: DbUrl",
+  b.thumbnail_url,
   b.published_at,
   b.author,
   b.archived_path,
-  coalesce(bt.tags, '[]'::JSONB) AS "tags: Json<Vec<TagRow>>",
+  coalesce(bt.tags, '[]'::JSONB) AS tags,
+  CASE
+    WHEN $4::TEXT IS NOT NULL THEN ts_rank(b.search_vector, websearch_to_tsquery('english', $4))
+  END AS rank,
+  CASE
+    WHEN $4::TEXT IS NOT NULL THEN ts_headline(
+      'english',
+      replace(
+        replace(
+          replace(b.title, '&', '&amp;'),
+          '<',
+          '&lt;'
+        ),
+        '>',
+        '&gt;'
+      ),
+      websearch_to_tsquery('english', $4),
+      'StartSel=<mark>, StopSel=</mark>'
+    )
+  END AS snippet,
   b.created_at,
   b.updated_at
 FROM
@@ -61,10 +80,6 @@ WHERE
     )
   )
   AND (
-    $4::TIMESTAMPTZ IS NULL
-    OR b.created_at > $4
+    $4::TEXT IS NULL
+    OR b.search_vector @@ websearch_to_tsquery('english', $4)
   )
-ORDER BY
-  b.created_at DESC
-LIMIT
-  $5
@@ -2,6 +2,7 @@ SELECT
   id,
   title,
   filter_json AS "filter_json: Json<BookmarkFilter>",
+  entry_filter_json AS "entry_filter_json: Json<EntryFilter>",
   created_at,
   updated_at
 FROM
@@ -3,14 +3,16 @@ INSERT INTO
     id,
     title,
     filter_json,
+    entry_filter_json,
     user_id,
     created_at,
     updated_at
   )
 VALUES
-  ($1, $2, $3, $4, $5, $6)
+  ($1, $2, $3, $4, $5, $6, $7)
 ON CONFLICT (id) DO UPDATE
 SET
   title = EXCLUDED.title,
   filter_json = EXCLUDED.filter_json,
+  entry_filter_json = EXCLUDED.entry_filter_json,
   updated_at = EXCLUDED.updated_at
@@ -4,17 +4,91 @@ SELECT
   fe.title,
   fe.published_at,
   fe.description,
+  fe.content,
+  fe.content_type,
+  fe.excerpt,
+  CASE
+    WHEN s.fetch_full_article THEN fe.article_content
+  END AS article_content,
   fe.author,
   fe.thumbnail_url,
+  coalesce(fee.enclosures, '[]'::JSONB) AS enclosures,
   rs.created_at AS read_at,
-  fe.feed_id
+  rs.feed_entry_id IS NOT NULL AS has_read,
+  se.created_at AS starred_at,
+  se.feed_entry_id IS NOT NULL AS starred,
+  en.content AS note,
+  fe.feed_id,
+  s.id AS subscription_id,
+  s.title AS subscription_title,
+  lower(
+    substring(
+      f.link
+      FROM
+        '^[^:]+://(?:[^@/]*@)?([^:/?#]+)'
+    )
+  ) AS feed_host,
+  CASE
+    WHEN $6::TEXT IS NOT NULL THEN ts_rank(fe.search_vector, websearch_to_tsquery('english', $6))
+  END AS rank,
+  CASE
+    WHEN $6::TEXT IS NOT NULL THEN ts_headline(
+      'english',
+      replace(
+        replace(
+          replace(coalesce(fe.excerpt, fe.title), '&', '&amp;'),
+          '<',
+          '&lt;'
+        ),
+        '>',
+        '&gt;'
+      ),
+      websearch_to_tsquery('english', $6),
+      'StartSel=<mark>, StopSel=</mark>, MaxFragments=2'
+    )
+  END AS snippet
 FROM
   feed_entries fe
-  LEFT JOIN read_statuses rs ON rs.feed_entry_id = fe.id
   INNER JOIN feeds f ON f.id = fe.feed_id
   INNER JOIN subscriptions s ON s.feed_id = f.id
+  LEFT JOIN read_statuses rs ON rs.feed_entry_id = fe.id
+  AND rs.user_id = s.user_id
+  LEFT JOIN starred_entries se ON se.feed_entry_id = fe.id
+  AND se.user_id = s.user_id
+  LEFT JOIN entry_notes en ON en.feed_entry_id = fe.id
+  AND en.user_id = s.user_id
+  LEFT JOIN LATERAL (
+    SELECT
+      jsonb_agg(
+        jsonb_build_object(
+          'url',
+          fee.url,
+          'mime_type',
+          fee.mime_type,
+          'length',
+          fee.length,
+          'duration_sec',
+          fee.duration_sec
+        )
+        ORDER BY
+          fee.url ASC
+      ) AS enclosures
+    FROM
+      feed_entry_enclosures fee
+    WHERE
+      fee.feed_entry_id = fe.id
+  ) AS fee ON TRUE
 WHERE
   s.user_id = $1
+  AND NOT EXISTS (
+    SELECT
+      1
+    FROM
+      hidden_entries he
+    WHERE
+      he.feed_entry_id = fe.id
+      AND he.user_id = s.user_id
+  )
   AND (
     $2::UUID IS NULL
     OR fe.id = $2
@@ -25,7 +99,7 @@ WHERE
   )
   AND (
     $4::BOOL IS NULL
-    OR rs.feed_entry_id IS NOT NULL
+    OR (rs.feed_entry_id IS NOT NULL) = $4
   )
   AND (
     $5::UUID[] IS NULL
@@ -38,16 +112,17 @@ WHERE
         st.subscription_id = s.id
         AND st.tag_id = ANY ($5)
     )
+    OR EXISTS (
+      SELECT
+        1
+      FROM
+        entry_tags et
+      WHERE
+        et.feed_entry_id = fe.id
+        AND et.tag_id = ANY ($5)
+    )
   )
   AND (
-    (
-      $6::TIMESTAMPTZ IS NULL
-      OR $7::UUID IS NULL
-    )
-    OR (fe.published_at, fe.id) > ($6, $7)
+    $6::TEXT IS NULL
+    OR fe.search_vector @@ websearch_to_tsquery('english', $6)
   )
-ORDER BY
-  fe.published_at DESC,
-  fe.id DESC
-LIMIT
-  $8
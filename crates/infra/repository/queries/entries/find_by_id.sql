@@ -1,10 +1,18 @@
 SELECT
   fe.id,
-  rs.created_at AS read_at,
-  rs.user_id
+  rs.created_at AS "read_at?",
+  se.created_at AS "starred_at?",
+  en.content AS "note?",
+  s.user_id
 FROM
   feed_entries fe
+  INNER JOIN subscriptions s ON s.feed_id = fe.feed_id
   LEFT JOIN read_statuses rs ON rs.feed_entry_id = fe.id
+  AND rs.user_id = s.user_id
+  LEFT JOIN starred_entries se ON se.feed_entry_id = fe.id
+  AND se.user_id = s.user_id
+  LEFT JOIN entry_notes en ON en.feed_entry_id = fe.id
+  AND en.user_id = s.user_id
 WHERE
   fe.id = $1
-  AND rs.user_id = $2
+  AND s.user_id = $2
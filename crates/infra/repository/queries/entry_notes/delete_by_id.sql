@@ -0,0 +1,4 @@
+DELETE FROM entry_notes
+WHERE
+  feed_entry_id = $1
+  AND user_id = $2
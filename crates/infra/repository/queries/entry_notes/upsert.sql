@@ -0,0 +1,10 @@
+INSERT INTO
+  entry_notes (feed_entry_id, user_id, content, created_at, updated_at)
+VALUES
+  ($1, $2, $3, now(), now())
+ON CONFLICT (user_id, feed_entry_id) DO UPDATE
+SET
+  content = EXCLUDED.content,
+  updated_at = EXCLUDED.updated_at
+WHERE
+  entry_notes.content <> EXCLUDED.content
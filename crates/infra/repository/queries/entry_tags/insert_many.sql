@@ -0,0 +1,12 @@
+INSERT INTO
+  entry_tags (feed_entry_id, tag_id, created_at, updated_at)
+SELECT
+  fe.id,
+  t.id,
+  now(),
+  now()
+FROM
+  unnest($1::UUID[]) AS fe (id)
+  INNER JOIN tags t ON t.id = ANY ($2)
+  AND t.user_id = $3
+ON CONFLICT (feed_entry_id, tag_id) DO NOTHING
@@ -0,0 +1,33 @@
+SELECT
+  fe.id,
+  fe.link AS "link: DbUrl",
+  fe.title,
+  fe.published_at,
+  fe.description,
+  fe.content,
+  fe.content_type AS "content_type: DbContentType",
+  fe.excerpt,
+  fe.author,
+  fe.thumbnail_url AS "thumbnail_url: DbUrl",
+  fe.article_content,
+  fe.article_fetched_at,
+  fe.created_at,
+  fe.updated_at
+FROM
+  feed_entries fe
+WHERE
+  fe.article_fetched_at IS NULL
+  AND fe.published_at > $1
+  AND EXISTS (
+    SELECT
+      1
+    FROM
+      subscriptions s
+    WHERE
+      s.feed_id = fe.feed_id
+      AND s.fetch_full_article
+  )
+ORDER BY
+  fe.published_at DESC
+LIMIT
+  $2
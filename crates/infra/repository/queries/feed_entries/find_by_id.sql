@@ -0,0 +1,19 @@
+SELECT
+  fe.id,
+  fe.link AS "link: DbUrl",
+  fe.title,
+  fe.published_at,
+  fe.description,
+  fe.content,
+  fe.content_type AS "content_type: DbContentType",
+  fe.excerpt,
+  fe.author,
+  fe.thumbnail_url AS "thumbnail_url: DbUrl",
+  fe.article_content,
+  fe.article_fetched_at,
+  fe.created_at,
+  fe.updated_at
+FROM
+  feed_entries fe
+WHERE
+  fe.id = $1
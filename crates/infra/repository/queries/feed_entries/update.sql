@@ -0,0 +1,6 @@
+UPDATE feed_entries
+SET
+  article_content = $2,
+  article_fetched_at = $3
+WHERE
+  id = $1
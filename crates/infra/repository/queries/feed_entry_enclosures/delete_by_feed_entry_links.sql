@@ -0,0 +1,5 @@
+DELETE FROM feed_entry_enclosures fee USING feed_entries fe
+WHERE
+  fee.feed_entry_id = fe.id
+  AND fe.feed_id = $1
+  AND fe.link = ANY ($2::TEXT[])
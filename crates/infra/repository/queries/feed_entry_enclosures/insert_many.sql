@@ -0,0 +1,30 @@
+INSERT INTO
+  feed_entry_enclosures (
+    feed_entry_id,
+    url,
+    mime_type,
+    length,
+    duration_sec
+  )
+SELECT DISTINCT
+  ON (fe.id, t.url) fe.id,
+  t.url,
+  t.mime_type,
+  t.length,
+  t.duration_sec
+FROM
+  unnest(
+    $2::TEXT[],
+    $3::TEXT[],
+    $4::TEXT[],
+    $5::BIGINT[],
+    $6::INTEGER[]
+  ) AS t (
+    feed_entry_link,
+    url,
+    mime_type,
+    length,
+    duration_sec
+  )
+  INNER JOIN feed_entries fe ON fe.feed_id = $1
+  AND fe.link = t.feed_entry_link
@@ -8,6 +8,14 @@ SELECT
   status AS "status: DbFeedStatus",
   refresh_interval_min,
   last_refreshed_at,
+  etag,
+  last_modified,
+  failure_count,
+  last_error,
+  hub_url AS "hub_url: DbUrl",
+  topic_url AS "topic_url: DbUrl",
+  websub_secret,
+  websub_lease_expires_at,
   created_at,
   updated_at
 FROM
@@ -0,0 +1,8 @@
+SELECT
+  link AS "link: DbUrl",
+  published_at
+FROM
+  feed_entries
+WHERE
+  feed_id = $1
+  AND published_at >= $2
@@ -8,13 +8,26 @@ SELECT
   status AS "status: DbFeedStatus",
   refresh_interval_min,
   last_refreshed_at,
+  etag,
+  last_modified,
+  failure_count,
+  last_error,
+  hub_url AS "hub_url: DbUrl",
+  topic_url AS "topic_url: DbUrl",
+  websub_secret,
+  websub_lease_expires_at,
   created_at,
   updated_at
 FROM
   feeds
 WHERE
-  last_refreshed_at IS NULL
-  OR last_refreshed_at + (refresh_interval_min * INTERVAL '1 minute') <= now()
+  status != 'disabled'
+  AND (
+    last_refreshed_at IS NULL
+    OR last_refreshed_at + (
+      refresh_interval_min * power(2, least(failure_count, 6)) * INTERVAL '1 minute'
+    ) <= now()
+  )
 ORDER BY
   last_refreshed_at ASC,
   refresh_interval_min ASC
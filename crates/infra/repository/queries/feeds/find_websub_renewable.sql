@@ -0,0 +1,33 @@
+SELECT
+  id,
+  source_url AS "source_url: DbUrl",
+  link AS "link: DbUrl",
+  title,
+  description,
+  is_custom,
+  status AS "status: DbFeedStatus",
+  refresh_interval_min,
+  last_refreshed_at,
+  etag,
+  last_modified,
+  failure_count,
+  last_error,
+  hub_url AS "hub_url: DbUrl",
+  topic_url AS "topic_url: DbUrl",
+  websub_secret,
+  websub_lease_expires_at,
+  created_at,
+  updated_at
+FROM
+  feeds
+WHERE
+  hub_url IS NOT NULL
+  AND status != 'disabled'
+  AND (
+    websub_lease_expires_at IS NULL
+    OR websub_lease_expires_at <= $1
+  )
+ORDER BY
+  websub_lease_expires_at ASC NULLS FIRST
+LIMIT
+  $2
@@ -1,6 +0,0 @@
-UPDATE feeds
-SET
-  status = 'failed',
-  last_refreshed_at = now()
-WHERE
-  source_url = $1
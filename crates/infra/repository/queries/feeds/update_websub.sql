@@ -0,0 +1,7 @@
+UPDATE feeds
+SET
+  websub_secret = $2,
+  websub_lease_expires_at = $3,
+  updated_at = $4
+WHERE
+  id = $1
@@ -9,19 +9,47 @@ WITH
         description,
         is_custom,
         status,
+        refresh_interval_min,
         last_refreshed_at,
+        etag,
+        last_modified,
+        failure_count,
+        last_error,
+        hub_url,
+        topic_url,
+        websub_secret,
+        websub_lease_expires_at,
         created_at,
         updated_at
       )
     VALUES
-      ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+      ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19)
     ON CONFLICT (source_url) DO UPDATE
     SET
       link = EXCLUDED.link,
       title = EXCLUDED.title,
       description = EXCLUDED.description,
       status = EXCLUDED.status,
+      refresh_interval_min = EXCLUDED.refresh_interval_min,
       last_refreshed_at = EXCLUDED.last_refreshed_at,
+      etag = EXCLUDED.etag,
+      last_modified = EXCLUDED.last_modified,
+      failure_count = EXCLUDED.failure_count,
+      last_error = EXCLUDED.last_error,
+      hub_url = EXCLUDED.hub_url,
+      topic_url = EXCLUDED.topic_url,
+      -- The WebSub secret and lease are only written through targeted updates, so that a refresh
+      -- can't overwrite them with stale values. They are cleared if the hub or topic changed.
+      websub_secret = CASE
+        WHEN feeds.hub_url IS DISTINCT FROM EXCLUDED.hub_url
+        OR feeds.topic_url IS DISTINCT FROM EXCLUDED.topic_url THEN NULL
+        ELSE feeds.websub_secret
+      END,
+      websub_lease_expires_at = CASE
+        WHEN feeds.hub_url IS DISTINCT FROM EXCLUDED.hub_url
+        OR feeds.topic_url IS DISTINCT FROM EXCLUDED.topic_url THEN NULL
+        ELSE feeds.websub_lease_expires_at
+      END,
       is_custom = EXCLUDED.is_custom,
       updated_at = EXCLUDED.updated_at
   ),
@@ -31,15 +59,18 @@ WITH
       $1 AS feed_id
     FROM
       unnest(
-        $11::UUID[],
-        $12::TEXT[],
-        $13::TEXT[],
-        $14::TIMESTAMPTZ[],
-        $15::TEXT[],
-        $16::TEXT[],
-        $17::UUID[],
-        $18::TIMESTAMPTZ[],
-        $19::TIMESTAMPTZ[]
+        $20::UUID[],
+        $21::TEXT[],
+        $22::TEXT[],
+        $23::TIMESTAMPTZ[],
+        $24::TEXT[],
+        $25::TEXT[],
+        $26::TEXT[],
+        $27::TIMESTAMPTZ[],
+        $28::TIMESTAMPTZ[],
+        $29::TEXT[],
+        $30::TEXT[],
+        $31::TEXT[]
       ) AS t (
         id,
         link,
@@ -49,7 +80,10 @@ WITH
         author,
         thumbnail_url,
         created_at,
-        updated_at
+        updated_at,
+        content,
+        content_type,
+        excerpt
       )
   )
 INSERT INTO
@@ -59,6 +93,9 @@ INSERT INTO
     title,
     published_at,
     description,
+    content,
+    content_type,
+    excerpt,
     author,
     thumbnail_url,
     feed_id,
@@ -71,6 +108,9 @@ SELECT
   fe.title,
   fe.published_at,
   fe.description,
+  fe.content,
+  fe.content_type,
+  fe.excerpt,
   fe.author,
   fe.thumbnail_url,
   fe.feed_id,
@@ -83,6 +123,9 @@ SET
   title = EXCLUDED.title,
   published_at = EXCLUDED.published_at,
   description = EXCLUDED.description,
+  content = EXCLUDED.content,
+  content_type = EXCLUDED.content_type,
+  excerpt = EXCLUDED.excerpt,
   author = EXCLUDED.author,
   thumbnail_url = EXCLUDED.thumbnail_url,
   updated_at = EXCLUDED.updated_at
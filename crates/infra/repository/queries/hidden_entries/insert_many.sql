@@ -0,0 +1,9 @@
+INSERT INTO
+  hidden_entries (feed_entry_id, user_id, created_at)
+SELECT
+  fe.id,
+  $2,
+  now()
+FROM
+  unnest($1::UUID[]) AS fe (id)
+ON CONFLICT (user_id, feed_entry_id) DO NOTHING
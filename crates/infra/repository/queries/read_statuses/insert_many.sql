@@ -0,0 +1,10 @@
+INSERT INTO
+  read_statuses (feed_entry_id, user_id, created_at, updated_at)
+SELECT
+  fe.id,
+  $2,
+  now(),
+  now()
+FROM
+  unnest($1::UUID[]) AS fe (id)
+ON CONFLICT (user_id, feed_entry_id) DO NOTHING
@@ -0,0 +1,24 @@
+SELECT
+  id,
+  title,
+  condition_json AS "condition_json: Json<EntryFilter>",
+  actions_json AS "actions_json: Json<Vec<RuleAction>>",
+  enabled,
+  created_at,
+  updated_at
+FROM
+  rules
+WHERE
+  user_id = $1
+  AND (
+    $2::UUID IS NULL
+    OR id = $2
+  )
+  AND (
+    $3::TEXT IS NULL
+    OR title > $3
+  )
+ORDER BY
+  title ASC
+LIMIT
+  $4
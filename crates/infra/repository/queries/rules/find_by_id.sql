@@ -0,0 +1,14 @@
+SELECT
+  id,
+  title,
+  condition_json AS "condition_json: Json<EntryFilter>",
+  actions_json AS "actions_json: Json<Vec<RuleAction>>",
+  enabled,
+  user_id,
+  created_at,
+  updated_at
+FROM
+  rules
+WHERE
+  id = $1
+  AND user_id = $2
@@ -0,0 +1,25 @@
+SELECT
+  r.id,
+  r.title,
+  r.condition_json AS "condition_json: Json<EntryFilter>",
+  r.actions_json AS "actions_json: Json<Vec<RuleAction>>",
+  r.enabled,
+  r.user_id,
+  r.created_at,
+  r.updated_at
+FROM
+  rules r
+WHERE
+  r.enabled
+  AND EXISTS (
+    SELECT
+      1
+    FROM
+      subscriptions s
+    WHERE
+      s.user_id = r.user_id
+      AND s.feed_id = $1
+  )
+ORDER BY
+  r.user_id ASC,
+  r.created_at ASC
@@ -0,0 +1,7 @@
+SELECT
+  id
+FROM
+  feed_entries
+WHERE
+  feed_id = $1
+  AND rules_applied_at IS NULL
@@ -0,0 +1,5 @@
+UPDATE feed_entries
+SET
+  rules_applied_at = now()
+WHERE
+  id = ANY ($1)
@@ -0,0 +1,20 @@
+INSERT INTO
+  rules (
+    id,
+    title,
+    condition_json,
+    actions_json,
+    enabled,
+    user_id,
+    created_at,
+    updated_at
+  )
+VALUES
+  ($1, $2, $3, $4, $5, $6, $7, $8)
+ON CONFLICT (id) DO UPDATE
+SET
+  title = EXCLUDED.title,
+  condition_json = EXCLUDED.condition_json,
+  actions_json = EXCLUDED.actions_json,
+  enabled = EXCLUDED.enabled,
+  updated_at = EXCLUDED.updated_at
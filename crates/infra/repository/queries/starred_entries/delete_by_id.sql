@@ -0,0 +1,4 @@
+DELETE FROM starred_entries
+WHERE
+  feed_entry_id = $1
+  AND user_id = $2
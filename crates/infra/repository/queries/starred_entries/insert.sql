@@ -0,0 +1,5 @@
+INSERT INTO
+  starred_entries (feed_entry_id, user_id, created_at)
+VALUES
+  ($1, $2, $3)
+ON CONFLICT (user_id, feed_entry_id) DO NOTHING
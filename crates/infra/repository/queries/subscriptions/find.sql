@@ -5,6 +5,13 @@ SELECT
   s.title,
   s.description,
   s.feed_id,
+  s.fetch_full_article,
+  f.status AS "feed_status: DbFeedStatus",
+  f.failure_count AS feed_failure_count,
+  f.last_error AS feed_last_error,
+  CASE
+    WHEN $7::BOOLEAN THEN coalesce(uc.total_count, 0)
+  END AS total_count,
   coalesce(uc.unread_count, 0) AS "unread_count!",
   coalesce(t.tags, '[]'::JSONB) AS "tags!: Json<Vec<TagRow>>",
   s.created_at,
@@ -15,13 +22,27 @@ FROM
   LEFT JOIN (
     SELECT
       s_inner.id AS subscription_id,
-      count(fe.id) AS unread_count
+      count(fe.id) AS total_count,
+      count(fe.id) FILTER (
+        WHERE
+          rs.feed_entry_id IS NULL
+      ) AS unread_count
     FROM
       subscriptions s_inner
       INNER JOIN feed_entries fe ON s_inner.feed_id = fe.feed_id
       LEFT JOIN read_statuses rs ON rs.feed_entry_id = fe.id
+      AND rs.user_id = s_inner.user_id
     WHERE
-      rs.feed_entry_id IS NULL
+      s_inner.user_id = $1
+      AND NOT EXISTS (
+        SELECT
+          1
+        FROM
+          hidden_entries he
+        WHERE
+          he.feed_entry_id = fe.id
+          AND he.user_id = s_inner.user_id
+      )
     GROUP BY
       s_inner.id
   ) AS uc ON s.id = uc.subscription_id
@@ -3,6 +3,7 @@ SELECT
   s.title,
   s.description,
   s.feed_id,
+  s.fetch_full_article,
   array_agg(
     st.tag_id
     ORDER BY
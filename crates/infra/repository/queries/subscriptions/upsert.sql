@@ -6,29 +6,31 @@ WITH
         title,
         description,
         feed_id,
+        fetch_full_article,
         user_id,
         created_at,
         updated_at
       )
     VALUES
-      ($1, $2, $3, $4, $6, $7, $8)
+      ($1, $2, $3, $4, $5, $7, $8, $9)
     ON CONFLICT (id) DO UPDATE
     SET
       title = EXCLUDED.title,
       description = EXCLUDED.description,
+      fetch_full_article = EXCLUDED.fetch_full_article,
       updated_at = EXCLUDED.updated_at
   ),
   input_tags AS (
     SELECT
       *
     FROM
-      unnest($5::UUID[]) AS t (id)
+      unnest($6::UUID[]) AS t (id)
   ),
   deleted_st AS (
     DELETE FROM subscription_tags
     WHERE
       subscription_id = $1
-      AND NOT tag_id = ANY ($5)
+      AND NOT tag_id = ANY ($6)
   )
 INSERT INTO
   subscription_tags (subscription_id, tag_id, created_at, updated_at)
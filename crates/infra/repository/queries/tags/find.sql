@@ -1,21 +1,75 @@
 SELECT
-  id,
-  title,
-  created_at,
-  updated_at
+  t.id,
+  t.title,
+  CASE
+    WHEN $5 THEN coalesce(c.total_count, 0)
+  END AS total_count,
+  CASE
+    WHEN $5 THEN coalesce(c.unread_count, 0)
+  END AS unread_count,
+  t.created_at,
+  t.updated_at
 FROM
-  tags
+  tags t
+  LEFT JOIN (
+    SELECT
+      te.tag_id,
+      count(te.feed_entry_id) AS total_count,
+      count(te.feed_entry_id) FILTER (
+        WHERE
+          rs.feed_entry_id IS NULL
+      ) AS unread_count
+    FROM
+      (
+        SELECT
+          st.tag_id,
+          fe.id AS feed_entry_id
+        FROM
+          subscriptions s
+          INNER JOIN subscription_tags st ON st.subscription_id = s.id
+          INNER JOIN feed_entries fe ON fe.feed_id = s.feed_id
+        WHERE
+          s.user_id = $1
+        UNION
+        SELECT
+          et.tag_id,
+          fe.id AS feed_entry_id
+        FROM
+          subscriptions s
+          INNER JOIN feed_entries fe ON fe.feed_id = s.feed_id
+          INNER JOIN entry_tags et ON et.feed_entry_id = fe.id
+          INNER JOIN tags t_inner ON t_inner.id = et.tag_id
+          AND t_inner.user_id = s.user_id
+        WHERE
+          s.user_id = $1
+      ) AS te
+      LEFT JOIN read_statuses rs ON rs.feed_entry_id = te.feed_entry_id
+      AND rs.user_id = $1
+    WHERE
+      $5::BOOLEAN
+      AND NOT EXISTS (
+        SELECT
+          1
+        FROM
+          hidden_entries he
+        WHERE
+          he.feed_entry_id = te.feed_entry_id
+          AND he.user_id = $1
+      )
+    GROUP BY
+      te.tag_id
+  ) AS c ON c.tag_id = t.id
 WHERE
-  user_id = $1
+  t.user_id = $1
   AND (
     $2::UUID IS NULL
-    OR id = $2
+    OR t.id = $2
   )
   AND (
     $3::TEXT IS NULL
-    OR title > $3
+    OR t.title > $3
   )
 ORDER BY
-  title ASC
+  t.title ASC
 LIMIT
   $4
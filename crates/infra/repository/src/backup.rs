@@ -1,7 +1,8 @@
 use chrono::{DateTime, Utc};
 use colette_archival::{BackupRepository, ImportBackupParams};
-use colette_common::RepositoryError;
+use colette_common::{RepositoryError, uuid_generate};
 use sqlx::PgPool;
+use uuid::Uuid;
 
 use crate::DbUrl;
 
@@ -57,6 +58,14 @@ impl BackupRepository for PostgresBackupRepository {
         let mut st_feed_source_urls = Vec::<DbUrl>::new();
         let mut st_tag_titles = Vec::<String>::new();
 
+        let mut entry_ids = Vec::<Uuid>::new();
+        let mut entry_feed_source_urls = Vec::<DbUrl>::new();
+        let mut entry_links = Vec::<DbUrl>::new();
+        let mut entry_titles = Vec::<String>::new();
+        let mut entry_published_ats = Vec::<DateTime<Utc>>::new();
+        let mut entry_notes = Vec::<Option<String>>::new();
+        let mut entry_starred_ats = Vec::<Option<DateTime<Utc>>>::new();
+
         for subscription in params.backup.subscriptions {
             let source_url = DbUrl(subscription.source_url);
 
@@ -65,6 +74,16 @@ impl BackupRepository for PostgresBackupRepository {
                 st_tag_titles.push(tag.title);
             }
 
+            for entry in subscription.entries {
+                entry_ids.push(uuid_generate());
+                entry_feed_source_urls.push(source_url.clone());
+                entry_links.push(DbUrl(entry.link));
+                entry_titles.push(entry.title);
+                entry_published_ats.push(entry.published_at);
+                entry_notes.push(entry.note);
+                entry_starred_ats.push(entry.starred_at);
+            }
+
             feed_source_urls.push(source_url);
             subscription_titles.push(subscription.title);
             subscription_descriptions.push(subscription.description);
@@ -105,6 +124,13 @@ impl BackupRepository for PostgresBackupRepository {
             &st_tag_titles,
             &bt_bookmark_links as &[DbUrl],
             &bt_tag_titles,
+            &entry_ids,
+            &entry_feed_source_urls as &[DbUrl],
+            &entry_links as &[DbUrl],
+            &entry_titles,
+            &entry_published_ats,
+            &entry_notes as &[Option<String>],
+            &entry_starred_ats as &[Option<DateTime<Utc>>],
         )
         .execute(&self.pool)
         .await?;
@@ -5,11 +5,11 @@ use colette_crud::{
     Bookmark, BookmarkDateField, BookmarkFilter, BookmarkId, BookmarkRepository, BookmarkTextField,
     ImportBookmarksParams,
 };
-use colette_handler::{BookmarkDto, BookmarkQueryParams, BookmarkQueryRepository};
+use colette_handler::{BookmarkDto, BookmarkQueryParams, BookmarkQueryRepository, SearchMatch};
 use sqlx::{PgPool, QueryBuilder, types::Json};
 use uuid::Uuid;
 
-use crate::{DbUrl, ToColumn, ToSql, tag::TagRow};
+use crate::{DbUrl, ToColumn, ToSql, and_all, or_any, tag::TagRow};
 
 const BASE_QUERY: &str = include_str!("../queries/bookmarks/find.sql");
 
@@ -191,19 +191,32 @@ impl BookmarkQueryRepository for PostgresBookmarkRepository {
         &self,
         params: BookmarkQueryParams,
     ) -> Result<Vec<BookmarkDto>, RepositoryError> {
+        let (cursor_rank, cursor_created_at) = if let Some((rank, created_at)) = params.cursor {
+            (rank, Some(created_at))
+        } else {
+            (None, None)
+        };
+
         let mut qb = QueryBuilder::new(format!(
             r#"WITH results AS ({BASE_QUERY}) SELECT * FROM results WHERE TRUE"#
         ));
         if let Some(filter) = params.filter {
-            qb.push(format!(" {}", filter.to_sql()));
+            qb.push(format!(" AND ({})", filter.to_sql()));
         }
 
+        qb.push(
+            r#" AND ($6::TIMESTAMPTZ IS NULL OR (coalesce(rank, 0), created_at) < (coalesce($5::REAL, 0), $6))"#,
+        );
+        qb.push(" ORDER BY rank DESC NULLS LAST, created_at DESC LIMIT $7");
+
         let rows = qb
             .build_query_as::<BookmarkRow>()
             .bind(params.user_id)
             .bind(params.id)
             .bind(params.tags)
-            .bind(params.cursor)
+            .bind(params.search)
+            .bind(cursor_rank)
+            .bind(cursor_created_at)
             .bind(params.limit.map(|e| e as i64))
             .fetch_all(&self.pool)
             .await?;
@@ -222,6 +235,8 @@ struct BookmarkRow {
     author: Option<String>,
     archived_path: Option<String>,
     tags: Json<Vec<TagRow>>,
+    rank: Option<f32>,
+    snippet: Option<String>,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
 }
@@ -237,6 +252,10 @@ impl From<BookmarkRow> for BookmarkDto {
             author: value.author,
             archived_path: value.archived_path,
             tags: value.tags.0.into_iter().map(Into::into).collect(),
+            search_match: value.rank.map(|rank| SearchMatch {
+                rank,
+                snippet: value.snippet,
+            }),
             created_at: value.created_at,
             updated_at: value.updated_at,
         }
@@ -269,33 +288,15 @@ impl ToSql for BookmarkFilter {
         match self {
             BookmarkFilter::Text { field, op } => match field {
                 BookmarkTextField::Tag => format!(
-                    "EXISTS (SELECT 1 FROM bookmark_tags bt INNER JOIN tags t ON t.id = bt.tag_id WHERE bt.bookmark_id = b.id AND {})",
+                    "EXISTS (SELECT 1 FROM bookmark_tags bt INNER JOIN tags t ON t.id = bt.tag_id WHERE bt.bookmark_id = results.id AND {})",
                     (field.to_column().as_str(), op).to_sql()
                 ),
                 _ => (field.to_column().as_str(), op).to_sql(),
             },
             BookmarkFilter::Date { field, op } => (field.to_column().as_str(), op).to_sql(),
-            BookmarkFilter::And(filters) => {
-                let mut conditions = filters.into_iter().map(|e| e.to_sql()).collect::<Vec<_>>();
-                let mut and = conditions.swap_remove(0);
-
-                for condition in conditions {
-                    and = format!("{and} AND {condition}");
-                }
-
-                and
-            }
-            BookmarkFilter::Or(filters) => {
-                let mut conditions = filters.into_iter().map(|e| e.to_sql()).collect::<Vec<_>>();
-                let mut or = conditions.swap_remove(0);
-
-                for condition in conditions {
-                    or = format!("{or} OR {condition}");
-                }
-
-                or
-            }
-            BookmarkFilter::Not(filter) => format!("NOT {}", (*filter).to_sql()),
+            BookmarkFilter::And(filters) => and_all(filters.into_iter().map(|e| e.to_sql())),
+            BookmarkFilter::Or(filters) => or_any(filters.into_iter().map(|e| e.to_sql())),
+            BookmarkFilter::Not(filter) => format!("NOT ({})", (*filter).to_sql()),
         }
     }
 }
@@ -307,7 +308,33 @@ fn validate_base_query() {
         Option::<Uuid>::None,
         Option::<Uuid>::None,
         Option::<&[Uuid]>::None,
-        Option::<DateTime<Utc>>::None,
-        1
+        Option::<&str>::None
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use colette_crud::TextOp;
+
+    use super::*;
+
+    #[test]
+    fn compiles_empty_groups() {
+        assert_eq!(BookmarkFilter::And(Vec::new()).to_sql(), "TRUE");
+        assert_eq!(BookmarkFilter::Or(Vec::new()).to_sql(), "FALSE");
+    }
+
+    #[test]
+    fn compiles_tag_filter_as_subquery() {
+        let sql = BookmarkFilter::Text {
+            field: BookmarkTextField::Tag,
+            op: TextOp::Contains("rust".into()),
+        }
+        .to_sql();
+
+        assert_eq!(
+            sql,
+            "EXISTS (SELECT 1 FROM bookmark_tags bt INNER JOIN tags t ON t.id = bt.tag_id WHERE bt.bookmark_id = results.id AND t.title LIKE '%rust%')"
+        );
+    }
+}
@@ -1,7 +1,7 @@
 use chrono::{DateTime, Utc};
 use colette_authentication::UserId;
 use colette_common::RepositoryError;
-use colette_crud::{BookmarkFilter, Collection, CollectionId, CollectionRepository};
+use colette_crud::{BookmarkFilter, Collection, CollectionId, CollectionRepository, EntryFilter};
 use colette_handler::{CollectionDto, CollectionQueryParams, CollectionQueryRepository};
 use sqlx::{PgPool, types::Json};
 use uuid::Uuid;
@@ -42,6 +42,7 @@ impl CollectionRepository for PostgresCollectionRepository {
             data.id().as_inner(),
             data.title().as_inner(),
             Json(data.filter().to_owned()) as Json<BookmarkFilter>,
+            data.entry_filter().cloned().map(Json) as Option<Json<EntryFilter>>,
             data.user_id().as_inner(),
             data.created_at(),
             data.updated_at()
@@ -77,6 +78,7 @@ struct CollectionByIdRow {
     id: Uuid,
     title: String,
     filter_json: Json<BookmarkFilter>,
+    entry_filter_json: Option<Json<EntryFilter>>,
     user_id: Uuid,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
@@ -88,6 +90,7 @@ impl From<CollectionByIdRow> for Collection {
             value.id,
             value.title,
             value.filter_json.0,
+            value.entry_filter_json.map(|e| e.0),
             value.user_id,
             value.created_at,
             value.updated_at,
@@ -120,6 +123,7 @@ struct CollectionRow {
     id: Uuid,
     title: String,
     filter_json: Json<BookmarkFilter>,
+    entry_filter_json: Option<Json<EntryFilter>>,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
 }
@@ -130,6 +134,8 @@ impl From<CollectionRow> for CollectionDto {
             id: value.id,
             title: value.title,
             filter: value.filter_json.0,
+            entry_filter: value.entry_filter_json.map(|e| e.0),
+            entry_counts: None,
             created_at: value.created_at,
             updated_at: value.updated_at,
         }
@@ -2,16 +2,19 @@ use chrono::{DateTime, Utc};
 use colette_authentication::UserId;
 use colette_common::RepositoryError;
 use colette_crud::{
-    Entry, EntryBooleanField, EntryDateField, EntryFilter, EntryId, EntryRepository,
-    EntryTextField, ReadStatus,
+    Entry, EntryBooleanField, EntryDateField, EntryFilter, EntryId, EntryMarkManyParams,
+    EntryRepository, EntryTextField, ReadStatus, StarStatus,
 };
-use colette_handler::{EntryDto, EntryQueryParams, EntryQueryRepository};
-use sqlx::{PgPool, QueryBuilder};
+use colette_handler::{
+    EnclosureDto, EntryCounts, EntryDto, EntryQueryParams, EntryQueryRepository, SearchMatch,
+};
+use sqlx::{PgPool, Postgres, QueryBuilder, postgres::PgArguments, query::Query, types::Json};
+use url::Url;
 use uuid::Uuid;
 
-use crate::{DbUrl, ToColumn, ToSql};
+use crate::{DbUrl, ToColumn, ToSql, and_all, feed_entry::DbContentType, or_any};
 
-const BASE_QUERY: &str = include_str!("../queries/entries/find.sql");
+pub(crate) const BASE_QUERY: &str = include_str!("../queries/entries/find.sql");
 
 #[derive(Debug, Clone)]
 pub struct PostgresEntryRepository {
@@ -44,6 +47,8 @@ impl EntryRepository for PostgresEntryRepository {
     }
 
     async fn save(&self, data: &Entry) -> Result<(), RepositoryError> {
+        let mut tx = self.pool.begin().await?;
+
         match data.read_status() {
             ReadStatus::Unread => {
                 sqlx::query_file!(
@@ -51,7 +56,7 @@ impl EntryRepository for PostgresEntryRepository {
                     data.id().as_inner(),
                     data.user_id().as_inner()
                 )
-                .execute(&self.pool)
+                .execute(&mut *tx)
                 .await?;
             }
             ReadStatus::Read(read_at) => {
@@ -61,19 +66,114 @@ impl EntryRepository for PostgresEntryRepository {
                     data.user_id().as_inner(),
                     read_at
                 )
-                .execute(&self.pool)
+                .execute(&mut *tx)
+                .await?;
+            }
+        }
+
+        match data.star_status() {
+            StarStatus::Unstarred => {
+                sqlx::query_file!(
+                    "queries/starred_entries/delete_by_id.sql",
+                    data.id().as_inner(),
+                    data.user_id().as_inner()
+                )
+                .execute(&mut *tx)
+                .await?;
+            }
+            StarStatus::Starred(starred_at) => {
+                sqlx::query_file!(
+                    "queries/starred_entries/insert.sql",
+                    data.id().as_inner(),
+                    data.user_id().as_inner(),
+                    starred_at
+                )
+                .execute(&mut *tx)
                 .await?;
             }
         }
 
+        if let Some(note) = data.note() {
+            sqlx::query_file!(
+                "queries/entry_notes/upsert.sql",
+                data.id().as_inner(),
+                data.user_id().as_inner(),
+                note
+            )
+            .execute(&mut *tx)
+            .await?;
+        } else {
+            sqlx::query_file!(
+                "queries/entry_notes/delete_by_id.sql",
+                data.id().as_inner(),
+                data.user_id().as_inner()
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+
         Ok(())
     }
+
+    async fn mark_many_as_read(&self, params: EntryMarkManyParams) -> Result<u64, RepositoryError> {
+        let mut qb = QueryBuilder::new(format!(
+            r#"WITH results AS ({BASE_QUERY}) INSERT INTO read_statuses (feed_entry_id, user_id, created_at, updated_at) SELECT DISTINCT id, $1, now(), now() FROM results WHERE ($7::TIMESTAMPTZ IS NULL OR published_at < $7)"#
+        ));
+        if let Some(filter) = params.filter.clone() {
+            qb.push(format!(" AND ({})", filter.to_sql()));
+        }
+        qb.push(" ON CONFLICT (user_id, feed_entry_id) DO NOTHING");
+
+        let result = bind_mark_many(qb.build(), params)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    async fn mark_many_as_unread(
+        &self,
+        params: EntryMarkManyParams,
+    ) -> Result<u64, RepositoryError> {
+        let mut qb = QueryBuilder::new(format!(
+            r#"WITH results AS ({BASE_QUERY}) DELETE FROM read_statuses WHERE user_id = $1 AND feed_entry_id IN (SELECT id FROM results WHERE ($7::TIMESTAMPTZ IS NULL OR published_at < $7)"#
+        ));
+        if let Some(filter) = params.filter.clone() {
+            qb.push(format!(" AND ({})", filter.to_sql()));
+        }
+        qb.push(")");
+
+        let result = bind_mark_many(qb.build(), params)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+}
+
+/// Binds the parameters of the base query, followed by the `published_at` cutoff
+fn bind_mark_many(
+    query: Query<'_, Postgres, PgArguments>,
+    params: EntryMarkManyParams,
+) -> Query<'_, Postgres, PgArguments> {
+    query
+        .bind(params.user_id.as_inner())
+        .bind(Option::<Uuid>::None)
+        .bind(params.subscription_id)
+        .bind(Option::<bool>::None)
+        .bind(params.tags)
+        .bind(Option::<String>::None)
+        .bind(params.published_before)
 }
 
 #[derive(sqlx::FromRow)]
 struct EntryByIdRow {
     id: Uuid,
     read_at: Option<DateTime<Utc>>,
+    starred_at: Option<DateTime<Utc>>,
+    note: Option<String>,
     user_id: Uuid,
 }
 
@@ -86,6 +186,12 @@ impl From<EntryByIdRow> for Entry {
             } else {
                 ReadStatus::Unread
             },
+            if let Some(starred_at) = value.starred_at {
+                StarStatus::Starred(starred_at)
+            } else {
+                StarStatus::Unstarred
+            },
+            value.note,
             value.user_id,
         )
     }
@@ -93,20 +199,26 @@ impl From<EntryByIdRow> for Entry {
 
 impl EntryQueryRepository for PostgresEntryRepository {
     async fn query(&self, params: EntryQueryParams) -> Result<Vec<EntryDto>, RepositoryError> {
-        let (cursor_published_at, cursor_id) = if let Some((published_at, id)) = params.cursor {
-            (Some(published_at), Some(id))
-        } else {
-            (None, None)
-        };
+        let (cursor_rank, cursor_published_at, cursor_id) =
+            if let Some((rank, published_at, id)) = params.cursor {
+                (rank, Some(published_at), Some(id))
+            } else {
+                (None, None, None)
+            };
 
         let mut qb = QueryBuilder::new(format!(
             r#"WITH results AS ({BASE_QUERY}) SELECT * FROM results WHERE TRUE"#
         ));
 
         if let Some(filter) = params.filter {
-            qb.push(format!(" {}", filter.to_sql()));
+            qb.push(format!(" AND ({})", filter.to_sql()));
         }
 
+        qb.push(
+            r#" AND ($8::TIMESTAMPTZ IS NULL OR $9::UUID IS NULL OR (coalesce(rank, 0), published_at, id) < (coalesce($7::REAL, 0), $8, $9))"#,
+        );
+        qb.push(" ORDER BY rank DESC NULLS LAST, published_at DESC, id DESC LIMIT $10");
+
         let rows = qb
             .build_query_as::<EntryRow>()
             .bind(params.user_id)
@@ -114,6 +226,8 @@ impl EntryQueryRepository for PostgresEntryRepository {
             .bind(params.subscription_id)
             .bind(params.has_read)
             .bind(params.tags)
+            .bind(params.search)
+            .bind(cursor_rank)
             .bind(cursor_published_at)
             .bind(cursor_id)
             .bind(params.limit.map(|e| e as i64))
@@ -122,6 +236,29 @@ impl EntryQueryRepository for PostgresEntryRepository {
 
         Ok(rows.into_iter().map(Into::into).collect())
     }
+
+    async fn count(&self, params: EntryQueryParams) -> Result<EntryCounts, RepositoryError> {
+        let mut qb = QueryBuilder::new(format!(
+            r#"WITH results AS ({BASE_QUERY}) SELECT count(*) AS total, count(*) FILTER (WHERE NOT has_read) AS unread FROM results WHERE TRUE"#
+        ));
+
+        if let Some(filter) = params.filter {
+            qb.push(format!(" AND ({})", filter.to_sql()));
+        }
+
+        let (total, unread) = qb
+            .build_query_as::<(i64, i64)>()
+            .bind(params.user_id)
+            .bind(params.id)
+            .bind(params.subscription_id)
+            .bind(params.has_read)
+            .bind(params.tags)
+            .bind(params.search)
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok(EntryCounts { total, unread })
+    }
 }
 
 #[derive(sqlx::FromRow)]
@@ -131,10 +268,19 @@ struct EntryRow {
     title: String,
     published_at: DateTime<Utc>,
     description: Option<String>,
+    content: Option<String>,
+    content_type: Option<DbContentType>,
+    excerpt: Option<String>,
+    article_content: Option<String>,
     author: Option<String>,
     thumbnail_url: Option<DbUrl>,
+    enclosures: Json<Vec<EnclosureRow>>,
     read_at: Option<DateTime<Utc>>,
+    starred_at: Option<DateTime<Utc>>,
+    note: Option<String>,
     feed_id: Uuid,
+    rank: Option<f32>,
+    snippet: Option<String>,
 }
 
 impl From<EntryRow> for EntryDto {
@@ -145,14 +291,48 @@ impl From<EntryRow> for EntryDto {
             title: value.title,
             published_at: value.published_at,
             description: value.description,
+            content: value.content,
+            content_type: value.content_type.map(Into::into),
+            excerpt: value.excerpt,
+            article_content: value.article_content,
             thumbnail_url: value.thumbnail_url.map(Into::into),
             author: value.author,
+            enclosures: value.enclosures.0.into_iter().map(Into::into).collect(),
             read_status: if let Some(read_at) = value.read_at {
                 ReadStatus::Read(read_at)
             } else {
                 ReadStatus::Unread
             },
+            star_status: if let Some(starred_at) = value.starred_at {
+                StarStatus::Starred(starred_at)
+            } else {
+                StarStatus::Unstarred
+            },
+            note: value.note,
             feed_id: value.feed_id,
+            search_match: value.rank.map(|rank| SearchMatch {
+                rank,
+                snippet: value.snippet,
+            }),
+        }
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct EnclosureRow {
+    url: Url,
+    mime_type: Option<String>,
+    length: Option<i64>,
+    duration_sec: Option<i32>,
+}
+
+impl From<EnclosureRow> for EnclosureDto {
+    fn from(value: EnclosureRow) -> Self {
+        Self {
+            url: value.url,
+            mime_type: value.mime_type,
+            length: value.length.map(|e| e as u64),
+            duration_sec: value.duration_sec.map(|e| e as u32),
         }
     }
 }
@@ -165,6 +345,8 @@ impl ToColumn for EntryTextField {
             Self::Description => "description".into(),
             Self::Author => "author".into(),
             Self::Tag => "t.title".into(),
+            Self::SubscriptionTitle => "subscription_title".into(),
+            Self::FeedHost => "feed_host".into(),
         }
     }
 }
@@ -173,6 +355,8 @@ impl ToColumn for EntryBooleanField {
     fn to_column(self) -> String {
         match self {
             Self::HasRead => "has_read".into(),
+            Self::Starred => "starred".into(),
+            Self::HasNote => "(note IS NOT NULL)".into(),
         }
     }
 }
@@ -181,6 +365,7 @@ impl ToColumn for EntryDateField {
     fn to_column(self) -> String {
         match self {
             Self::PublishedAt => "published_at".into(),
+            Self::ReadAt => "read_at".into(),
         }
     }
 }
@@ -190,34 +375,16 @@ impl ToSql for EntryFilter {
         match self {
             EntryFilter::Text { field, op } => match field {
                 EntryTextField::Tag => format!(
-                    "EXISTS (SELECT 1 FROM subscriptions_tags st INNER JOIN tags t ON t.id = st.tag_id WHERE st.subscription_id = s.id AND {})",
+                    "EXISTS (SELECT 1 FROM tags t INNER JOIN subscriptions s ON s.user_id = t.user_id WHERE s.id = results.subscription_id AND (EXISTS (SELECT 1 FROM subscription_tags st WHERE st.subscription_id = s.id AND st.tag_id = t.id) OR EXISTS (SELECT 1 FROM entry_tags et WHERE et.feed_entry_id = results.id AND et.tag_id = t.id)) AND {})",
                     (field.to_column().as_str(), op).to_sql()
                 ),
                 _ => (field.to_column().as_str(), op).to_sql(),
             },
             EntryFilter::Boolean { field, op } => (field.to_column().as_str(), op).to_sql(),
             EntryFilter::Date { field, op } => (field.to_column().as_str(), op).to_sql(),
-            EntryFilter::And(filters) => {
-                let mut conditions = filters.into_iter().map(|e| e.to_sql()).collect::<Vec<_>>();
-                let mut and = conditions.swap_remove(0);
-
-                for condition in conditions {
-                    and = format!("{and} AND {condition}");
-                }
-
-                and
-            }
-            EntryFilter::Or(filters) => {
-                let mut conditions = filters.into_iter().map(|e| e.to_sql()).collect::<Vec<_>>();
-                let mut or = conditions.swap_remove(0);
-
-                for condition in conditions {
-                    or = format!("{or} OR {condition}");
-                }
-
-                or
-            }
-            EntryFilter::Not(filter) => format!("NOT {}", (*filter).to_sql()),
+            EntryFilter::And(filters) => and_all(filters.into_iter().map(|e| e.to_sql())),
+            EntryFilter::Or(filters) => or_any(filters.into_iter().map(|e| e.to_sql())),
+            EntryFilter::Not(filter) => format!("NOT ({})", (*filter).to_sql()),
         }
     }
 }
@@ -231,8 +398,90 @@ fn validate_base_query() {
         Option::<Uuid>::None,
         Option::<bool>::None,
         Option::<&[Uuid]>::None,
-        Option::<DateTime<Utc>>::None,
-        Option::<Uuid>::None,
-        1
+        Option::<&str>::None
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use colette_crud::{BooleanOp, DateOp, TextOp};
+
+    use super::*;
+
+    fn title_equals(value: &str) -> EntryFilter {
+        EntryFilter::Text {
+            field: EntryTextField::Title,
+            op: TextOp::Equals(value.into()),
+        }
+    }
+
+    #[test]
+    fn compiles_nested_filters() {
+        let filter = EntryFilter::And(vec![
+            EntryFilter::Or(vec![title_equals("a"), title_equals("b")]),
+            EntryFilter::Not(Box::new(EntryFilter::Boolean {
+                field: EntryBooleanField::HasRead,
+                op: BooleanOp::Equals(true),
+            })),
+        ]);
+
+        assert_eq!(
+            filter.to_sql(),
+            "((title = 'a') OR (title = 'b')) AND (NOT (has_read = true))"
+        );
+    }
+
+    #[test]
+    fn compiles_empty_groups() {
+        assert_eq!(EntryFilter::And(Vec::new()).to_sql(), "TRUE");
+        assert_eq!(EntryFilter::Or(Vec::new()).to_sql(), "FALSE");
+        assert_eq!(
+            EntryFilter::Not(Box::new(EntryFilter::Or(Vec::new()))).to_sql(),
+            "NOT (FALSE)"
+        );
+    }
+
+    #[test]
+    fn compiles_tag_filter_as_subquery() {
+        let sql = EntryFilter::Text {
+            field: EntryTextField::Tag,
+            op: TextOp::Equals("rust".into()),
+        }
+        .to_sql();
+
+        assert!(sql.starts_with("EXISTS (SELECT 1 FROM tags t"));
+        assert!(sql.ends_with("AND t.title = 'rust')"));
+    }
+
+    #[test]
+    fn compiles_subscription_title_and_feed_host_filters() {
+        let filter = EntryFilter::Or(vec![
+            EntryFilter::Text {
+                field: EntryTextField::SubscriptionTitle,
+                op: TextOp::Contains("news".into()),
+            },
+            EntryFilter::Text {
+                field: EntryTextField::FeedHost,
+                op: TextOp::EndsWith("example.com".into()),
+            },
+        ]);
+
+        assert_eq!(
+            filter.to_sql(),
+            "(subscription_title LIKE '%news%') OR (feed_host LIKE '%example.com')"
+        );
+    }
+
+    #[test]
+    fn compiles_read_at_filter() {
+        let filter = EntryFilter::Date {
+            field: EntryDateField::ReadAt,
+            op: DateOp::InLast(3600),
+        };
+
+        assert_eq!(
+            filter.to_sql(),
+            "read_at > now() - make_interval(secs => 3600)"
+        );
+    }
+}
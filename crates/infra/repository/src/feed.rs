@@ -1,7 +1,8 @@
 use chrono::{DateTime, Utc};
 use colette_common::RepositoryError;
 use colette_ingestion::{
-    Feed, FeedBatch, FeedFindOutdatedParams, FeedId, FeedRepository, FeedStatus,
+    Feed, FeedBatch, FeedFindOutdatedParams, FeedFindWebSubRenewableParams, FeedId, FeedRepository,
+    FeedStatus,
 };
 use sqlx::{
     Decode, Encode, PgPool, Postgres, Type,
@@ -70,6 +71,51 @@ impl FeedRepository for PostgresFeedRepository {
         Ok(feeds)
     }
 
+    async fn find_entry_history(
+        &self,
+        id: FeedId,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<(Url, DateTime<Utc>)>, RepositoryError> {
+        let history =
+            sqlx::query_file!("queries/feeds/find_entry_history.sql", id.as_inner(), since)
+                .map(|e| (e.link.into(), e.published_at))
+                .fetch_all(&self.pool)
+                .await?;
+
+        Ok(history)
+    }
+
+    async fn find_websub_renewable(
+        &self,
+        params: FeedFindWebSubRenewableParams,
+    ) -> Result<Vec<Feed>, RepositoryError> {
+        let feeds = sqlx::query_file_as!(
+            FeedRow,
+            "queries/feeds/find_websub_renewable.sql",
+            params.expires_before,
+            params.limit.map(|e| e as i64)
+        )
+        .map(Into::into)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(feeds)
+    }
+
+    async fn update_websub(&self, data: &Feed) -> Result<(), RepositoryError> {
+        sqlx::query_file!(
+            "queries/feeds/update_websub.sql",
+            data.id().as_inner(),
+            data.websub_secret(),
+            data.websub_lease_expires_at(),
+            data.updated_at()
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
     async fn upsert(&self, data: FeedBatch) -> Result<(), RepositoryError> {
         let mut fe_ids = Vec::<Uuid>::new();
         let mut fe_links = Vec::<DbUrl>::new();
@@ -80,8 +126,25 @@ impl FeedRepository for PostgresFeedRepository {
         let mut fe_thumbnail_urls = Vec::<Option<DbUrl>>::new();
         let mut fe_created_ats = Vec::<DateTime<Utc>>::new();
         let mut fe_updated_ats = Vec::<DateTime<Utc>>::new();
+        let mut fe_contents = Vec::<Option<String>>::new();
+        let mut fe_content_types = Vec::<Option<String>>::new();
+        let mut fe_excerpts = Vec::<Option<String>>::new();
+
+        let mut fee_feed_entry_links = Vec::<DbUrl>::new();
+        let mut fee_urls = Vec::<DbUrl>::new();
+        let mut fee_mime_types = Vec::<Option<String>>::new();
+        let mut fee_lengths = Vec::<Option<i64>>::new();
+        let mut fee_duration_secs = Vec::<Option<i32>>::new();
 
         for item in data.feed_entries {
+            for enclosure in item.enclosures() {
+                fee_feed_entry_links.push(DbUrl(item.link().to_owned()));
+                fee_urls.push(DbUrl(enclosure.url.clone()));
+                fee_mime_types.push(enclosure.mime_type.clone());
+                fee_lengths.push(enclosure.length.map(|e| e as i64));
+                fee_duration_secs.push(enclosure.duration_sec.map(|e| e as i32));
+            }
+
             fe_ids.push(item.id().as_inner());
             fe_links.push(DbUrl(item.link().to_owned()));
             fe_titles.push(item.title().to_owned());
@@ -91,8 +154,13 @@ impl FeedRepository for PostgresFeedRepository {
             fe_thumbnail_urls.push(item.thumbnail_url().map(ToOwned::to_owned).map(DbUrl));
             fe_created_ats.push(item.created_at());
             fe_updated_ats.push(item.updated_at());
+            fe_contents.push(item.content().map(ToOwned::to_owned));
+            fe_content_types.push(item.content_type().map(|e| e.to_string()));
+            fe_excerpts.push(item.excerpt().map(ToOwned::to_owned));
         }
 
+        let mut tx = self.pool.begin().await?;
+
         sqlx::query_file!(
             "queries/feeds/upsert.sql",
             data.feed.id().as_inner(),
@@ -102,7 +170,16 @@ impl FeedRepository for PostgresFeedRepository {
             data.feed.description(),
             data.feed.is_custom(),
             DbFeedStatus(data.feed.status().to_owned()) as DbFeedStatus,
+            data.feed.refresh_interval_min() as i32,
             data.feed.last_refreshed_at(),
+            data.feed.etag(),
+            data.feed.last_modified(),
+            data.feed.failure_count() as i32,
+            data.feed.last_error(),
+            data.feed.hub_url().cloned().map(DbUrl) as Option<DbUrl>,
+            data.feed.topic_url().cloned().map(DbUrl) as Option<DbUrl>,
+            data.feed.websub_secret(),
+            data.feed.websub_lease_expires_at(),
             data.feed.created_at(),
             data.feed.updated_at(),
             &fe_ids,
@@ -114,20 +191,36 @@ impl FeedRepository for PostgresFeedRepository {
             &fe_thumbnail_urls as &[Option<DbUrl>],
             &fe_created_ats,
             &fe_updated_ats,
+            &fe_contents as &[Option<String>],
+            &fe_content_types as &[Option<String>],
+            &fe_excerpts as &[Option<String>],
         )
-        .execute(&self.pool)
+        .execute(&mut *tx)
         .await?;
 
-        Ok(())
-    }
+        if !fe_links.is_empty() {
+            sqlx::query_file!(
+                "queries/feed_entry_enclosures/delete_by_feed_entry_links.sql",
+                data.feed.id().as_inner(),
+                &fe_links as &[DbUrl]
+            )
+            .execute(&mut *tx)
+            .await?;
 
-    async fn mark_as_failed(&self, source_url: Url) -> Result<(), RepositoryError> {
-        sqlx::query_file!(
-            "queries/feeds/mark_as_failed.sql",
-            DbUrl(source_url) as DbUrl
-        )
-        .execute(&self.pool)
-        .await?;
+            sqlx::query_file!(
+                "queries/feed_entry_enclosures/insert_many.sql",
+                data.feed.id().as_inner(),
+                &fee_feed_entry_links as &[DbUrl],
+                &fee_urls as &[DbUrl],
+                &fee_mime_types as &[Option<String>],
+                &fee_lengths as &[Option<i64>],
+                &fee_duration_secs as &[Option<i32>]
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
 
         Ok(())
     }
@@ -143,6 +236,14 @@ pub(crate) struct FeedRow {
     status: DbFeedStatus,
     refresh_interval_min: i32,
     last_refreshed_at: Option<DateTime<Utc>>,
+    etag: Option<String>,
+    last_modified: Option<String>,
+    failure_count: i32,
+    last_error: Option<String>,
+    hub_url: Option<DbUrl>,
+    topic_url: Option<DbUrl>,
+    websub_secret: Option<String>,
+    websub_lease_expires_at: Option<DateTime<Utc>>,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
 }
@@ -159,6 +260,14 @@ impl From<FeedRow> for Feed {
             value.status.0,
             value.refresh_interval_min as u32,
             value.last_refreshed_at,
+            value.etag,
+            value.last_modified,
+            value.failure_count as u32,
+            value.last_error,
+            value.hub_url.map(|e| e.0),
+            value.topic_url.map(|e| e.0),
+            value.websub_secret,
+            value.websub_lease_expires_at,
             value.created_at,
             value.updated_at,
         )
@@ -0,0 +1,141 @@
+use chrono::{DateTime, Utc};
+use colette_common::RepositoryError;
+use colette_ingestion::{
+    ContentType, FeedEntry, FeedEntryFindArticlePendingParams, FeedEntryId, FeedEntryRepository,
+};
+use sqlx::{
+    Decode, Encode, PgPool, Postgres, Type,
+    encode::IsNull,
+    error::BoxDynError,
+    postgres::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef},
+};
+use uuid::Uuid;
+
+use crate::DbUrl;
+
+#[derive(Debug, Clone)]
+pub struct PostgresFeedEntryRepository {
+    pool: PgPool,
+}
+
+impl PostgresFeedEntryRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl FeedEntryRepository for PostgresFeedEntryRepository {
+    async fn find_by_id(&self, id: FeedEntryId) -> Result<Option<FeedEntry>, RepositoryError> {
+        let feed_entry = sqlx::query_file_as!(
+            FeedEntryRow,
+            "queries/feed_entries/find_by_id.sql",
+            id.as_inner()
+        )
+        .map(Into::into)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(feed_entry)
+    }
+
+    async fn find_article_pending(
+        &self,
+        params: FeedEntryFindArticlePendingParams,
+    ) -> Result<Vec<FeedEntry>, RepositoryError> {
+        let feed_entries = sqlx::query_file_as!(
+            FeedEntryRow,
+            "queries/feed_entries/find_article_pending.sql",
+            params.published_after,
+            params.limit.map(|e| e as i64)
+        )
+        .map(Into::into)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(feed_entries)
+    }
+
+    async fn save(&self, data: &FeedEntry) -> Result<(), RepositoryError> {
+        sqlx::query_file!(
+            "queries/feed_entries/update.sql",
+            data.id().as_inner(),
+            data.article_content(),
+            data.article_fetched_at(),
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+}
+
+struct FeedEntryRow {
+    id: Uuid,
+    link: DbUrl,
+    title: String,
+    published_at: DateTime<Utc>,
+    description: Option<String>,
+    content: Option<String>,
+    content_type: Option<DbContentType>,
+    excerpt: Option<String>,
+    author: Option<String>,
+    thumbnail_url: Option<DbUrl>,
+    article_content: Option<String>,
+    article_fetched_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<FeedEntryRow> for FeedEntry {
+    fn from(value: FeedEntryRow) -> Self {
+        Self::from_unchecked(
+            value.id,
+            value.link.into(),
+            value.title,
+            value.published_at,
+            value.description,
+            value.content,
+            value.content_type.map(Into::into),
+            value.excerpt,
+            value.author,
+            value.thumbnail_url.map(Into::into),
+            value.article_content,
+            value.article_fetched_at,
+            value.created_at,
+            value.updated_at,
+        )
+    }
+}
+
+pub(crate) struct DbContentType(ContentType);
+
+impl From<DbContentType> for ContentType {
+    fn from(value: DbContentType) -> Self {
+        value.0
+    }
+}
+
+impl Type<Postgres> for DbContentType {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("text")
+    }
+}
+
+impl Encode<'_, Postgres> for DbContentType {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        buf.extend_from_slice(self.0.to_string().as_bytes());
+
+        Ok(IsNull::No)
+    }
+}
+
+impl Decode<'_, Postgres> for DbContentType {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        match value.format() {
+            PgValueFormat::Binary => str::from_utf8(value.as_bytes()?)?.parse(),
+            PgValueFormat::Text => value.as_str()?.parse(),
+        }
+        .map(DbContentType)
+        .map_err(Into::into)
+    }
+}
@@ -1,10 +1,13 @@
 pub use backup::PostgresBackupRepository;
 pub use bookmark::PostgresBookmarkRepository;
+use chrono::{DateTime, Utc};
 use colette_crud::{BooleanOp, DateOp, NumberOp, TextOp};
 pub use collection::PostgresCollectionRepository;
 pub use entry::PostgresEntryRepository;
 pub use feed::PostgresFeedRepository;
+pub use feed_entry::PostgresFeedEntryRepository;
 pub use pat::PostgresPatRepository;
+pub use rule::PostgresRuleRepository;
 use sqlx::{
     Decode, Encode, Postgres, Type,
     encode::IsNull,
@@ -21,7 +24,9 @@ mod bookmark;
 mod collection;
 mod entry;
 mod feed;
+mod feed_entry;
 mod pat;
+mod rule;
 mod subscription;
 mod tag;
 mod user;
@@ -85,10 +90,25 @@ impl ToSql for (&str, TextOp) {
         let (column, op) = self;
 
         match op {
-            TextOp::Equals(value) => format!("{column} = {value}"),
-            TextOp::Contains(value) => format!("{column} LIKE %{value}%"),
-            TextOp::StartsWith(value) => format!("{column} LIKE {value}%"),
-            TextOp::EndsWith(value) => format!("{column} LIKE %{value}"),
+            TextOp::Equals(value) => format!("{column} = {}", quote(&value)),
+            TextOp::Contains(value) => {
+                format!(
+                    "{column} LIKE {}",
+                    quote(&format!("%{}%", escape_like(&value)))
+                )
+            }
+            TextOp::StartsWith(value) => {
+                format!(
+                    "{column} LIKE {}",
+                    quote(&format!("{}%", escape_like(&value)))
+                )
+            }
+            TextOp::EndsWith(value) => {
+                format!(
+                    "{column} LIKE {}",
+                    quote(&format!("%{}", escape_like(&value)))
+                )
+            }
         }
     }
 }
@@ -123,14 +143,129 @@ impl ToSql for (&str, DateOp) {
         let (column, op) = self;
 
         match op {
-            DateOp::Before(value) => format!("{column} < {value}"),
-            DateOp::After(value) => format!("{column} > {value}"),
+            DateOp::Before(value) => format!("{column} < {}", quote_timestamp(value)),
+            DateOp::After(value) => format!("{column} > {}", quote_timestamp(value)),
             DateOp::Between(value) => {
-                format!("{} BETWEEN {} AND {}", column, value.start, value.end)
+                format!(
+                    "{} BETWEEN {} AND {}",
+                    column,
+                    quote_timestamp(value.start),
+                    quote_timestamp(value.end)
+                )
             }
             DateOp::InLast(value) => {
-                format!("(extract(epoch FROM now()) - {column}) < {value}")
+                format!("{column} > now() - make_interval(secs => {value})")
             }
         }
     }
 }
+
+/// Joins conditions with `AND`. An empty list matches everything.
+pub(crate) fn and_all<I: IntoIterator<Item = String>>(conditions: I) -> String {
+    conditions
+        .into_iter()
+        .reduce(|and, condition| format!("({and}) AND ({condition})"))
+        .unwrap_or_else(|| "TRUE".into())
+}
+
+/// Joins conditions with `OR`. An empty list matches nothing.
+pub(crate) fn or_any<I: IntoIterator<Item = String>>(conditions: I) -> String {
+    conditions
+        .into_iter()
+        .reduce(|or, condition| format!("({or}) OR ({condition})"))
+        .unwrap_or_else(|| "FALSE".into())
+}
+
+/// Quotes a string as a Postgres literal, since the compiled filters are inlined into the query
+/// rather than bound.
+fn quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+fn quote_timestamp(value: DateTime<Utc>) -> String {
+    format!("{}::TIMESTAMPTZ", quote(&value.to_rfc3339()))
+}
+
+fn escape_like(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
+
+#[cfg(test)]
+mod tests {
+    use colette_crud::{BooleanOp, DateOp, TextOp};
+
+    use super::*;
+
+    #[test]
+    fn text_op_quotes_values() {
+        assert_eq!(
+            ("title", TextOp::Equals("it's".into())).to_sql(),
+            "title = 'it''s'"
+        );
+    }
+
+    #[test]
+    fn text_op_escapes_like_patterns() {
+        assert_eq!(
+            ("title", TextOp::Contains("50%_off\\".into())).to_sql(),
+            r"title LIKE '%50\%\_off\\%'"
+        );
+        assert_eq!(
+            ("title", TextOp::StartsWith("a".into())).to_sql(),
+            "title LIKE 'a%'"
+        );
+        assert_eq!(
+            ("title", TextOp::EndsWith("a".into())).to_sql(),
+            "title LIKE '%a'"
+        );
+    }
+
+    #[test]
+    fn boolean_op_compiles() {
+        assert_eq!(
+            ("has_read", BooleanOp::Equals(false)).to_sql(),
+            "has_read = false"
+        );
+    }
+
+    #[test]
+    fn date_op_compiles() {
+        let start = DateTime::parse_from_rfc3339("2025-01-01T00:00:00Z")
+            .unwrap()
+            .to_utc();
+        let end = DateTime::parse_from_rfc3339("2025-02-01T00:00:00Z")
+            .unwrap()
+            .to_utc();
+
+        assert_eq!(
+            ("published_at", DateOp::Before(start)).to_sql(),
+            "published_at < '2025-01-01T00:00:00+00:00'::TIMESTAMPTZ"
+        );
+        assert_eq!(
+            ("published_at", DateOp::Between(start..end)).to_sql(),
+            "published_at BETWEEN '2025-01-01T00:00:00+00:00'::TIMESTAMPTZ AND '2025-02-01T00:00:00+00:00'::TIMESTAMPTZ"
+        );
+        assert_eq!(
+            ("published_at", DateOp::InLast(3600)).to_sql(),
+            "published_at > now() - make_interval(secs => 3600)"
+        );
+    }
+
+    #[test]
+    fn empty_conditions_compile_to_identities() {
+        assert_eq!(and_all(Vec::new()), "TRUE");
+        assert_eq!(or_any(Vec::new()), "FALSE");
+    }
+
+    #[test]
+    fn conditions_are_parenthesized() {
+        assert_eq!(
+            and_all(["a".to_owned(), "b".to_owned(), "c".to_owned()]),
+            "((a) AND (b)) AND (c)"
+        );
+        assert_eq!(or_any(["a".to_owned()]), "a");
+    }
+}
@@ -0,0 +1,251 @@
+use chrono::{DateTime, Utc};
+use colette_authentication::UserId;
+use colette_common::RepositoryError;
+use colette_crud::{EntryFilter, Rule, RuleAction, RuleApplyParams, RuleId, RuleRepository};
+use colette_handler::{RuleDto, RuleQueryParams, RuleQueryRepository};
+use sqlx::{PgPool, QueryBuilder, types::Json};
+use uuid::Uuid;
+
+use crate::{ToSql, entry::BASE_QUERY};
+
+#[derive(Debug, Clone)]
+pub struct PostgresRuleRepository {
+    pool: PgPool,
+}
+
+impl PostgresRuleRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl RuleRepository for PostgresRuleRepository {
+    async fn find_by_id(
+        &self,
+        id: RuleId,
+        user_id: UserId,
+    ) -> Result<Option<Rule>, RepositoryError> {
+        let rule = sqlx::query_file_as!(
+            RuleByIdRow,
+            "queries/rules/find_by_id.sql",
+            id.as_inner(),
+            user_id.as_inner()
+        )
+        .map(Into::into)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(rule)
+    }
+
+    async fn find_enabled_by_feed(&self, feed_id: Uuid) -> Result<Vec<Rule>, RepositoryError> {
+        let rules = sqlx::query_file_as!(
+            RuleByIdRow,
+            "queries/rules/find_enabled_by_feed.sql",
+            feed_id
+        )
+        .map(Into::into)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rules)
+    }
+
+    async fn save(&self, data: &Rule) -> Result<(), RepositoryError> {
+        sqlx::query_file!(
+            "queries/rules/upsert.sql",
+            data.id().as_inner(),
+            data.title().as_inner(),
+            Json(data.condition().as_inner().to_owned()) as Json<EntryFilter>,
+            Json(data.actions().as_inner().to_vec()) as Json<Vec<RuleAction>>,
+            data.enabled(),
+            data.user_id().as_inner(),
+            data.created_at(),
+            data.updated_at()
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::Database(e) if e.is_unique_violation() => RepositoryError::Duplicate,
+            _ => RepositoryError::Unknown(e),
+        })?;
+
+        Ok(())
+    }
+
+    async fn delete_by_id(&self, id: RuleId, user_id: UserId) -> Result<(), RepositoryError> {
+        sqlx::query_file!(
+            "queries/rules/delete_by_id.sql",
+            id.as_inner(),
+            user_id.as_inner()
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => RepositoryError::NotFound,
+            _ => RepositoryError::Unknown(e),
+        })?;
+
+        Ok(())
+    }
+
+    async fn apply(&self, data: &Rule, params: RuleApplyParams) -> Result<u64, RepositoryError> {
+        let mut qb = QueryBuilder::new(format!(
+            r#"WITH results AS ({BASE_QUERY}) SELECT id FROM results WHERE ($7::UUID[] IS NULL OR id = ANY ($7))"#
+        ));
+        qb.push(format!(
+            " AND ({})",
+            data.condition().as_inner().to_owned().to_sql()
+        ));
+
+        let user_id = data.user_id().as_inner();
+
+        let mut tx = self.pool.begin().await?;
+
+        let feed_entry_ids = qb
+            .build_query_scalar::<Uuid>()
+            .bind(user_id)
+            .bind(Option::<Uuid>::None)
+            .bind(Option::<Uuid>::None)
+            .bind(Option::<bool>::None)
+            .bind(Option::<Vec<Uuid>>::None)
+            .bind(Option::<String>::None)
+            .bind(params.feed_entry_ids)
+            .fetch_all(&mut *tx)
+            .await?;
+        if feed_entry_ids.is_empty() {
+            return Ok(0);
+        }
+
+        for action in data.actions().as_inner() {
+            match action {
+                RuleAction::MarkAsRead => {
+                    sqlx::query_file!(
+                        "queries/read_statuses/insert_many.sql",
+                        &feed_entry_ids,
+                        user_id
+                    )
+                    .execute(&mut *tx)
+                    .await?;
+                }
+                RuleAction::Star => {
+                    sqlx::query_file!(
+                        "queries/starred_entries/insert_many.sql",
+                        &feed_entry_ids,
+                        user_id
+                    )
+                    .execute(&mut *tx)
+                    .await?;
+                }
+                RuleAction::AddTags(tag_ids) => {
+                    sqlx::query_file!(
+                        "queries/entry_tags/insert_many.sql",
+                        &feed_entry_ids,
+                        tag_ids,
+                        user_id
+                    )
+                    .execute(&mut *tx)
+                    .await?;
+                }
+                RuleAction::Hide => {
+                    sqlx::query_file!(
+                        "queries/hidden_entries/insert_many.sql",
+                        &feed_entry_ids,
+                        user_id
+                    )
+                    .execute(&mut *tx)
+                    .await?;
+                }
+            }
+        }
+
+        tx.commit().await?;
+
+        Ok(feed_entry_ids.len() as u64)
+    }
+
+    async fn find_pending_entries(&self, feed_id: Uuid) -> Result<Vec<Uuid>, RepositoryError> {
+        let feed_entry_ids =
+            sqlx::query_file_scalar!("queries/rules/find_pending_entries.sql", feed_id)
+                .fetch_all(&self.pool)
+                .await?;
+
+        Ok(feed_entry_ids)
+    }
+
+    async fn mark_entries_applied(&self, feed_entry_ids: Vec<Uuid>) -> Result<(), RepositoryError> {
+        sqlx::query_file!("queries/rules/mark_entries_applied.sql", &feed_entry_ids)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+}
+
+struct RuleByIdRow {
+    id: Uuid,
+    title: String,
+    condition_json: Json<EntryFilter>,
+    actions_json: Json<Vec<RuleAction>>,
+    enabled: bool,
+    user_id: Uuid,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<RuleByIdRow> for Rule {
+    fn from(value: RuleByIdRow) -> Self {
+        Self::from_unchecked(
+            value.id,
+            value.title,
+            value.condition_json.0,
+            value.actions_json.0,
+            value.enabled,
+            value.user_id,
+            value.created_at,
+            value.updated_at,
+        )
+    }
+}
+
+impl RuleQueryRepository for PostgresRuleRepository {
+    async fn query(&self, params: RuleQueryParams) -> Result<Vec<RuleDto>, RepositoryError> {
+        let rules = sqlx::query_file_as!(
+            RuleRow,
+            "queries/rules/find.sql",
+            params.user_id,
+            params.id,
+            params.cursor,
+            params.limit.map(|e| e as i64)
+        )
+        .map(Into::into)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rules)
+    }
+}
+
+struct RuleRow {
+    id: Uuid,
+    title: String,
+    condition_json: Json<EntryFilter>,
+    actions_json: Json<Vec<RuleAction>>,
+    enabled: bool,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<RuleRow> for RuleDto {
+    fn from(value: RuleRow) -> Self {
+        Self {
+            id: value.id,
+            title: value.title,
+            condition: value.condition_json.0,
+            actions: value.actions_json.0,
+            enabled: value.enabled,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
@@ -4,11 +4,13 @@ use colette_common::RepositoryError;
 use colette_crud::{
     ImportSubscriptionsParams, Subscription, SubscriptionId, SubscriptionRepository,
 };
-use colette_handler::{SubscriptionDto, SubscriptionQueryParams, SubscriptionQueryRepository};
+use colette_handler::{
+    EntryCounts, SubscriptionDto, SubscriptionQueryParams, SubscriptionQueryRepository,
+};
 use sqlx::{PgPool, types::Json};
 use uuid::Uuid;
 
-use crate::{DbUrl, tag::TagRow};
+use crate::{DbUrl, feed::DbFeedStatus, tag::TagRow};
 
 #[derive(Debug, Clone)]
 pub struct PostgresSubscriptionRepository {
@@ -47,6 +49,7 @@ impl SubscriptionRepository for PostgresSubscriptionRepository {
             data.title().as_inner(),
             data.description().map(|e| e.as_inner()),
             data.feed_id().as_inner(),
+            data.fetch_full_article(),
             &data.tags().iter().map(|e| e.as_inner()).collect::<Vec<_>>(),
             data.user_id().as_inner(),
             data.created_at(),
@@ -132,6 +135,7 @@ struct SubscriptionByIdRow {
     title: String,
     description: Option<String>,
     feed_id: Uuid,
+    fetch_full_article: bool,
     tags: Vec<Uuid>,
     user_id: Uuid,
     created_at: DateTime<Utc>,
@@ -145,6 +149,7 @@ impl From<SubscriptionByIdRow> for Subscription {
             value.title,
             value.description,
             value.feed_id,
+            value.fetch_full_article,
             value.tags,
             value.user_id,
             value.created_at,
@@ -172,7 +177,8 @@ impl SubscriptionQueryRepository for PostgresSubscriptionRepository {
             params.tags.as_deref(),
             cursor_title,
             cursor_id,
-            params.limit.map(|e| e as i64)
+            params.limit.map(|e| e as i64),
+            params.with_counts
         )
         .map(Into::into)
         .fetch_all(&self.pool)
@@ -189,7 +195,12 @@ struct SubscriptionRow {
     title: String,
     description: Option<String>,
     feed_id: Uuid,
+    fetch_full_article: bool,
+    feed_status: DbFeedStatus,
+    feed_failure_count: i32,
+    feed_last_error: Option<String>,
     tags: Json<Vec<TagRow>>,
+    total_count: Option<i64>,
     unread_count: i64,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
@@ -204,8 +215,16 @@ impl From<SubscriptionRow> for SubscriptionDto {
             title: value.title,
             description: value.description,
             feed_id: value.feed_id,
+            fetch_full_article: value.fetch_full_article,
+            feed_status: value.feed_status.into(),
+            feed_failure_count: value.feed_failure_count as u32,
+            feed_last_error: value.feed_last_error,
             tags: value.tags.0.into_iter().map(Into::into).collect(),
             unread_count: value.unread_count,
+            entry_counts: value.total_count.map(|total| EntryCounts {
+                total,
+                unread: value.unread_count,
+            }),
             created_at: value.created_at,
             updated_at: value.updated_at,
         }
@@ -2,7 +2,7 @@ use chrono::{DateTime, Utc};
 use colette_authentication::UserId;
 use colette_common::RepositoryError;
 use colette_crud::{Tag, TagId, TagRepository};
-use colette_handler::{TagDto, TagQueryParams, TagQueryRepository};
+use colette_handler::{EntryCounts, TagDto, TagQueryParams, TagQueryRepository};
 use sqlx::PgPool;
 use uuid::Uuid;
 
@@ -97,7 +97,8 @@ impl TagQueryRepository for PostgresTagRepository {
             params.user_id,
             params.id,
             params.cursor,
-            params.limit.map(|e| e as i64)
+            params.limit.map(|e| e as i64),
+            params.with_counts
         )
         .map(Into::into)
         .fetch_all(&self.pool)
@@ -111,6 +112,10 @@ impl TagQueryRepository for PostgresTagRepository {
 pub(crate) struct TagRow {
     id: Uuid,
     title: String,
+    #[serde(default)]
+    total_count: Option<i64>,
+    #[serde(default)]
+    unread_count: Option<i64>,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
 }
@@ -120,6 +125,10 @@ impl From<TagRow> for TagDto {
         Self {
             id: value.id,
             title: value.title,
+            entry_counts: value
+                .total_count
+                .zip(value.unread_count)
+                .map(|(total, unread)| EntryCounts { total, unread }),
             created_at: value.created_at,
             updated_at: value.updated_at,
         }
@@ -13,6 +13,7 @@ use crate::{basic::Basic, open_graph::OpenGraph, util::Value};
 
 pub mod basic;
 pub mod open_graph;
+pub mod readability;
 pub mod rss;
 pub mod schema_org;
 pub mod util;
@@ -59,7 +60,11 @@ pub fn parse_metadata<R: Read>(reader: R) -> Result<Metadata, Error> {
                         handle_open_graph(open_graph, property, content);
                     }
                 } else if let Some(r#type) = tag.attributes.remove("type".as_bytes()) {
-                    if tag.name.as_slice() == b"link" && r#type.as_slice() == b"application/rss+xml"
+                    if tag.name.as_slice() == b"link"
+                        && matches!(
+                            r#type.as_slice(),
+                            b"application/rss+xml" | b"application/feed+json"
+                        )
                     {
                         if let (Some(title), Some(href)) = (
                             tag.attributes.remove("title".as_bytes()),
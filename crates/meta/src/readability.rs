@@ -0,0 +1,216 @@
+use std::collections::HashMap;
+
+use scraper::{ElementRef, Html, Node, Selector};
+
+/// Minimum number of characters for a paragraph to contribute to the score of its ancestors
+const MIN_PARAGRAPH_LENGTH: usize = 25;
+
+/// Minimum number of characters of text in an extracted article
+const MIN_ARTICLE_LENGTH: usize = 250;
+
+/// Class and ID fragments of elements that are unlikely to be part of the article
+const UNLIKELY_CANDIDATES: &[&str] = &[
+    "banner",
+    "breadcrumb",
+    "combx",
+    "comment",
+    "community",
+    "cookie",
+    "disqus",
+    "footer",
+    "header",
+    "menu",
+    "modal",
+    "nav",
+    "popup",
+    "related",
+    "remark",
+    "share",
+    "shoutbox",
+    "sidebar",
+    "social",
+    "sponsor",
+    "subscribe",
+];
+
+/// Class and ID fragments that outweigh an unlikely match
+const MAYBE_CANDIDATES: &[&str] = &["article", "body", "column", "content", "main"];
+
+const POSITIVE_WEIGHTS: &[&str] = &[
+    "article", "blog", "body", "content", "entry", "main", "page", "post", "story", "text",
+];
+
+const NEGATIVE_WEIGHTS: &[&str] = &[
+    "ad-", "comment", "footer", "masthead", "media", "meta", "outbrain", "promo", "related",
+    "scroll", "share", "shopping", "sidebar", "sponsor", "tags", "widget",
+];
+
+/// Tags whose content is never part of the article
+const SKIPPED_TAGS: &[&str] = &[
+    "aside", "button", "footer", "form", "header", "iframe", "nav", "noscript", "script", "select",
+    "style", "svg",
+];
+
+/// Extracts the main article of a webpage as an HTML fragment, using a simplified version of the
+/// Readability scoring algorithm. The result is not sanitized.
+pub fn extract_article(html: &str) -> Option<String> {
+    let document = Html::parse_document(html);
+    let paragraph_selector = Selector::parse("p, pre, td").unwrap();
+
+    let mut scores = HashMap::new();
+
+    for paragraph in document.select(&paragraph_selector) {
+        if paragraph
+            .ancestors()
+            .filter_map(ElementRef::wrap)
+            .any(is_unlikely)
+        {
+            continue;
+        }
+
+        let text = inner_text(paragraph);
+        if text.chars().count() < MIN_PARAGRAPH_LENGTH {
+            continue;
+        }
+
+        let score = 1.0 + text.matches(',').count() as f64 + (text.len() / 100).min(3) as f64;
+
+        let ancestors = paragraph.ancestors().filter_map(ElementRef::wrap).take(2);
+        for (level, ancestor) in ancestors.enumerate() {
+            let entry = scores
+                .entry(ancestor.id())
+                .or_insert_with(|| (ancestor, initial_score(ancestor)));
+            entry.1 += score / (level + 1) as f64;
+        }
+    }
+
+    let (top, top_score) = scores
+        .into_values()
+        .map(|(e, score)| (e, score * (1.0 - link_density(e))))
+        .max_by(|a, b| a.1.total_cmp(&b.1))?;
+
+    let mut article = String::new();
+    let threshold = (top_score * 0.2).max(10.0);
+
+    let siblings = top
+        .parent()
+        .map(|e| {
+            e.children()
+                .filter_map(ElementRef::wrap)
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_else(|| vec![top]);
+    for sibling in siblings {
+        let include = if sibling.id() == top.id() {
+            true
+        } else if is_unlikely(sibling) {
+            false
+        } else if sibling.value().name() == "p" {
+            let text = inner_text(sibling);
+            let length = text.chars().count();
+            let density = link_density(sibling);
+
+            (length > 80 && density < 0.25)
+                || (length > 0 && density == 0.0 && text.trim_end().ends_with('.'))
+        } else {
+            initial_score(sibling) * (1.0 - link_density(sibling)) >= threshold
+        };
+
+        if include {
+            article.push_str(&sibling.html());
+        }
+    }
+
+    let length = inner_text(Html::parse_fragment(&article).root_element())
+        .chars()
+        .count();
+
+    (length >= MIN_ARTICLE_LENGTH).then_some(article)
+}
+
+fn initial_score(e: ElementRef) -> f64 {
+    let score = match e.value().name() {
+        "article" => 10.0,
+        "div" => 5.0,
+        "blockquote" | "pre" | "td" => 3.0,
+        "address" | "dd" | "dl" | "dt" | "form" | "li" | "ol" | "ul" => -3.0,
+        "h1" | "h2" | "h3" | "h4" | "h5" | "h6" | "th" => -5.0,
+        _ => 0.0,
+    };
+
+    score + class_weight(e)
+}
+
+fn class_weight(e: ElementRef) -> f64 {
+    let mut weight = 0.0;
+
+    for value in [e.value().attr("class"), e.value().attr("id")]
+        .into_iter()
+        .flatten()
+    {
+        let value = value.to_lowercase();
+
+        if NEGATIVE_WEIGHTS.iter().any(|e| value.contains(e)) {
+            weight -= 25.0;
+        }
+        if POSITIVE_WEIGHTS.iter().any(|e| value.contains(e)) {
+            weight += 25.0;
+        }
+    }
+
+    weight
+}
+
+fn is_unlikely(e: ElementRef) -> bool {
+    let name = e.value().name();
+    if SKIPPED_TAGS.contains(&name) {
+        return true;
+    }
+    if matches!(name, "article" | "body" | "main") {
+        return false;
+    }
+
+    let value = [e.value().attr("class"), e.value().attr("id")]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase();
+
+    UNLIKELY_CANDIDATES.iter().any(|e| value.contains(e))
+        && !MAYBE_CANDIDATES.iter().any(|e| value.contains(e))
+}
+
+fn link_density(e: ElementRef) -> f64 {
+    let length = inner_text(e).chars().count();
+    if length == 0 {
+        return 0.0;
+    }
+
+    let link_length = e
+        .descendants()
+        .filter_map(ElementRef::wrap)
+        .filter(|e| e.value().name() == "a")
+        .map(|e| inner_text(e).chars().count())
+        .sum::<usize>();
+
+    link_length as f64 / length as f64
+}
+
+fn inner_text(e: ElementRef) -> String {
+    let mut text = String::new();
+
+    for node in e.descendants() {
+        if let Node::Text(value) = node.value() {
+            let skipped = node
+                .ancestors()
+                .filter_map(ElementRef::wrap)
+                .any(|e| matches!(e.value().name(), "script" | "style" | "noscript"));
+            if !skipped {
+                text.push_str(value);
+            }
+        }
+    }
+
+    text
+}
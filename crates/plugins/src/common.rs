@@ -1,11 +1,14 @@
 use core::str;
 
 use bytes::Bytes;
+use colette_http::HttpClient;
 use colette_scraper::{
     ExtractorQuery, TextSelector,
     bookmark::{BookmarkError, ExtractedBookmark},
-    feed::{ExtractedFeed, ExtractedFeedEntry, FeedError},
+    charset::to_utf8,
+    feed::{ExtractedFeed, ExtractedFeedEntry, FeedError, ProcessedFeed},
 };
+use http::{Response, header::CONTENT_TYPE, request::Builder};
 use scraper::{Html, Selector};
 
 #[derive(Debug, Clone, Default)]
@@ -34,6 +37,7 @@ impl FeedExtractor {
     }
 
     pub fn extract(&self, body: Bytes) -> Result<ExtractedFeed, FeedError> {
+        let body = to_utf8(body, None);
         let html = Html::parse_document(str::from_utf8(&body)?);
 
         let entries = self
@@ -51,6 +55,7 @@ impl FeedExtractor {
                             .select_text(&self.options.feed_entry_description_queries),
                         author: element.select_text(&self.options.feed_entry_author_queries),
                         thumbnail: element.select_text(&self.options.feed_entry_thumbnail_queries),
+                        ..Default::default()
                     })
                     .collect::<Vec<_>>();
 
@@ -68,6 +73,7 @@ impl FeedExtractor {
             description: html.select_text(&self.options.feed_description_queries),
             refreshed: html.select_text(&self.options.feed_refreshed_queries),
             entries,
+            ..Default::default()
         };
 
         Ok(feed)
@@ -93,6 +99,7 @@ impl BookmarkExtractor {
     }
 
     pub fn extract(&self, body: Bytes) -> Result<ExtractedBookmark, BookmarkError> {
+        let body = to_utf8(body, None);
         let html = Html::parse_document(str::from_utf8(&body)?);
 
         let bookmark = ExtractedBookmark {
@@ -105,3 +112,33 @@ impl BookmarkExtractor {
         Ok(bookmark)
     }
 }
+
+/// Sends a request through the shared HTTP client, so that plugins are throttled and cached like
+/// any other scrape. Unsuccessful responses are returned as errors.
+pub async fn fetch<HC: HttpClient>(
+    client: &HC,
+    request: Builder,
+) -> Result<Response<Bytes>, colette_http::Error> {
+    let resp = client.execute(request.body(Bytes::new())?).await?;
+    if !resp.status().is_success() {
+        return Err(colette_http::Error::Status(resp.status()));
+    }
+
+    Ok(resp)
+}
+
+/// Fetches and parses a native Atom, RSS, or JSON feed, for plugins that only rewrite page URLs
+/// into feed URLs
+pub async fn fetch_feed<HC: HttpClient>(
+    client: &HC,
+    request: Builder,
+) -> Result<ProcessedFeed, FeedError> {
+    let resp = fetch(client, request).await?;
+    let content_type = resp
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|e| e.to_str().ok())
+        .map(ToOwned::to_owned);
+
+    colette_scraper::feed::parse_feed(resp.into_body(), content_type.as_deref())
+}
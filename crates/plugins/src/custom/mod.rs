@@ -1,12 +1,12 @@
+use colette_http::HttpClient;
 use colette_scraper::{bookmark::BookmarkPlugin, feed::FeedPlugin};
-use reqwest::Client;
 
 #[allow(unused_variables)]
-pub fn feeds(client: Client) -> Vec<Box<dyn FeedPlugin>> {
+pub fn feeds<HC: HttpClient + Clone + 'static>(client: HC) -> Vec<Box<dyn FeedPlugin>> {
     vec![]
 }
 
 #[allow(unused_variables)]
-pub fn bookmarks(client: Client) -> Vec<Box<dyn BookmarkPlugin>> {
+pub fn bookmarks<HC: HttpClient + Clone + 'static>(client: HC) -> Vec<Box<dyn BookmarkPlugin>> {
     vec![]
 }
@@ -0,0 +1,51 @@
+use colette_http::HttpClient;
+use colette_scraper::bookmark::{BookmarkError, BookmarkPlugin, ProcessedBookmark};
+use http::Request;
+use url::Url;
+
+use super::{BookmarkPluginConfig, PluginTarget};
+use crate::common::{BookmarkExtractor, fetch};
+
+#[derive(Clone)]
+pub struct DeclarativeBookmarkPlugin<HC> {
+    client: HC,
+    target: PluginTarget,
+    extractor: BookmarkExtractor,
+}
+
+impl<HC: HttpClient> DeclarativeBookmarkPlugin<HC> {
+    pub(crate) fn new(client: HC, config: BookmarkPluginConfig) -> Self {
+        Self {
+            client,
+            target: config.target,
+            extractor: BookmarkExtractor::new(config.options),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<HC: HttpClient + 'static> BookmarkPlugin for DeclarativeBookmarkPlugin<HC> {
+    fn is_supported(&self, url: &mut Url) -> bool {
+        self.target.matches(url)
+    }
+
+    async fn scrape(&self, url: &Url) -> Result<ProcessedBookmark, BookmarkError> {
+        let mut request = Request::get(url.as_str());
+        if let Some(headers) = request.headers_mut() {
+            headers.extend(self.target.headers());
+        }
+        let body = fetch(&self.client, request).await?.into_body();
+
+        let mut extracted = self.extractor.extract(body)?;
+
+        if let Some(thumbnail) = extracted.thumbnail.take() {
+            extracted.thumbnail = Some(
+                url.join(thumbnail.trim())
+                    .map(String::from)
+                    .unwrap_or(thumbnail),
+            );
+        }
+
+        Ok(extracted.try_into()?)
+    }
+}
@@ -0,0 +1,53 @@
+use colette_http::HttpClient;
+use colette_scraper::feed::{FeedError, FeedPlugin, ProcessedFeed};
+use http::Request;
+use url::Url;
+
+use super::{FeedPluginConfig, PluginTarget};
+use crate::common::{FeedExtractor, fetch};
+
+#[derive(Clone)]
+pub struct DeclarativeFeedPlugin<HC> {
+    client: HC,
+    target: PluginTarget,
+    extractor: FeedExtractor,
+}
+
+impl<HC: HttpClient> DeclarativeFeedPlugin<HC> {
+    pub(crate) fn new(client: HC, config: FeedPluginConfig) -> Self {
+        Self {
+            client,
+            target: config.target,
+            extractor: FeedExtractor::new(config.options),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<HC: HttpClient + 'static> FeedPlugin for DeclarativeFeedPlugin<HC> {
+    fn is_supported(&self, url: &mut Url) -> bool {
+        self.target.matches(url)
+    }
+
+    async fn scrape(&self, url: &mut Url) -> Result<ProcessedFeed, FeedError> {
+        let mut request = Request::get(url.as_str());
+        if let Some(headers) = request.headers_mut() {
+            headers.extend(self.target.headers());
+        }
+        let body = fetch(&self.client, request).await?.into_body();
+
+        let mut extracted = self.extractor.extract(body)?;
+
+        extracted.link = Some(resolve(url, extracted.link).unwrap_or_else(|| url.to_string()));
+        for entry in extracted.entries.iter_mut() {
+            entry.link = resolve(url, entry.link.take());
+            entry.thumbnail = resolve(url, entry.thumbnail.take());
+        }
+
+        Ok(extracted.try_into()?)
+    }
+}
+
+fn resolve(base: &Url, value: Option<String>) -> Option<String> {
+    value.map(|e| base.join(e.trim()).map(String::from).unwrap_or(e))
+}
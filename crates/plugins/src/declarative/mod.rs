@@ -0,0 +1,342 @@
+//! Site-scraper plugins defined in TOML or JSON files, so that any HTML page can be turned into a
+//! feed or bookmark without recompiling.
+//!
+//! Each file contains a list of `feeds` and `bookmarks` plugins:
+//!
+//! ```toml
+//! [[feeds]]
+//! name = "example-blog"
+//! match = ["^https://example\\.com/blog/?$"]
+//! headers = { "User-Agent" = "colette" }
+//! title = [{ selector = "title" }]
+//! entries = ["article.post"]
+//! entry.link = [{ selector = "a.permalink", attr = "href" }]
+//! entry.title = [{ selector = "h2" }]
+//! entry.published = [{ selector = "time", attr = "datetime" }]
+//!
+//! [[feeds.rewrite]]
+//! pattern = "^http://"
+//! replacement = "https://"
+//! ```
+//!
+//! Queries without an `attr` select the inner HTML of the first matching element.
+
+use std::{
+    collections::HashMap,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+pub use bookmark::DeclarativeBookmarkPlugin;
+use colette_scraper::{ExtractorQuery, Node};
+pub use feed::DeclarativeFeedPlugin;
+use lazy_regex::Regex;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use scraper::Selector;
+use url::Url;
+
+use crate::common::{BookmarkExtractorOptions, FeedExtractorOptions};
+
+mod bookmark;
+mod feed;
+
+/// Plugins loaded from definition files, ready to be registered
+#[derive(Debug, Clone, Default)]
+pub struct DeclarativePlugins {
+    feeds: Vec<FeedPluginConfig>,
+    bookmarks: Vec<BookmarkPluginConfig>,
+}
+
+impl DeclarativePlugins {
+    /// Loads every `.toml` and `.json` file in a directory, in alphabetical order
+    pub fn load(dir: &Path) -> Result<Self, Error> {
+        let mut paths = fs::read_dir(dir)?
+            .map(|e| e.map(|e| e.path()))
+            .collect::<Result<Vec<_>, _>>()?;
+        paths.sort();
+
+        let mut plugins = Self::default();
+
+        for path in paths {
+            let file = match path.extension().and_then(|e| e.to_str()) {
+                Some("toml") => toml::from_str::<PluginFile>(&fs::read_to_string(&path)?)
+                    .map_err(|e| Error::parse(&path, e.to_string()))?,
+                Some("json") => serde_json::from_str::<PluginFile>(&fs::read_to_string(&path)?)
+                    .map_err(|e| Error::parse(&path, e.to_string()))?,
+                _ => continue,
+            };
+
+            for definition in file.feeds {
+                plugins.feeds.push(definition.try_into()?);
+            }
+            for definition in file.bookmarks {
+                plugins.bookmarks.push(definition.try_into()?);
+            }
+        }
+
+        Ok(plugins)
+    }
+
+    pub(crate) fn feeds(&self) -> &[FeedPluginConfig] {
+        &self.feeds
+    }
+
+    pub(crate) fn bookmarks(&self) -> &[BookmarkPluginConfig] {
+        &self.bookmarks
+    }
+}
+
+/// URL matching and request options shared by feed and bookmark plugins
+#[derive(Debug, Clone)]
+pub(crate) struct PluginTarget {
+    patterns: Vec<Regex>,
+    rewrites: Vec<(Regex, String)>,
+    headers: HeaderMap,
+}
+
+impl PluginTarget {
+    /// Checks the URL against the match patterns, and applies the rewrite rules if it matches
+    fn matches(&self, url: &mut Url) -> bool {
+        if !self.patterns.iter().any(|e| e.is_match(url.as_str())) {
+            return false;
+        }
+
+        for (pattern, replacement) in self.rewrites.iter() {
+            if let Ok(rewritten) = Url::parse(&pattern.replace(url.as_str(), replacement.as_str()))
+            {
+                *url = rewritten;
+            }
+        }
+
+        true
+    }
+
+    fn headers(&self) -> HeaderMap {
+        self.headers.clone()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct FeedPluginConfig {
+    target: PluginTarget,
+    options: FeedExtractorOptions,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct BookmarkPluginConfig {
+    target: PluginTarget,
+    options: BookmarkExtractorOptions,
+}
+
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(default)]
+struct PluginFile {
+    feeds: Vec<FeedPluginDefinition>,
+    bookmarks: Vec<BookmarkPluginDefinition>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+struct FeedPluginDefinition {
+    name: String,
+    #[serde(flatten)]
+    target: TargetDefinition,
+    #[serde(default)]
+    link: Vec<QueryDefinition>,
+    #[serde(default)]
+    title: Vec<QueryDefinition>,
+    #[serde(default)]
+    description: Vec<QueryDefinition>,
+    #[serde(default)]
+    refreshed: Vec<QueryDefinition>,
+    entries: Vec<String>,
+    #[serde(default)]
+    entry: FeedEntryDefinition,
+}
+
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(default)]
+struct FeedEntryDefinition {
+    link: Vec<QueryDefinition>,
+    title: Vec<QueryDefinition>,
+    published: Vec<QueryDefinition>,
+    description: Vec<QueryDefinition>,
+    author: Vec<QueryDefinition>,
+    thumbnail: Vec<QueryDefinition>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+struct BookmarkPluginDefinition {
+    name: String,
+    #[serde(flatten)]
+    target: TargetDefinition,
+    #[serde(default)]
+    title: Vec<QueryDefinition>,
+    #[serde(default)]
+    published: Vec<QueryDefinition>,
+    #[serde(default)]
+    author: Vec<QueryDefinition>,
+    #[serde(default)]
+    thumbnail: Vec<QueryDefinition>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+struct TargetDefinition {
+    /// Regular expressions matched against the full URL
+    #[serde(rename = "match")]
+    patterns: Vec<String>,
+    #[serde(default)]
+    rewrite: Vec<RewriteDefinition>,
+    #[serde(default)]
+    headers: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+struct RewriteDefinition {
+    pattern: String,
+    replacement: String,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+struct QueryDefinition {
+    selector: String,
+    attr: Option<String>,
+}
+
+impl TryFrom<FeedPluginDefinition> for FeedPluginConfig {
+    type Error = Error;
+
+    fn try_from(value: FeedPluginDefinition) -> Result<Self, Self::Error> {
+        let name = value.name.as_str();
+        let queries = |queries: Vec<QueryDefinition>| parse_queries(name, queries);
+
+        let options = FeedExtractorOptions {
+            feed_link_queries: queries(value.link)?,
+            feed_title_queries: queries(value.title)?,
+            feed_description_queries: queries(value.description)?,
+            feed_refreshed_queries: queries(value.refreshed)?,
+            feed_entries_selectors: value
+                .entries
+                .iter()
+                .map(|e| parse_selector(name, e))
+                .collect::<Result<_, _>>()?,
+            feed_entry_link_queries: queries(value.entry.link)?,
+            feed_entry_title_queries: queries(value.entry.title)?,
+            feed_entry_published_queries: queries(value.entry.published)?,
+            feed_entry_description_queries: queries(value.entry.description)?,
+            feed_entry_author_queries: queries(value.entry.author)?,
+            feed_entry_thumbnail_queries: queries(value.entry.thumbnail)?,
+        };
+
+        Ok(Self {
+            target: parse_target(name, value.target)?,
+            options,
+        })
+    }
+}
+
+impl TryFrom<BookmarkPluginDefinition> for BookmarkPluginConfig {
+    type Error = Error;
+
+    fn try_from(value: BookmarkPluginDefinition) -> Result<Self, Self::Error> {
+        let name = value.name.as_str();
+        let queries = |queries: Vec<QueryDefinition>| parse_queries(name, queries);
+
+        let options = BookmarkExtractorOptions {
+            title_queries: queries(value.title)?,
+            published_queries: queries(value.published)?,
+            author_queries: queries(value.author)?,
+            thumbnail_queries: queries(value.thumbnail)?,
+        };
+
+        Ok(Self {
+            target: parse_target(name, value.target)?,
+            options,
+        })
+    }
+}
+
+fn parse_target(name: &str, value: TargetDefinition) -> Result<PluginTarget, Error> {
+    if value.patterns.is_empty() {
+        return Err(Error::invalid(
+            name,
+            "at least one match pattern is required",
+        ));
+    }
+
+    let patterns = value
+        .patterns
+        .iter()
+        .map(|e| parse_regex(name, e))
+        .collect::<Result<_, _>>()?;
+    let rewrites = value
+        .rewrite
+        .into_iter()
+        .map(|e| parse_regex(name, &e.pattern).map(|pattern| (pattern, e.replacement)))
+        .collect::<Result<_, _>>()?;
+
+    let mut headers = HeaderMap::new();
+    for (key, value) in value.headers {
+        let key = HeaderName::try_from(key.as_str())
+            .map_err(|_| Error::invalid(name, format!("invalid header name: {key}")))?;
+        let value = HeaderValue::try_from(value.as_str())
+            .map_err(|_| Error::invalid(name, format!("invalid header value: {value}")))?;
+
+        headers.insert(key, value);
+    }
+
+    Ok(PluginTarget {
+        patterns,
+        rewrites,
+        headers,
+    })
+}
+
+fn parse_queries(name: &str, value: Vec<QueryDefinition>) -> Result<Vec<ExtractorQuery>, Error> {
+    value
+        .into_iter()
+        .map(|e| {
+            let node = match e.attr {
+                Some(attr) => Node::Attr(attr),
+                None => Node::Text,
+            };
+
+            parse_selector(name, &e.selector).map(|selector| ExtractorQuery::new(selector, node))
+        })
+        .collect()
+}
+
+fn parse_selector(name: &str, value: &str) -> Result<Selector, Error> {
+    Selector::parse(value).map_err(|_| Error::invalid(name, format!("invalid selector: {value}")))
+}
+
+fn parse_regex(name: &str, value: &str) -> Result<Regex, Error> {
+    Regex::new(value).map_err(|e| Error::invalid(name, format!("invalid pattern: {e}")))
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("failed to parse plugin file {}: {message}", path.display())]
+    Parse { path: PathBuf, message: String },
+
+    #[error("invalid plugin {name}: {message}")]
+    Invalid { name: String, message: String },
+
+    #[error(transparent)]
+    Io(#[from] io::Error),
+}
+
+impl Error {
+    fn parse(path: &Path, message: String) -> Self {
+        Self::Parse {
+            path: path.to_owned(),
+            message,
+        }
+    }
+
+    fn invalid(name: &str, message: impl Into<String>) -> Self {
+        Self::Invalid {
+            name: name.to_owned(),
+            message: message.into(),
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use colette_http::HttpClient;
+use colette_scraper::feed::{FeedError, FeedPlugin, ProcessedFeed};
+use http::Request;
+use url::Url;
+
+use crate::common::fetch_feed;
+
+/// Top-level GitHub paths that aren't user or organization names
+const RESERVED_OWNERS: &[&str] = &[
+    "about",
+    "account",
+    "apps",
+    "codespaces",
+    "collections",
+    "contact",
+    "customer-stories",
+    "dashboard",
+    "enterprise",
+    "events",
+    "explore",
+    "features",
+    "issues",
+    "join",
+    "login",
+    "logout",
+    "marketplace",
+    "new",
+    "notifications",
+    "organizations",
+    "orgs",
+    "pricing",
+    "pulls",
+    "search",
+    "security",
+    "sessions",
+    "settings",
+    "signup",
+    "site",
+    "solutions",
+    "sponsors",
+    "stars",
+    "team",
+    "topics",
+    "trending",
+    "users",
+    "watching",
+];
+
+/// Turns GitHub repository URLs into the repository's releases, tags, or commits Atom feed.
+/// Repository home pages default to releases.
+#[derive(Clone)]
+pub struct GitHubFeedPlugin<HC> {
+    client: HC,
+}
+
+impl<HC: HttpClient> GitHubFeedPlugin<HC> {
+    pub fn new(client: HC) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait::async_trait]
+impl<HC: HttpClient + 'static> FeedPlugin for GitHubFeedPlugin<HC> {
+    fn is_supported(&self, url: &mut Url) -> bool {
+        if !matches!(url.domain(), Some("github.com" | "www.github.com"))
+            || url.path().ends_with(".atom")
+        {
+            return false;
+        }
+
+        let segments = url
+            .path_segments()
+            .map(|e| e.filter(|e| !e.is_empty()).collect::<Vec<_>>())
+            .unwrap_or_default();
+
+        let path = match segments.as_slice() {
+            [owner, ..] if RESERVED_OWNERS.contains(owner) => return false,
+            [owner, repo] | [owner, repo, "releases"] => {
+                format!("/{owner}/{repo}/releases.atom")
+            }
+            [owner, repo, "tags"] => format!("/{owner}/{repo}/tags.atom"),
+            [owner, repo, "commits"] => format!("/{owner}/{repo}/commits.atom"),
+            [owner, repo, "commits", branch @ ..] | [owner, repo, "tree", branch @ ..] => {
+                format!("/{owner}/{repo}/commits/{}.atom", branch.join("/"))
+            }
+            _ => return false,
+        };
+
+        url.set_path(&path);
+        url.set_query(None);
+        url.set_fragment(None);
+        let _ = url.set_host(Some("github.com"));
+
+        true
+    }
+
+    async fn scrape(&self, url: &mut Url) -> Result<ProcessedFeed, FeedError> {
+        fetch_feed(&self.client, Request::get(url.as_str())).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use colette_http::ReqwestClient;
+    use reqwest::Client;
+
+    use super::*;
+
+    fn rewrite(url: &str) -> Option<String> {
+        let mut url = Url::parse(url).unwrap();
+
+        GitHubFeedPlugin::new(ReqwestClient::new(Client::new()))
+            .is_supported(&mut url)
+            .then(|| url.to_string())
+    }
+
+    #[test]
+    fn rewrites_repository_urls_to_feeds() {
+        assert_eq!(
+            rewrite("https://www.github.com/owner/repo?tab=readme").as_deref(),
+            Some("https://github.com/owner/repo/releases.atom")
+        );
+        assert_eq!(
+            rewrite("https://github.com/owner/repo/tags").as_deref(),
+            Some("https://github.com/owner/repo/tags.atom")
+        );
+        assert_eq!(
+            rewrite("https://github.com/owner/repo/tree/feature/name").as_deref(),
+            Some("https://github.com/owner/repo/commits/feature/name.atom")
+        );
+    }
+
+    #[test]
+    fn rejects_reserved_and_feed_urls() {
+        assert_eq!(rewrite("https://github.com/login/oauth"), None);
+        assert_eq!(rewrite("https://github.com/settings/profile"), None);
+        assert_eq!(rewrite("https://github.com/owner/repo/releases.atom"), None);
+        assert_eq!(rewrite("https://github.com/owner/repo/issues/1"), None);
+    }
+}
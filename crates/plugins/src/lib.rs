@@ -1,23 +1,49 @@
+use colette_http::HttpClient;
 use colette_scraper::{bookmark::BookmarkPlugin, feed::FeedPlugin};
-use reddit::RedditBookmarkPlugin;
-use reqwest::Client;
+use declarative::{DeclarativeBookmarkPlugin, DeclarativeFeedPlugin};
+pub use declarative::{DeclarativePlugins, Error as DeclarativePluginError};
+use github::GitHubFeedPlugin;
+use mastodon::MastodonFeedPlugin;
+use reddit::{RedditBookmarkPlugin, RedditFeedPlugin};
+use youtube::YouTubeFeedPlugin;
 
 mod common;
 mod custom;
+mod declarative;
+mod github;
+mod mastodon;
 mod reddit;
+mod youtube;
 
-pub fn register_feed_plugins(client: Client) -> Vec<Box<dyn FeedPlugin>> {
+pub fn register_feed_plugins<HC: HttpClient + Clone + 'static>(
+    client: HC,
+    declarative: &DeclarativePlugins,
+) -> Vec<Box<dyn FeedPlugin>> {
     let mut plugins: Vec<Box<dyn FeedPlugin>> = vec![];
 
+    plugins.extend(declarative.feeds().iter().map(|e| {
+        Box::new(DeclarativeFeedPlugin::new(client.clone(), e.to_owned())) as Box<dyn FeedPlugin>
+    }));
+    plugins.push(Box::new(YouTubeFeedPlugin::new(client.clone())));
+    plugins.push(Box::new(RedditFeedPlugin::new(client.clone())));
+    plugins.push(Box::new(GitHubFeedPlugin::new(client.clone())));
+    plugins.push(Box::new(MastodonFeedPlugin::new(client.clone())));
     plugins.extend(custom::feeds(client));
 
     plugins
 }
 
-pub fn register_bookmark_plugins(client: Client) -> Vec<Box<dyn BookmarkPlugin>> {
-    let mut plugins: Vec<Box<dyn BookmarkPlugin>> =
-        vec![Box::new(RedditBookmarkPlugin::new(client.clone()))];
+pub fn register_bookmark_plugins<HC: HttpClient + Clone + 'static>(
+    client: HC,
+    declarative: &DeclarativePlugins,
+) -> Vec<Box<dyn BookmarkPlugin>> {
+    let mut plugins: Vec<Box<dyn BookmarkPlugin>> = vec![];
 
+    plugins.extend(declarative.bookmarks().iter().map(|e| {
+        Box::new(DeclarativeBookmarkPlugin::new(client.clone(), e.to_owned()))
+            as Box<dyn BookmarkPlugin>
+    }));
+    plugins.push(Box::new(RedditBookmarkPlugin::new(client.clone())));
     plugins.extend(custom::bookmarks(client));
 
     plugins
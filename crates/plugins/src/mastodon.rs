@@ -0,0 +1,137 @@
+use colette_http::HttpClient;
+use colette_scraper::feed::{FeedError, FeedPlugin, ProcessedFeed};
+use http::Request;
+use url::Url;
+
+use crate::common::{fetch, fetch_feed};
+
+const INSTANCE_PATH: &str = "/api/v1/instance";
+
+/// Sites that use `/@name` profile paths without being Mastodon instances
+const EXCLUDED_DOMAINS: &[&str] = &[
+    "medium.com",
+    "www.threads.net",
+    "www.tiktok.com",
+    "www.youtube.com",
+    "youtube.com",
+];
+
+/// Turns Mastodon profile URLs into the profile's RSS feed. Any domain is matched, since instances
+/// can't be told apart from other sites by their URLs, so the instance API is probed before
+/// scraping. The rewritten `.rss` URLs are left to the generic scraper, so refreshes of a stored
+/// feed don't probe again.
+#[derive(Clone)]
+pub struct MastodonFeedPlugin<HC> {
+    client: HC,
+}
+
+impl<HC: HttpClient> MastodonFeedPlugin<HC> {
+    pub fn new(client: HC) -> Self {
+        Self { client }
+    }
+
+    async fn is_instance(&self, url: &Url) -> bool {
+        let Ok(api_url) = url.join(INSTANCE_PATH) else {
+            return false;
+        };
+
+        let Ok(resp) = fetch(&self.client, Request::get(api_url.as_str())).await else {
+            return false;
+        };
+
+        serde_json::from_slice::<serde_json::Value>(resp.body())
+            .is_ok_and(|e| e.get("uri").is_some())
+    }
+}
+
+#[async_trait::async_trait]
+impl<HC: HttpClient + 'static> FeedPlugin for MastodonFeedPlugin<HC> {
+    fn is_supported(&self, url: &mut Url) -> bool {
+        let Some(domain) = url.domain() else {
+            return false;
+        };
+        if EXCLUDED_DOMAINS.contains(&domain) {
+            return false;
+        }
+
+        let segments = url
+            .path_segments()
+            .map(|e| e.filter(|e| !e.is_empty()).collect::<Vec<_>>())
+            .unwrap_or_default();
+        if segments.last().is_some_and(|e| e.ends_with(".rss")) {
+            return false;
+        }
+
+        // Remote profiles (`/@name@other.instance`) have no feed on this instance
+        let path = match segments.as_slice() {
+            [name] if is_local_account(name) => format!("/{name}.rss"),
+            [name, "tagged", tag] if is_local_account(name) => format!("/{name}/tagged/{tag}.rss"),
+            _ => return false,
+        };
+
+        url.set_path(&path);
+        url.set_query(None);
+        url.set_fragment(None);
+
+        true
+    }
+
+    async fn scrape(&self, url: &mut Url) -> Result<ProcessedFeed, FeedError> {
+        // Other sites fall through to feed detection on the page itself
+        if !self.is_instance(url).await {
+            return Err(FeedError::Unsupported);
+        }
+
+        fetch_feed(&self.client, Request::get(url.as_str())).await
+    }
+}
+
+fn is_local_account(segment: &str) -> bool {
+    segment
+        .strip_prefix('@')
+        .is_some_and(|e| !e.is_empty() && e.chars().all(|e| e.is_ascii_alphanumeric() || e == '_'))
+}
+
+#[cfg(test)]
+mod tests {
+    use colette_http::ReqwestClient;
+    use reqwest::Client;
+
+    use super::*;
+
+    fn rewrite(url: &str) -> Option<String> {
+        let mut url = Url::parse(url).unwrap();
+
+        MastodonFeedPlugin::new(ReqwestClient::new(Client::new()))
+            .is_supported(&mut url)
+            .then(|| url.to_string())
+    }
+
+    #[test]
+    fn rewrites_local_profiles_to_feeds() {
+        assert_eq!(
+            rewrite("https://mastodon.social/@name?page=2").as_deref(),
+            Some("https://mastodon.social/@name.rss")
+        );
+        assert_eq!(
+            rewrite("https://mastodon.social/@name/tagged/rust").as_deref(),
+            Some("https://mastodon.social/@name/tagged/rust.rss")
+        );
+    }
+
+    #[test]
+    fn rejects_remote_profiles_and_excluded_domains() {
+        assert_eq!(rewrite("https://mastodon.social/@name@other.social"), None);
+        assert_eq!(rewrite("https://medium.com/@name"), None);
+        assert_eq!(rewrite("https://mastodon.social/about"), None);
+    }
+
+    #[test]
+    fn skips_rewritten_feeds() {
+        assert_eq!(rewrite("https://mastodon.social/@name.rss"), None);
+        assert_eq!(
+            rewrite("https://mastodon.social/@name/tagged/rust.rss"),
+            None
+        );
+    }
+}
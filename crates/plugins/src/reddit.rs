@@ -1,42 +1,104 @@
+use colette_http::HttpClient;
 use colette_scraper::{
     ExtractorQuery, Node,
     bookmark::{BookmarkError, BookmarkPlugin, ProcessedBookmark},
+    feed::{FeedError, FeedPlugin, ProcessedFeed},
 };
-use reqwest::{
-    Client, Method, Request, RequestBuilder,
+use http::{
+    Request,
     header::{self, HeaderValue},
 };
 use scraper::Selector;
 use url::Url;
 
-use crate::common::{BookmarkExtractor, BookmarkExtractorOptions};
+use crate::common::{BookmarkExtractor, BookmarkExtractorOptions, fetch, fetch_feed};
 
 const USER_AGENT: &str = "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/128.0.0.0 Safari/537.36";
 
+/// Turns subreddit and user URLs into their RSS feeds
 #[derive(Clone)]
-pub struct RedditBookmarkPlugin {
-    client: Client,
+pub struct RedditFeedPlugin<HC> {
+    client: HC,
+}
+
+impl<HC: HttpClient> RedditFeedPlugin<HC> {
+    pub fn new(client: HC) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait::async_trait]
+impl<HC: HttpClient + 'static> FeedPlugin for RedditFeedPlugin<HC> {
+    fn is_supported(&self, url: &mut Url) -> bool {
+        if !matches!(
+            url.domain(),
+            Some("reddit.com" | "www.reddit.com" | "old.reddit.com")
+        ) {
+            return false;
+        }
+
+        // Feed URLs are matched as well, so that refreshes also send the custom user agent
+        let mut segments = url
+            .path_segments()
+            .map(|e| e.filter(|e| !e.is_empty()).collect::<Vec<_>>())
+            .unwrap_or_default();
+        if segments.last() == Some(&".rss") {
+            segments.pop();
+        }
+
+        let path = match segments.as_slice() {
+            [kind @ ("r" | "user" | "u"), name] => format!("/{kind}/{name}/.rss"),
+            [
+                kind @ ("r" | "user" | "u"),
+                name,
+                sort @ ("hot" | "new" | "top" | "rising"),
+            ] => {
+                format!("/{kind}/{name}/{sort}/.rss")
+            }
+            _ => return false,
+        };
+
+        url.set_path(&path);
+        url.set_fragment(None);
+        let _ = url.set_host(Some("www.reddit.com"));
+
+        true
+    }
+
+    async fn scrape(&self, url: &mut Url) -> Result<ProcessedFeed, FeedError> {
+        fetch_feed(
+            &self.client,
+            Request::get(url.as_str())
+                .header(header::USER_AGENT, HeaderValue::from_static(USER_AGENT)),
+        )
+        .await
+    }
+}
+
+#[derive(Clone)]
+pub struct RedditBookmarkPlugin<HC> {
+    client: HC,
     extractor: BookmarkExtractor,
 }
 
-impl RedditBookmarkPlugin {
-    pub fn new(client: Client) -> Self {
+impl<HC: HttpClient> RedditBookmarkPlugin<HC> {
+    pub fn new(client: HC) -> Self {
         let options = BookmarkExtractorOptions {
             title_queries: vec![ExtractorQuery {
                 selector: Selector::parse("shreddit-post").unwrap(),
-                node: Node::Attr("post-title"),
+                node: Node::Attr("post-title".into()),
             }],
             thumbnail_queries: vec![ExtractorQuery {
                 selector: Selector::parse(".preview-img").unwrap(),
-                node: Node::Attr("src"),
+                node: Node::Attr("src".into()),
             }],
             published_queries: vec![ExtractorQuery {
                 selector: Selector::parse("shreddit-post").unwrap(),
-                node: Node::Attr("created-timestamp"),
+                node: Node::Attr("created-timestamp".into()),
             }],
             author_queries: vec![ExtractorQuery {
                 selector: Selector::parse("shreddit-post").unwrap(),
-                node: Node::Attr("author"),
+                node: Node::Attr("author".into()),
             }],
         };
 
@@ -48,7 +110,7 @@ impl RedditBookmarkPlugin {
 }
 
 #[async_trait::async_trait]
-impl BookmarkPlugin for RedditBookmarkPlugin {
+impl<HC: HttpClient + 'static> BookmarkPlugin for RedditBookmarkPlugin<HC> {
     fn is_supported(&self, url: &mut Url) -> bool {
         if let Some(domain) = url.domain()
             && domain != "www.reddit.com"
@@ -64,17 +126,54 @@ impl BookmarkPlugin for RedditBookmarkPlugin {
     }
 
     async fn scrape(&self, url: &Url) -> Result<ProcessedBookmark, BookmarkError> {
-        let resp = RequestBuilder::from_parts(
-            self.client.clone(),
-            Request::new(Method::GET, url.to_owned()),
+        let body = fetch(
+            &self.client,
+            Request::get(url.as_str())
+                .header(header::USER_AGENT, HeaderValue::from_static(USER_AGENT)),
         )
-        .header(header::USER_AGENT, HeaderValue::from_static(USER_AGENT))
-        .send()
-        .await?;
-        let body = resp.bytes().await?;
+        .await?
+        .into_body();
 
         let extracted = self.extractor.extract(body)?;
 
         Ok(extracted.try_into()?)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use colette_http::ReqwestClient;
+    use reqwest::Client;
+
+    use super::*;
+
+    fn rewrite(url: &str) -> Option<String> {
+        let mut url = Url::parse(url).unwrap();
+
+        RedditFeedPlugin::new(ReqwestClient::new(Client::new()))
+            .is_supported(&mut url)
+            .then(|| url.to_string())
+    }
+
+    #[test]
+    fn rewrites_subreddit_and_user_urls_to_feeds() {
+        assert_eq!(
+            rewrite("https://old.reddit.com/r/rust/").as_deref(),
+            Some("https://www.reddit.com/r/rust/.rss")
+        );
+        assert_eq!(
+            rewrite("https://reddit.com/u/name/top").as_deref(),
+            Some("https://www.reddit.com/u/name/top/.rss")
+        );
+        assert_eq!(
+            rewrite("https://www.reddit.com/r/rust/.rss").as_deref(),
+            Some("https://www.reddit.com/r/rust/.rss")
+        );
+    }
+
+    #[test]
+    fn rejects_other_urls() {
+        assert_eq!(rewrite("https://www.reddit.com/r/rust/comments/123"), None);
+        assert_eq!(rewrite("https://example.com/r/rust"), None);
+    }
+}
@@ -0,0 +1,158 @@
+use bytes::Buf;
+use colette_http::HttpClient;
+use colette_scraper::feed::{FeedError, FeedPlugin, ProcessedFeed};
+use http::Request;
+use url::Url;
+
+use crate::common::{fetch, fetch_feed};
+
+const FEED_PATH: &str = "/feeds/videos.xml";
+
+/// Turns YouTube channel, user, and handle URLs into the channel's video feed
+#[derive(Clone)]
+pub struct YouTubeFeedPlugin<HC> {
+    client: HC,
+}
+
+impl<HC: HttpClient> YouTubeFeedPlugin<HC> {
+    pub fn new(client: HC) -> Self {
+        Self { client }
+    }
+
+    /// Handle and custom URLs don't contain the channel ID, so the feed URL is read from the
+    /// channel page instead. The resolved URL replaces the page URL, so that the feed is stored
+    /// and refreshed under its `channel_id` feed URL.
+    async fn resolve_feed_url(&self, url: &Url) -> Result<Url, FeedError> {
+        let body = fetch(&self.client, Request::get(url.as_str()))
+            .await?
+            .into_body();
+
+        let metadata =
+            colette_meta::parse_metadata(body.reader()).map_err(|_| FeedError::Unsupported)?;
+
+        metadata
+            .feeds
+            .into_iter()
+            .filter_map(|e| url.join(&e.href).ok())
+            .find(|e| e.path() == FEED_PATH)
+            .ok_or(FeedError::Unsupported)
+    }
+}
+
+#[async_trait::async_trait]
+impl<HC: HttpClient + 'static> FeedPlugin for YouTubeFeedPlugin<HC> {
+    fn is_supported(&self, url: &mut Url) -> bool {
+        if !matches!(
+            url.domain(),
+            Some("youtube.com" | "www.youtube.com" | "m.youtube.com")
+        ) {
+            return false;
+        }
+
+        let segments = url
+            .path_segments()
+            .map(|e| {
+                e.filter(|e| !e.is_empty())
+                    .map(String::from)
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        match segments.as_slice() {
+            [prefix, id, ..] if prefix == "channel" => set_feed_url(url, "channel_id", id),
+            [prefix, name, ..] if prefix == "user" => set_feed_url(url, "user", name),
+            [prefix] if prefix == "playlist" => {
+                let Some(id) = url
+                    .query_pairs()
+                    .find_map(|(k, v)| (k == "list").then(|| v.into_owned()))
+                else {
+                    return false;
+                };
+                set_feed_url(url, "playlist_id", &id);
+            }
+            [handle, ..] if handle.starts_with('@') => set_page_url(url, &format!("/{handle}")),
+            [prefix, name, ..] if prefix == "c" => set_page_url(url, &format!("/c/{name}")),
+            _ => return false,
+        }
+
+        true
+    }
+
+    async fn scrape(&self, url: &mut Url) -> Result<ProcessedFeed, FeedError> {
+        if url.path() != FEED_PATH {
+            *url = self.resolve_feed_url(url).await?;
+            normalize(url);
+        }
+
+        fetch_feed(&self.client, Request::get(url.as_str())).await
+    }
+}
+
+fn set_feed_url(url: &mut Url, key: &str, value: &str) {
+    url.set_path(FEED_PATH);
+    url.query_pairs_mut().clear().append_pair(key, value);
+    normalize(url);
+}
+
+fn set_page_url(url: &mut Url, path: &str) {
+    url.set_path(path);
+    url.set_query(None);
+    normalize(url);
+}
+
+fn normalize(url: &mut Url) {
+    let _ = url.set_scheme("https");
+    let _ = url.set_host(Some("www.youtube.com"));
+    url.set_fragment(None);
+}
+
+#[cfg(test)]
+mod tests {
+    use colette_http::ReqwestClient;
+    use reqwest::Client;
+
+    use super::*;
+
+    fn rewrite(url: &str) -> Option<String> {
+        let mut url = Url::parse(url).unwrap();
+
+        YouTubeFeedPlugin::new(ReqwestClient::new(Client::new()))
+            .is_supported(&mut url)
+            .then(|| url.to_string())
+    }
+
+    #[test]
+    fn rewrites_channel_and_user_urls_to_feeds() {
+        assert_eq!(
+            rewrite("https://m.youtube.com/channel/UC123/videos").as_deref(),
+            Some("https://www.youtube.com/feeds/videos.xml?channel_id=UC123")
+        );
+        assert_eq!(
+            rewrite("http://youtube.com/user/name").as_deref(),
+            Some("https://www.youtube.com/feeds/videos.xml?user=name")
+        );
+        assert_eq!(
+            rewrite("https://www.youtube.com/playlist?list=PL123&index=2").as_deref(),
+            Some("https://www.youtube.com/feeds/videos.xml?playlist_id=PL123")
+        );
+    }
+
+    #[test]
+    fn keeps_handle_and_custom_urls_for_resolution() {
+        assert_eq!(
+            rewrite("https://youtube.com/@name/videos?view=0").as_deref(),
+            Some("https://www.youtube.com/@name")
+        );
+        assert_eq!(
+            rewrite("https://www.youtube.com/c/name/featured").as_deref(),
+            Some("https://www.youtube.com/c/name")
+        );
+    }
+
+    #[test]
+    fn rejects_other_urls() {
+        assert_eq!(rewrite("https://www.youtube.com/watch?v=123"), None);
+        assert_eq!(rewrite("https://www.youtube.com/playlist"), None);
+        assert_eq!(rewrite("https://example.com/channel/UC123"), None);
+    }
+}
@@ -12,8 +12,11 @@ use colette_meta::{
         TypeOrString, VideoObject, WebPage, WebSite,
     },
 };
+use reqwest::header::CONTENT_TYPE;
 use url::Url;
 
+use crate::charset::to_utf8;
+
 const RFC3339_WITH_MILLI: &str = "%Y-%m-%dT%H:%M:%S%.3f%z";
 const RFC3339_WITH_MICRO: &str = "%Y-%m-%dT%H:%M:%S%.6f%z";
 
@@ -41,7 +44,14 @@ impl<HC: HttpClient> BookmarkScraper<HC> {
             }
         }
 
-        let body = self.client.get(url).await?;
+        let resp = self.client.get_response(url).await?;
+        let content_type = resp
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|e| e.to_str().ok())
+            .map(ToOwned::to_owned);
+
+        let body = to_utf8(resp.into_body(), content_type.as_deref());
         let mut reader = BufReader::new(body.reader());
 
         let raw = str::from_utf8(reader.peek(14)?)?;
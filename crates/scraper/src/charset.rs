@@ -0,0 +1,144 @@
+use bytes::Bytes;
+use encoding_rs::{Encoding, UTF_8};
+
+/// Number of bytes searched for an XML declaration or HTML `<meta>` charset
+const PRESCAN_LENGTH: usize = 1024;
+
+/// Transcodes a document to UTF-8. The encoding is detected from, in order of precedence, a byte
+/// order mark, the `charset` parameter of the `Content-Type` header, and the XML declaration or
+/// HTML `<meta>` tag. Documents without any of these are assumed to be UTF-8, and are returned
+/// untouched.
+pub fn to_utf8(body: Bytes, content_type: Option<&str>) -> Bytes {
+    let (encoding, bom_length) = match Encoding::for_bom(&body) {
+        Some(e) => e,
+        None => (
+            content_type
+                .and_then(charset_param)
+                .or_else(|| sniff_charset(&body))
+                .and_then(|e| Encoding::for_label(e.as_bytes()))
+                .unwrap_or(UTF_8),
+            0,
+        ),
+    };
+
+    if encoding == UTF_8 {
+        return body.slice(bom_length..);
+    }
+
+    let (decoded, _) = encoding.decode_without_bom_handling(&body[bom_length..]);
+
+    Bytes::from(decoded.into_owned())
+}
+
+/// Extracts the `charset` parameter of a `Content-Type` header value
+fn charset_param(content_type: &str) -> Option<String> {
+    content_type.split(';').skip(1).find_map(|e| {
+        let (key, value) = e.split_once('=')?;
+
+        key.trim()
+            .eq_ignore_ascii_case("charset")
+            .then(|| value.trim().trim_matches(['"', '\'']).to_owned())
+    })
+}
+
+/// Looks for `encoding=` in an XML declaration, or `charset=` in an HTML `<meta>` tag
+fn sniff_charset(body: &[u8]) -> Option<String> {
+    let prefix = String::from_utf8_lossy(&body[..body.len().min(PRESCAN_LENGTH)]).to_lowercase();
+    let prefix = prefix.trim_start();
+
+    if prefix.starts_with("<?xml") {
+        let declaration = &prefix[..prefix.find("?>")?];
+
+        return attr_value(declaration, "encoding=");
+    }
+
+    prefix
+        .match_indices("<meta")
+        .filter_map(|(i, _)| {
+            let tag = &prefix[i..];
+
+            attr_value(&tag[..tag.find('>')?], "charset=")
+        })
+        .next()
+}
+
+fn attr_value(tag: &str, key: &str) -> Option<String> {
+    let value = &tag[tag.find(key)? + key.len()..];
+    let value = value.trim_start_matches(['"', '\'']);
+    let end = value
+        .find(|e: char| matches!(e, '"' | '\'' | ';' | '>' | '/') || e.is_whitespace())
+        .unwrap_or(value.len());
+
+    (end > 0).then(|| value[..end].to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strips_utf8_bom() {
+        let body = Bytes::from_static(b"\xEF\xBB\xBF<rss/>");
+
+        assert_eq!(to_utf8(body, None), Bytes::from_static(b"<rss/>"));
+    }
+
+    #[test]
+    fn uses_content_type_charset() {
+        let body = Bytes::from_static(b"<p>caf\xE9</p>");
+
+        assert_eq!(
+            to_utf8(body, Some("text/html; charset=\"ISO-8859-1\"")),
+            Bytes::from("<p>café</p>")
+        );
+    }
+
+    #[test]
+    fn content_type_takes_precedence_over_document() {
+        let body = Bytes::from_static(b"<meta charset=\"shift_jis\"><p>caf\xE9</p>");
+
+        assert_eq!(
+            to_utf8(body, Some("text/html; charset=windows-1252")),
+            Bytes::from("<meta charset=\"shift_jis\"><p>café</p>")
+        );
+    }
+
+    #[test]
+    fn sniffs_xml_declaration() {
+        let body = Bytes::from_static(
+            b"<?xml version=\"1.0\" encoding=\"ISO-8859-1\"?><title>caf\xE9</title>",
+        );
+
+        assert_eq!(
+            to_utf8(body, Some("application/rss+xml")),
+            Bytes::from("<?xml version=\"1.0\" encoding=\"ISO-8859-1\"?><title>café</title>")
+        );
+    }
+
+    #[test]
+    fn sniffs_html_meta_tags() {
+        assert_eq!(
+            sniff_charset(b"<html><head><META CHARSET=Windows-1251>").as_deref(),
+            Some("windows-1251")
+        );
+        assert_eq!(
+            sniff_charset(
+                b"<meta name=\"x\"><meta http-equiv=\"Content-Type\" content=\"text/html; charset=euc-jp\">"
+            )
+            .as_deref(),
+            Some("euc-jp")
+        );
+        assert_eq!(sniff_charset(b"<html><body>"), None);
+    }
+
+    #[test]
+    fn leaves_undeclared_and_unknown_encodings_untouched() {
+        let body = Bytes::from_static(b"<p>caf\xC3\xA9</p>");
+
+        assert_eq!(to_utf8(body.clone(), None), body);
+        assert_eq!(
+            to_utf8(body.clone(), Some("text/html; charset=bogus")),
+            body
+        );
+    }
+}
@@ -1,23 +1,34 @@
 use core::str;
 use std::{io::BufReader, str::Utf8Error};
 
-use bytes::Buf;
+use bytes::{Buf, Bytes};
 use chrono::{DateTime, Utc};
 use colette_feed::{
     Feed,
-    atom::{AtomEntry, AtomFeed, AtomLink, AtomRel},
+    atom::{AtomEntry, AtomFeed, AtomLink, AtomRel, AtomTextType},
+    json::{JsonFeed, JsonItem},
+    rdf::{RdfFeed, RdfItem},
     rss::{RssFeed, RssItem},
 };
-use colette_http::HttpClient;
+use colette_http::{HttpClient, Validators};
+use reqwest::{StatusCode, header::CONTENT_TYPE};
 use url::Url;
 
+use crate::{
+    charset::to_utf8,
+    sanitize::{html_excerpt, sanitize_html, text_excerpt},
+};
+
 const RFC2822_WITHOUT_COMMA: &str = "%a %d %b %Y %H:%M:%S %z";
 
 #[async_trait::async_trait]
 pub trait FeedPlugin: Send + Sync {
     fn is_supported(&self, url: &mut Url) -> bool;
 
-    async fn scrape(&self, url: &Url) -> Result<ProcessedFeed, FeedError>;
+    /// Scrapes the feed. Plugins that can only find the feed by fetching the page first replace
+    /// `url` with the URL of the feed. Returning [`FeedError::Unsupported`] hands the original URL
+    /// on to the next plugin.
+    async fn scrape(&self, url: &mut Url) -> Result<ProcessedFeed, FeedError>;
 }
 
 pub struct FeedScraper<HC: HttpClient> {
@@ -31,26 +42,91 @@ impl<HC: HttpClient> FeedScraper<HC> {
     }
 
     pub async fn scrape(&self, url: &mut Url) -> Result<ProcessedFeed, FeedError> {
+        match self.scrape_if_modified(url, &Validators::default()).await? {
+            ConditionalScrape::Modified(processed) => Ok(processed),
+            ConditionalScrape::NotModified { .. } => Err(FeedError::NotModified),
+        }
+    }
+
+    /// Scrapes the feed, sending the validators from a previous scrape as conditional request
+    /// headers.
+    pub async fn scrape_if_modified(
+        &self,
+        url: &mut Url,
+        validators: &Validators,
+    ) -> Result<ConditionalScrape, FeedError> {
         for plugin in self.plugins.iter() {
-            if plugin.is_supported(url) {
-                return plugin.scrape(url).await;
+            let mut plugin_url = url.clone();
+            if !plugin.is_supported(&mut plugin_url) {
+                continue;
             }
+
+            match plugin.scrape(&mut plugin_url).await {
+                Err(FeedError::Unsupported) => continue,
+                result => {
+                    *url = plugin_url;
+                    return result.map(ConditionalScrape::Modified);
+                }
+            }
+        }
+
+        let resp = self.client.get_if_modified(url, validators).await?;
+
+        let max_age = colette_http::max_age(resp.headers()).map(|e| (e.as_secs() / 60) as u32);
+        if resp.status() == StatusCode::NOT_MODIFIED {
+            return Ok(ConditionalScrape::NotModified { max_age });
         }
 
-        let body = self.client.get(url).await?;
-        let mut reader = BufReader::new(body.reader());
+        let validators = Validators::from_headers(resp.headers());
+        let content_type = resp
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|e| e.to_str().ok())
+            .map(ToOwned::to_owned);
+
+        let mut processed = parse_feed(resp.into_body(), content_type.as_deref())?;
+        processed.validators = validators;
+        processed.ttl = processed.ttl.max(max_age);
+
+        Ok(ConditionalScrape::Modified(processed))
+    }
+}
+
+/// Result of a conditional scrape
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone)]
+pub enum ConditionalScrape {
+    Modified(ProcessedFeed),
+    /// The feed has not been modified since the previous scrape. The `max-age` of the response is
+    /// in minutes.
+    NotModified {
+        max_age: Option<u32>,
+    },
+}
 
+/// Parses a raw Atom, RSS, or JSON Feed document, such as a payload pushed by a WebSub hub.
+/// JSON feeds are detected by their content type or by sniffing the start of the body, and
+/// documents in other character encodings are transcoded to UTF-8 first.
+pub fn parse_feed(body: Bytes, content_type: Option<&str>) -> Result<ProcessedFeed, FeedError> {
+    let body = to_utf8(body, content_type);
+
+    let is_json = content_type.is_some_and(|e| e.contains("json"))
+        || body.trim_ascii_start().starts_with(b"{");
+
+    let mut reader = BufReader::new(body.reader());
+
+    if !is_json {
         let raw = str::from_utf8(reader.peek(14)?)?;
         if !raw.contains("<?xml") {
             return Err(FeedError::Unsupported);
         }
+    }
 
-        let extracted = colette_feed::from_reader(reader)
-            .map(ExtractedFeed::from)
-            .map_err(FeedError::Parse)?;
+    let extracted = colette_feed::from_reader(reader)
+        .map(ExtractedFeed::from)
+        .map_err(FeedError::Parse)?;
 
-        extracted.try_into().map_err(FeedError::Postprocess)
-    }
+    extracted.try_into().map_err(FeedError::Postprocess)
 }
 
 #[derive(Debug, Clone, Default)]
@@ -59,6 +135,11 @@ pub struct ExtractedFeed {
     pub title: Option<String>,
     pub description: Option<String>,
     pub refreshed: Option<String>,
+    pub ttl: Option<String>,
+    pub update_period: Option<String>,
+    pub update_frequency: Option<String>,
+    pub hub_url: Option<String>,
+    pub self_url: Option<String>,
     pub entries: Vec<ExtractedFeedEntry>,
 }
 
@@ -68,8 +149,19 @@ pub struct ExtractedFeedEntry {
     pub title: Option<String>,
     pub published: Option<String>,
     pub description: Option<String>,
+    pub content: Option<String>,
+    pub content_type: Option<String>,
     pub author: Option<String>,
     pub thumbnail: Option<String>,
+    pub enclosures: Vec<ExtractedEnclosure>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ExtractedEnclosure {
+    pub url: Option<String>,
+    pub mime_type: Option<String>,
+    pub length: Option<String>,
+    pub duration: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -78,7 +170,14 @@ pub struct ProcessedFeed {
     pub title: String,
     pub description: Option<String>,
     pub refreshed: Option<DateTime<Utc>>,
+    /// Minimum number of minutes between refreshes, as advertised by the publisher
+    pub ttl: Option<u32>,
+    /// WebSub hub advertised by the publisher
+    pub hub_url: Option<Url>,
+    /// Canonical URL of the feed, used as the WebSub topic
+    pub self_url: Option<Url>,
     pub entries: Vec<ProcessedFeedEntry>,
+    pub validators: Validators,
 }
 
 #[derive(Debug, Clone)]
@@ -87,8 +186,43 @@ pub struct ProcessedFeedEntry {
     pub title: String,
     pub published: DateTime<Utc>,
     pub description: Option<String>,
+    /// Full body of the entry, as opposed to the summary in `description`
+    pub content: Option<String>,
+    pub content_type: ContentType,
+    /// Plain-text excerpt of the entry, for use in list views
+    pub excerpt: Option<String>,
     pub author: Option<String>,
     pub thumbnail: Option<Url>,
+    pub enclosures: Vec<ProcessedEnclosure>,
+}
+
+/// Markup of the full content of an entry
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ContentType {
+    Text,
+    #[default]
+    Html,
+    Xhtml,
+}
+
+impl ContentType {
+    fn parse(value: &str) -> Self {
+        match value.trim() {
+            "text" => Self::Text,
+            "xhtml" => Self::Xhtml,
+            _ => Self::Html,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ProcessedEnclosure {
+    pub url: Url,
+    pub mime_type: Option<String>,
+    /// Size of the media file in bytes
+    pub length: Option<u64>,
+    /// Duration of the media file in seconds
+    pub duration: Option<u32>,
 }
 
 impl TryFrom<ExtractedFeed> for ProcessedFeed {
@@ -111,18 +245,45 @@ impl TryFrom<ExtractedFeed> for ProcessedFeed {
             entries.push(entry.try_into()?);
         }
 
+        let ttl = value.ttl.and_then(|e| e.trim().parse::<u32>().ok());
+        let update_interval = value
+            .update_period
+            .as_deref()
+            .and_then(|e| parse_update_interval(e, value.update_frequency.as_deref()));
+
         let feed = Self {
             link,
             title: title.trim().to_owned(),
             description: value.description,
             refreshed: value.refreshed.and_then(|e| parse_date(&e)),
+            ttl: ttl.max(update_interval),
+            hub_url: value.hub_url.and_then(|e| Url::parse(e.trim()).ok()),
+            self_url: value.self_url.and_then(|e| Url::parse(e.trim()).ok()),
             entries,
+            validators: Validators::default(),
         };
 
         Ok(feed)
     }
 }
 
+fn parse_update_interval(period: &str, frequency: Option<&str>) -> Option<u32> {
+    let period = match period.trim() {
+        "hourly" => 60,
+        "daily" => 60 * 24,
+        "weekly" => 60 * 24 * 7,
+        "monthly" => 60 * 24 * 30,
+        "yearly" => 60 * 24 * 365,
+        _ => return None,
+    };
+    let frequency = frequency
+        .and_then(|e| e.trim().parse::<u32>().ok())
+        .filter(|e| *e > 0)
+        .unwrap_or(1);
+
+    Some(period / frequency)
+}
+
 fn parse_date(value: &str) -> Option<DateTime<Utc>> {
     DateTime::parse_from_rfc3339(value)
         .ok()
@@ -153,17 +314,40 @@ impl TryFrom<ExtractedFeedEntry> for ProcessedFeedEntry {
             .as_ref()
             .and_then(|e| Url::parse(e.trim()).ok());
 
+        let content_type = value
+            .content_type
+            .as_deref()
+            .map(ContentType::parse)
+            .unwrap_or_default();
+
+        // Descriptions are treated as HTML regardless of the declared type, as most publishers
+        // embed markup in them
+        let description = value
+            .description
+            .map(|e| sanitize_html(e.trim(), &link))
+            .filter(|e| !e.trim().is_empty());
+        let content = value
+            .content
+            .map(|e| match content_type {
+                ContentType::Text => e.trim().to_owned(),
+                _ => sanitize_html(e.trim(), &link),
+            })
+            .filter(|e| !e.trim().is_empty());
+        let excerpt = description.as_deref().and_then(html_excerpt).or_else(|| {
+            content.as_deref().and_then(|e| match content_type {
+                ContentType::Text => text_excerpt(e),
+                _ => html_excerpt(e),
+            })
+        });
+
         let entry = Self {
             link,
             title: title.trim().to_owned(),
             published,
-            description: value.description.and_then(|e| {
-                if e.is_empty() {
-                    None
-                } else {
-                    Some(e.trim().to_owned())
-                }
-            }),
+            description,
+            content,
+            content_type,
+            excerpt,
             author: value.author.and_then(|e| {
                 if e.is_empty() {
                     None
@@ -172,17 +356,59 @@ impl TryFrom<ExtractedFeedEntry> for ProcessedFeedEntry {
                 }
             }),
             thumbnail,
+            // Malformed enclosures are dropped rather than failing the whole entry
+            enclosures: value
+                .enclosures
+                .into_iter()
+                .filter_map(|e| e.try_into().ok())
+                .collect(),
         };
 
         Ok(entry)
     }
 }
 
+impl TryFrom<ExtractedEnclosure> for ProcessedEnclosure {
+    type Error = PostprocessorError;
+
+    fn try_from(value: ExtractedEnclosure) -> Result<Self, Self::Error> {
+        let Some(Ok(url)) = value.url.as_ref().map(|e| Url::parse(e.trim())) else {
+            return Err(PostprocessorError::Link);
+        };
+
+        let enclosure = Self {
+            url,
+            mime_type: value
+                .mime_type
+                .map(|e| e.trim().to_owned())
+                .filter(|e| !e.is_empty()),
+            length: value
+                .length
+                .and_then(|e| e.trim().parse().ok())
+                .filter(|e| *e > 0),
+            duration: value.duration.as_deref().and_then(parse_duration),
+        };
+
+        Ok(enclosure)
+    }
+}
+
+/// Parses a duration formatted as seconds, `MM:SS`, or `HH:MM:SS`
+fn parse_duration(value: &str) -> Option<u32> {
+    value.trim().split(':').try_fold(0, |acc: u32, part| {
+        let part = part.trim().split('.').next()?.parse::<u32>().ok()?;
+
+        acc.checked_mul(60)?.checked_add(part)
+    })
+}
+
 impl From<Feed> for ExtractedFeed {
     fn from(value: Feed) -> Self {
         match value {
             Feed::Atom(atom) => atom.into(),
             Feed::Rss(rss) => rss.into(),
+            Feed::Rdf(rdf) => rdf.into(),
+            Feed::Json(json) => json.into(),
         }
     }
 }
@@ -190,11 +416,14 @@ impl From<Feed> for ExtractedFeed {
 impl From<AtomFeed> for ExtractedFeed {
     fn from(value: AtomFeed) -> Self {
         Self {
-            link: parse_atom_link(value.link),
+            link: parse_atom_link(&value.link, AtomRel::Alternate),
             title: Some(value.title.text),
             description: value.subtitle.map(|e| e.text),
             refreshed: Some(value.updated),
+            hub_url: parse_atom_link(&value.link, AtomRel::Hub),
+            self_url: parse_atom_link(&value.link, AtomRel::RelSelf),
             entries: value.entry.into_iter().map(Into::into).collect(),
+            ..Default::default()
         }
     }
 }
@@ -202,7 +431,8 @@ impl From<AtomFeed> for ExtractedFeed {
 impl From<AtomEntry> for ExtractedFeedEntry {
     fn from(value: AtomEntry) -> Self {
         let mut title = value.title.text;
-        let mut description = value.summary.or(value.content).map(|e| e.text);
+        let content = value.content;
+        let mut description = value.summary.map(|e| e.text);
         let mut thumbnail = Option::<String>::None;
 
         if let Some(extension) = value.extension
@@ -222,10 +452,14 @@ impl From<AtomEntry> for ExtractedFeedEntry {
         }
 
         Self {
-            link: parse_atom_link(value.link),
+            link: parse_atom_link(&value.link, AtomRel::Alternate),
             title: Some(title),
             published: value.published,
             description,
+            content_type: content
+                .as_ref()
+                .map(|e| atom_text_type(&e.r#type).to_owned()),
+            content: content.map(|e| e.text),
             author: Some(
                 value
                     .author
@@ -235,15 +469,31 @@ impl From<AtomEntry> for ExtractedFeedEntry {
                     .join(","),
             ),
             thumbnail,
+            enclosures: value
+                .link
+                .into_iter()
+                .filter(|e| e.rel == AtomRel::Enclosure)
+                .map(|e| ExtractedEnclosure {
+                    url: Some(e.href),
+                    mime_type: e.r#type,
+                    length: e.length,
+                    duration: None,
+                })
+                .collect(),
         }
     }
 }
 
-fn parse_atom_link(links: Vec<AtomLink>) -> Option<String> {
-    links.into_iter().find_map(|l| match l.rel {
-        AtomRel::Alternate => Some(l.href),
-        _ => None,
-    })
+fn atom_text_type(value: &AtomTextType) -> &'static str {
+    match value {
+        AtomTextType::Text => "text",
+        AtomTextType::Html => "html",
+        AtomTextType::Xhtml => "xhtml",
+    }
+}
+
+fn parse_atom_link(links: &[AtomLink], rel: AtomRel) -> Option<String> {
+    links.iter().find(|l| l.rel == rel).map(|l| l.href.clone())
 }
 
 impl From<RssFeed> for ExtractedFeed {
@@ -253,6 +503,11 @@ impl From<RssFeed> for ExtractedFeed {
             title: Some(value.channel.title),
             description: Some(value.channel.description),
             refreshed: value.channel.last_build_date,
+            ttl: value.channel.ttl,
+            update_period: value.channel.update_period,
+            update_frequency: value.channel.update_frequency,
+            hub_url: parse_atom_link(&value.channel.atom_link, AtomRel::Hub),
+            self_url: parse_atom_link(&value.channel.atom_link, AtomRel::RelSelf),
             entries: value.channel.item.into_iter().map(Into::into).collect(),
         }
     }
@@ -265,8 +520,115 @@ impl From<RssItem> for ExtractedFeedEntry {
             title: Some(value.title),
             published: value.pub_date,
             description: Some(value.description),
+            content: value.content_encoded,
+            content_type: None,
             author: value.author,
             thumbnail: None,
+            enclosures: value
+                .enclosure
+                .into_iter()
+                .map(|e| ExtractedEnclosure {
+                    url: Some(e.url),
+                    mime_type: e.r#type,
+                    length: e.length,
+                    duration: value.itunes_duration.clone(),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<RdfFeed> for ExtractedFeed {
+    fn from(value: RdfFeed) -> Self {
+        Self {
+            link: Some(value.channel.link),
+            title: Some(value.channel.title),
+            description: Some(value.channel.description),
+            refreshed: value.channel.dc_date,
+            entries: value.item.into_iter().map(Into::into).collect(),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<RdfItem> for ExtractedFeedEntry {
+    fn from(value: RdfItem) -> Self {
+        Self {
+            link: Some(value.link),
+            title: Some(value.title),
+            published: value.dc_date,
+            description: value.description,
+            author: value.dc_creator,
+            thumbnail: None,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<JsonFeed> for ExtractedFeed {
+    fn from(value: JsonFeed) -> Self {
+        Self {
+            link: value.home_page_url.or(value.feed_url.clone()),
+            title: Some(value.title),
+            description: value.description,
+            hub_url: value
+                .hubs
+                .into_iter()
+                .find(|e| e.r#type.eq_ignore_ascii_case("websub"))
+                .map(|e| e.url),
+            self_url: value.feed_url,
+            entries: value.items.into_iter().map(Into::into).collect(),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<JsonItem> for ExtractedFeedEntry {
+    fn from(value: JsonItem) -> Self {
+        // Titles are optional in JSON Feed, so fall back to the summary or the first line of the
+        // text content
+        let title = value.title.or(value.summary.clone()).or(value
+            .content_text
+            .as_deref()
+            .and_then(|e| e.lines().next())
+            .map(ToOwned::to_owned));
+
+        let authors = if value.authors.is_empty() {
+            value.author.into_iter().collect()
+        } else {
+            value.authors
+        };
+
+        Self {
+            link: value.url.or(value.external_url),
+            title,
+            published: value.date_published.or(value.date_modified),
+            description: value.summary,
+            content_type: value
+                .content_html
+                .as_ref()
+                .map(|_| "html")
+                .or(value.content_text.as_ref().map(|_| "text"))
+                .map(Into::into),
+            content: value.content_html.or(value.content_text),
+            author: Some(
+                authors
+                    .into_iter()
+                    .filter_map(|e| e.name)
+                    .collect::<Vec<_>>()
+                    .join(","),
+            ),
+            thumbnail: value.image.or(value.banner_image),
+            enclosures: value
+                .attachments
+                .into_iter()
+                .map(|e| ExtractedEnclosure {
+                    url: Some(e.url),
+                    mime_type: Some(e.mime_type),
+                    length: e.size_in_bytes.map(|e| e.to_string()),
+                    duration: e.duration_in_seconds.map(|e| e.to_string()),
+                })
+                .collect(),
         }
     }
 }
@@ -276,6 +638,9 @@ pub enum FeedError {
     #[error("document type not supported")]
     Unsupported,
 
+    #[error("feed not modified")]
+    NotModified,
+
     #[error(transparent)]
     Parse(#[from] colette_feed::Error),
 
@@ -306,3 +671,149 @@ pub enum PostprocessorError {
     #[error("could not process published date")]
     Published,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_item_title_falls_back_to_summary_then_text() {
+        let item = JsonItem {
+            summary: Some("Summary".into()),
+            content_text: Some("First line\nSecond line".into()),
+            ..Default::default()
+        };
+        assert_eq!(
+            ExtractedFeedEntry::from(item).title.as_deref(),
+            Some("Summary")
+        );
+
+        let item = JsonItem {
+            content_text: Some("First line\nSecond line".into()),
+            ..Default::default()
+        };
+        let entry = ExtractedFeedEntry::from(item);
+        assert_eq!(entry.title.as_deref(), Some("First line"));
+        assert_eq!(entry.content_type.as_deref(), Some("text"));
+    }
+
+    #[test]
+    fn json_item_prefers_html_content_and_legacy_author() {
+        let item = JsonItem {
+            external_url: Some("https://example.com/1".into()),
+            content_html: Some("<p>Hello</p>".into()),
+            content_text: Some("Hello".into()),
+            author: Some(colette_feed::json::JsonAuthor {
+                name: Some("Alice".into()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let entry = ExtractedFeedEntry::from(item);
+        assert_eq!(entry.link.as_deref(), Some("https://example.com/1"));
+        assert_eq!(entry.content.as_deref(), Some("<p>Hello</p>"));
+        assert_eq!(entry.content_type.as_deref(), Some("html"));
+        assert_eq!(entry.author.as_deref(), Some("Alice"));
+    }
+
+    #[test]
+    fn atom_entry_without_summary_has_no_description() {
+        let entry = ExtractedFeedEntry::from(AtomEntry {
+            content: Some(colette_feed::atom::AtomText {
+                r#type: AtomTextType::Html,
+                text: "<p>Hello</p>".into(),
+            }),
+            ..Default::default()
+        });
+
+        assert_eq!(entry.description, None);
+        assert_eq!(entry.content.as_deref(), Some("<p>Hello</p>"));
+    }
+
+    #[test]
+    fn json_item_excerpt_comes_from_content_without_summary() {
+        let item = JsonItem {
+            url: Some("https://example.com/1".into()),
+            title: Some("Title".into()),
+            content_html: Some("<p>Hello world</p>".into()),
+            date_published: Some("2025-01-01T00:00:00Z".into()),
+            ..Default::default()
+        };
+
+        let entry = ExtractedFeedEntry::from(item);
+        assert_eq!(entry.description, None);
+
+        let entry = ProcessedFeedEntry::try_from(entry).unwrap();
+        assert_eq!(entry.description, None);
+        assert_eq!(entry.excerpt.as_deref(), Some("Hello world"));
+    }
+
+    #[test]
+    fn json_feed_uses_websub_hub_and_feed_url() {
+        let feed = JsonFeed {
+            title: "Example".into(),
+            feed_url: Some("https://example.com/feed.json".into()),
+            hubs: vec![
+                colette_feed::json::JsonHub {
+                    r#type: "rssCloud".into(),
+                    url: "https://cloud.example.com/".into(),
+                },
+                colette_feed::json::JsonHub {
+                    r#type: "WebSub".into(),
+                    url: "https://hub.example.com/".into(),
+                },
+            ],
+            ..Default::default()
+        };
+
+        let feed = ExtractedFeed::from(feed);
+        assert_eq!(feed.link.as_deref(), Some("https://example.com/feed.json"));
+        assert_eq!(feed.hub_url.as_deref(), Some("https://hub.example.com/"));
+        assert_eq!(
+            feed.self_url.as_deref(),
+            Some("https://example.com/feed.json")
+        );
+    }
+
+    struct StaticClient(&'static str);
+
+    impl HttpClient for StaticClient {
+        async fn send(
+            &self,
+            _request: http::Request<Bytes>,
+        ) -> Result<http::Response<reqwest::Body>, colette_http::Error> {
+            Ok(http::Response::new(self.0.into()))
+        }
+    }
+
+    struct DecliningPlugin;
+
+    #[async_trait::async_trait]
+    impl FeedPlugin for DecliningPlugin {
+        fn is_supported(&self, url: &mut Url) -> bool {
+            url.set_path("/rewritten.rss");
+            true
+        }
+
+        async fn scrape(&self, _url: &mut Url) -> Result<ProcessedFeed, FeedError> {
+            Err(FeedError::Unsupported)
+        }
+    }
+
+    #[tokio::test]
+    async fn falls_through_when_plugin_declines() {
+        let scraper = FeedScraper::new(
+            StaticClient(
+                r#"<?xml version="1.0"?><rss version="2.0"><channel><title>Feed</title><link>https://example.com</link></channel></rss>"#,
+            ),
+            vec![Box::new(DecliningPlugin)],
+        );
+
+        let mut url = Url::parse("https://example.com/feed").unwrap();
+        let feed = scraper.scrape(&mut url).await.unwrap();
+
+        assert_eq!(feed.title, "Feed");
+        assert_eq!(url.as_str(), "https://example.com/feed");
+    }
+}
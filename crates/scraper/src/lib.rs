@@ -2,12 +2,14 @@
 use scraper::{ElementRef, Html, Selector};
 
 pub mod bookmark;
+pub mod charset;
 pub mod feed;
+pub mod sanitize;
 
 #[derive(Debug, Clone)]
 pub enum Node {
     Text,
-    Attr(&'static str),
+    Attr(String),
 }
 
 #[derive(Debug, Clone)]
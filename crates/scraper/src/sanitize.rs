@@ -0,0 +1,203 @@
+use std::{borrow::Cow, sync::LazyLock};
+
+use ammonia::{Builder, UrlRelative};
+use scraper::{ElementRef, Html, Selector};
+use url::Url;
+
+/// Maximum number of characters in a plain-text excerpt
+pub const EXCERPT_MAX_CHARS: usize = 280;
+
+/// Hosts that only serve tracking pixels and analytics beacons
+const TRACKER_HOSTS: &[&str] = &[
+    "ad.doubleclick.net",
+    "feeds.feedburner.com",
+    "google-analytics.com",
+    "pixel.quantserve.com",
+    "pixel.wp.com",
+    "stats.wordpress.com",
+    "www.google-analytics.com",
+];
+
+static IMG_SELECTOR: LazyLock<Selector> = LazyLock::new(|| Selector::parse("img").unwrap());
+
+/// Sanitizes untrusted HTML, keeping only allow-listed tags and attributes. Relative URLs are
+/// resolved against `base`, and tracking pixels and `utm_*` query parameters are removed.
+pub fn sanitize_html(html: &str, base: &Url) -> String {
+    let html = strip_tracking_pixels(html);
+    let filter_base = base.clone();
+
+    Builder::default()
+        .add_tags(&["audio", "source", "video"])
+        .add_tag_attributes("audio", &["controls", "src"])
+        .add_tag_attributes("source", &["src", "type"])
+        .add_tag_attributes("video", &["controls", "height", "poster", "src", "width"])
+        .link_rel(Some("noopener noreferrer nofollow"))
+        .url_relative(UrlRelative::RewriteWithBase(base.to_owned()))
+        .attribute_filter(
+            move |element, attribute, value| match (element, attribute) {
+                (_, "href" | "src") | ("video", "poster") => {
+                    rewrite_url(value, &filter_base).map(Cow::Owned)
+                }
+                _ => Some(value.into()),
+            },
+        )
+        .clean(&html)
+        .to_string()
+}
+
+/// Produces a plain-text excerpt of an HTML fragment, for use in list views
+pub fn html_excerpt(html: &str) -> Option<String> {
+    let fragment = Html::parse_fragment(html);
+
+    truncate_words(
+        fragment
+            .root_element()
+            .text()
+            .flat_map(str::split_whitespace),
+    )
+}
+
+/// Produces an excerpt of plain text, for use in list views
+pub fn text_excerpt(text: &str) -> Option<String> {
+    truncate_words(text.split_whitespace())
+}
+
+fn strip_tracking_pixels(html: &str) -> Cow<'_, str> {
+    let mut fragment = Html::parse_fragment(html);
+
+    let ids = fragment
+        .select(&IMG_SELECTOR)
+        .filter(is_tracking_pixel)
+        .map(|e| e.id())
+        .collect::<Vec<_>>();
+    if ids.is_empty() {
+        return Cow::Borrowed(html);
+    }
+
+    for id in ids {
+        if let Some(mut node) = fragment.tree.get_mut(id) {
+            node.detach();
+        }
+    }
+
+    Cow::Owned(fragment.root_element().inner_html())
+}
+
+fn is_tracking_pixel(e: &ElementRef) -> bool {
+    let is_pixel = |attr: &str| {
+        e.attr(attr)
+            .is_some_and(|e| matches!(e.trim().trim_end_matches("px"), "0" | "1"))
+    };
+    if is_pixel("width") && is_pixel("height") {
+        return true;
+    }
+
+    e.attr("src")
+        .and_then(|e| Url::parse(e.trim()).ok())
+        .is_some_and(|e| e.host_str().is_some_and(|e| TRACKER_HOSTS.contains(&e)))
+}
+
+fn rewrite_url(value: &str, base: &Url) -> Option<String> {
+    let mut url = base.join(value.trim()).ok()?;
+
+    if url.query_pairs().any(|(k, _)| k.starts_with("utm_")) {
+        let pairs = url
+            .query_pairs()
+            .filter(|(k, _)| !k.starts_with("utm_"))
+            .map(|(k, v)| (k.into_owned(), v.into_owned()))
+            .collect::<Vec<_>>();
+
+        if pairs.is_empty() {
+            url.set_query(None);
+        } else {
+            url.query_pairs_mut().clear().extend_pairs(pairs);
+        }
+    }
+
+    Some(url.into())
+}
+
+fn truncate_words<'a>(words: impl Iterator<Item = &'a str>) -> Option<String> {
+    let mut excerpt = String::new();
+
+    for word in words {
+        let len = excerpt.chars().count();
+        if len > 0 && len + 1 + word.chars().count() > EXCERPT_MAX_CHARS {
+            excerpt.push('…');
+            break;
+        }
+        if len > 0 {
+            excerpt.push(' ');
+        }
+
+        excerpt.extend(word.chars().take(EXCERPT_MAX_CHARS));
+    }
+
+    (!excerpt.is_empty()).then_some(excerpt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn base() -> Url {
+        "https://example.com/posts/1".parse().unwrap()
+    }
+
+    #[test]
+    fn removes_scripts_and_event_handlers() {
+        let html = r#"<p onclick="alert(1)">Hello</p><script>alert(2)</script>"#;
+
+        assert_eq!(sanitize_html(html, &base()), "<p>Hello</p>");
+    }
+
+    #[test]
+    fn resolves_relative_urls_and_strips_utm_params() {
+        let html =
+            r#"<a href="../2?utm_source=feed&id=3">Next</a><img src="/a.png?utm_medium=rss">"#;
+
+        assert_eq!(
+            sanitize_html(html, &base()),
+            r#"<a href="https://example.com/2?id=3" rel="noopener noreferrer nofollow">Next</a><img src="https://example.com/a.png">"#
+        );
+    }
+
+    #[test]
+    fn removes_tracking_pixels() {
+        let html = r#"<p>Text</p><img src="https://example.com/p.gif" width="1" height="1px"><img src="https://stats.wordpress.com/b.gif"><img src="https://example.com/photo.jpg" width="1" height="400">"#;
+
+        assert_eq!(
+            sanitize_html(html, &base()),
+            r#"<p>Text</p><img height="400" src="https://example.com/photo.jpg" width="1">"#
+        );
+    }
+
+    #[test]
+    fn keeps_media_elements() {
+        let html = r#"<video controls poster="/poster.jpg"><source src="/v.mp4" type="video/mp4"></video>"#;
+
+        assert_eq!(
+            sanitize_html(html, &base()),
+            r#"<video controls="" poster="https://example.com/poster.jpg"><source src="https://example.com/v.mp4" type="video/mp4"></video>"#
+        );
+    }
+
+    #[test]
+    fn excerpts_collapse_whitespace_and_strip_markup() {
+        assert_eq!(
+            html_excerpt("<p>Hello <b>big</b>\n\n world</p>").as_deref(),
+            Some("Hello big world")
+        );
+        assert_eq!(html_excerpt("<img src=\"a.png\">"), None);
+        assert_eq!(text_excerpt("  \n "), None);
+    }
+
+    #[test]
+    fn excerpts_truncate_on_word_boundaries() {
+        let text = "word ".repeat(100);
+        let excerpt = text_excerpt(&text).unwrap();
+
+        assert!(excerpt.ends_with("word…"));
+        assert!(excerpt.chars().count() <= EXCERPT_MAX_CHARS + 1);
+    }
+}
@@ -3,8 +3,10 @@ use argon2::{
     password_hash::{SaltString, rand_core::OsRng},
 };
 use base64::{Engine as _, engine::general_purpose};
+use hmac::{Hmac, Mac, digest::KeyInit};
 use rand::RngCore as _;
-use sha2::{Digest, Sha256};
+use sha1::Sha1;
+use sha2::{Digest, Sha256, Sha384, Sha512};
 
 pub fn argon2_hash(value: &str) -> Result<String, CryptoError> {
     let salt = SaltString::generate(&mut OsRng);
@@ -54,6 +56,35 @@ pub fn hex_encode(data: &[u8]) -> String {
     hex::encode(data)
 }
 
+pub fn hex_decode(raw: &str) -> Result<Vec<u8>, CryptoError> {
+    hex::decode(raw).map_err(CryptoError::Hex)
+}
+
+pub fn hmac_sha1_verify(key: &[u8], data: &[u8], signature: &[u8]) -> bool {
+    hmac_verify::<Hmac<Sha1>>(key, data, signature)
+}
+
+pub fn hmac_sha256_verify(key: &[u8], data: &[u8], signature: &[u8]) -> bool {
+    hmac_verify::<Hmac<Sha256>>(key, data, signature)
+}
+
+pub fn hmac_sha384_verify(key: &[u8], data: &[u8], signature: &[u8]) -> bool {
+    hmac_verify::<Hmac<Sha384>>(key, data, signature)
+}
+
+pub fn hmac_sha512_verify(key: &[u8], data: &[u8], signature: &[u8]) -> bool {
+    hmac_verify::<Hmac<Sha512>>(key, data, signature)
+}
+
+fn hmac_verify<M: Mac + KeyInit>(key: &[u8], data: &[u8], signature: &[u8]) -> bool {
+    let Ok(mut mac) = <M as Mac>::new_from_slice(key) else {
+        return false;
+    };
+    mac.update(data);
+
+    mac.verify_slice(signature).is_ok()
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum CryptoError {
     #[error(transparent)]
@@ -61,4 +92,7 @@ pub enum CryptoError {
 
     #[error(transparent)]
     Base64(#[from] base64::DecodeError),
+
+    #[error(transparent)]
+    Hex(#[from] hex::FromHexError),
 }
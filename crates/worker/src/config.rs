@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use config::{Config, Environment, FileFormat};
 use url::Url;
 
@@ -53,10 +55,22 @@ pub async fn from_env() -> Result<AppConfig, Box<dyn std::error::Error>> {
         }
     };
 
+    if raw.feed_refresh.min_interval > raw.feed_refresh.max_interval {
+        return Err("feed_refresh.min_interval must not exceed feed_refresh.max_interval".into());
+    }
+
+    if raw.websub.enabled && raw.websub.callback_base_url.is_none() {
+        return Err("websub.callback_base_url must be set when WebSub is enabled".into());
+    }
+
     Ok(AppConfig {
         database,
         smtp: raw.smtp,
         s3,
+        feed_refresh: raw.feed_refresh,
+        websub: raw.websub,
+        http: raw.http,
+        plugins: raw.plugins,
     })
 }
 
@@ -65,6 +79,10 @@ pub struct AppConfig {
     pub database: DatabaseConfig,
     pub smtp: SmtpConfig,
     pub s3: S3Config,
+    pub feed_refresh: FeedRefreshConfig,
+    pub websub: WebSubConfig,
+    pub http: HttpConfig,
+    pub plugins: PluginsConfig,
 }
 
 #[derive(Debug, Clone)]
@@ -80,6 +98,20 @@ pub struct SmtpConfig {
     pub from_address: String,
 }
 
+/// Bounds, in minutes, for adaptive feed refresh intervals
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct FeedRefreshConfig {
+    pub min_interval: u32,
+    pub max_interval: u32,
+}
+
+/// WebSub push subscriptions. The callback base URL must point to the public API server.
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct WebSubConfig {
+    pub enabled: bool,
+    pub callback_base_url: Option<Url>,
+}
+
 #[derive(Debug, Clone)]
 pub struct S3Config {
     pub access_key_id: String,
@@ -91,11 +123,43 @@ pub struct S3Config {
     pub image_base_url: Url,
 }
 
+/// Outgoing request politeness. The interval is in milliseconds, and the retry delay in seconds.
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct HttpConfig {
+    pub user_agent: String,
+    pub max_concurrency_per_host: usize,
+    pub min_request_interval: u64,
+    pub max_retry_delay: u64,
+    pub respect_robots_txt: bool,
+    pub cache: HttpCacheConfig,
+}
+
+/// In-memory response cache, optionally persisted to a directory. The TTL, used for responses
+/// without caching headers, is in seconds.
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct HttpCacheConfig {
+    pub capacity: usize,
+    pub max_body_size: usize,
+    pub default_ttl: u64,
+    pub dir: Option<PathBuf>,
+}
+
+/// Directory of declarative scraper plugin definitions, in TOML or JSON
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+pub struct PluginsConfig {
+    pub dir: Option<PathBuf>,
+}
+
 #[derive(Debug, Clone, serde::Deserialize)]
 struct RawConfig {
     database: RawDatabaseConfig,
     smtp: SmtpConfig,
     s3: RawS3Config,
+    feed_refresh: FeedRefreshConfig,
+    websub: WebSubConfig,
+    http: HttpConfig,
+    #[serde(default)]
+    plugins: PluginsConfig,
 }
 
 #[derive(Debug, Clone, serde::Deserialize)]
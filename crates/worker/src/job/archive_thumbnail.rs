@@ -6,7 +6,7 @@ use std::{
 
 use colette_crud::{ArchiveThumbnailJobData, ThumbnailOperation};
 use colette_handler::{ArchiveThumbnailCommand, ArchiveThumbnailHandler, Handler as _};
-use colette_http::ReqwestClient;
+use colette_http::{CachingClient, PoliteClient, ReqwestClient};
 use colette_queue::Job;
 use colette_repository::PostgresBookmarkRepository;
 use colette_s3::S3ClientImpl;
@@ -16,14 +16,23 @@ use tower::Service;
 use crate::job::Error;
 
 pub struct ArchiveThumbnailJobHandler {
-    archive_thumbnail:
-        Arc<ArchiveThumbnailHandler<PostgresBookmarkRepository, ReqwestClient, S3ClientImpl>>,
+    archive_thumbnail: Arc<
+        ArchiveThumbnailHandler<
+            PostgresBookmarkRepository,
+            CachingClient<PoliteClient<ReqwestClient>>,
+            S3ClientImpl,
+        >,
+    >,
 }
 
 impl ArchiveThumbnailJobHandler {
     pub fn new(
         archive_thumbnail: Arc<
-            ArchiveThumbnailHandler<PostgresBookmarkRepository, ReqwestClient, S3ClientImpl>,
+            ArchiveThumbnailHandler<
+                PostgresBookmarkRepository,
+                CachingClient<PoliteClient<ReqwestClient>>,
+                S3ClientImpl,
+            >,
         >,
     ) -> Self {
         Self { archive_thumbnail }
@@ -0,0 +1,86 @@
+use std::{
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+use colette_handler::{
+    ExtractArticleCommand, ExtractArticleHandler, FetchPendingArticlesHandler,
+    FetchPendingArticlesQuery, Handler as _,
+};
+use colette_http::{CachingClient, PoliteClient, ReqwestClient};
+use colette_queue::Job;
+use colette_repository::PostgresFeedEntryRepository;
+use futures::FutureExt;
+use tower::Service;
+
+use crate::job::Error;
+
+pub struct ExtractArticlesJobHandler {
+    fetch_pending_articles: Arc<FetchPendingArticlesHandler<PostgresFeedEntryRepository>>,
+    extract_article: Arc<
+        ExtractArticleHandler<
+            PostgresFeedEntryRepository,
+            CachingClient<PoliteClient<ReqwestClient>>,
+        >,
+    >,
+}
+
+impl ExtractArticlesJobHandler {
+    pub fn new(
+        fetch_pending_articles: Arc<FetchPendingArticlesHandler<PostgresFeedEntryRepository>>,
+        extract_article: Arc<
+            ExtractArticleHandler<
+                PostgresFeedEntryRepository,
+                CachingClient<PoliteClient<ReqwestClient>>,
+            >,
+        >,
+    ) -> Self {
+        Self {
+            fetch_pending_articles,
+            extract_article,
+        }
+    }
+}
+
+impl Service<Job> for ExtractArticlesJobHandler {
+    type Response = ();
+    type Error = Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, _job: Job) -> Self::Future {
+        let fetch_pending_articles = self.fetch_pending_articles.clone();
+        let extract_article = self.extract_article.clone();
+
+        async move {
+            tracing::debug!("Extracting full articles");
+
+            let feed_entries = fetch_pending_articles
+                .handle(FetchPendingArticlesQuery {})
+                .await
+                .map_err(|e| Error::Service(e.to_string()))?;
+
+            for feed_entry in feed_entries {
+                if let Err(e) = extract_article
+                    .handle(ExtractArticleCommand {
+                        id: feed_entry.id(),
+                    })
+                    .await
+                {
+                    tracing::warn!(
+                        "Failed to extract article at URL: {}: {}",
+                        feed_entry.link().as_str(),
+                        e
+                    );
+                }
+            }
+
+            Ok(())
+        }
+        .boxed()
+    }
+}
@@ -8,7 +8,7 @@ use colette_crud::ScrapeBookmarkJobData;
 use colette_handler::{
     Handler as _, ImportBookmarksJobData, ListBookmarksHandler, ListBookmarksQuery,
 };
-use colette_queue::{Job, JobProducer, TokioJobProducer};
+use colette_queue::{Job, JobProducer, PostgresJobProducer};
 use colette_repository::{PostgresBookmarkRepository, PostgresCollectionRepository};
 use futures::FutureExt;
 use tokio::sync::Mutex;
@@ -19,7 +19,7 @@ use crate::job::Error;
 pub struct ImportBookmarksJobHandler {
     list_bookmarks:
         Arc<ListBookmarksHandler<PostgresBookmarkRepository, PostgresCollectionRepository>>,
-    scrape_bookmark_producer: Arc<Mutex<TokioJobProducer>>,
+    scrape_bookmark_producer: Arc<Mutex<PostgresJobProducer>>,
 }
 
 impl ImportBookmarksJobHandler {
@@ -27,7 +27,7 @@ impl ImportBookmarksJobHandler {
         list_bookmarks: Arc<
             ListBookmarksHandler<PostgresBookmarkRepository, PostgresCollectionRepository>,
         >,
-        scrape_bookmark_producer: Arc<Mutex<TokioJobProducer>>,
+        scrape_bookmark_producer: Arc<Mutex<PostgresJobProducer>>,
     ) -> Self {
         Self {
             list_bookmarks,
@@ -56,6 +56,7 @@ impl Service<Job> for ImportBookmarksJobHandler {
                 .handle(ListBookmarksQuery {
                     collection_id: None,
                     tags: None,
+                    search: None,
                     cursor: None,
                     limit: None,
                     user_id: input_data.user_id.as_inner(),
@@ -70,7 +71,8 @@ impl Service<Job> for ImportBookmarksJobHandler {
                     url: bookmark.link,
                     user_id: input_data.user_id,
                 };
-                let job = Job::create("scrape_bookmark", data)?;
+                let mut job = Job::create("scrape_bookmark", data)?;
+                job.user_id = Some(input_data.user_id.as_inner());
 
                 let mut scrape_bookmark_producer = scrape_bookmark_producer.lock().await;
 
@@ -1,12 +1,16 @@
 pub use archive_thumbnail::*;
+pub use extract_articles::*;
 pub use import_bookmarks::*;
 pub use refresh_feeds::*;
+pub use renew_websub::*;
 pub use scrape_bookmark::*;
 pub use scrape_feed::*;
 
 mod archive_thumbnail;
+mod extract_articles;
 mod import_bookmarks;
 mod refresh_feeds;
+mod renew_websub;
 mod scrape_bookmark;
 mod scrape_feed;
 
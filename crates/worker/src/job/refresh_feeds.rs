@@ -6,7 +6,7 @@ use std::{
 
 use colette_handler::{FetchOutdatedFeedsHandler, FetchOutdatedFeedsQuery, Handler as _};
 use colette_ingestion::ScrapeFeedJobData;
-use colette_queue::{Job, JobProducer, TokioJobProducer};
+use colette_queue::{Job, JobProducer, PostgresJobProducer};
 use colette_repository::PostgresFeedRepository;
 use futures::FutureExt;
 use tokio::sync::Mutex;
@@ -16,13 +16,13 @@ use crate::job::Error;
 
 pub struct RefreshFeedsJobHandler {
     fetch_outdated_feeds: Arc<FetchOutdatedFeedsHandler<PostgresFeedRepository>>,
-    scrape_feed_producer: Arc<Mutex<TokioJobProducer>>,
+    scrape_feed_producer: Arc<Mutex<PostgresJobProducer>>,
 }
 
 impl RefreshFeedsJobHandler {
     pub fn new(
         fetch_outdated_feeds: Arc<FetchOutdatedFeedsHandler<PostgresFeedRepository>>,
-        scrape_feed_producer: Arc<Mutex<TokioJobProducer>>,
+        scrape_feed_producer: Arc<Mutex<PostgresJobProducer>>,
     ) -> Self {
         Self {
             fetch_outdated_feeds,
@@ -57,7 +57,9 @@ impl Service<Job> for RefreshFeedsJobHandler {
                     feed_id: feed.id(),
                     source_url: feed.source_url().to_owned(),
                 };
-                let job = Job::create("scrape_feed", data)?;
+                let dedupe_key = data.dedupe_key();
+                let mut job = Job::create("scrape_feed", data)?;
+                job.dedupe_key = Some(dedupe_key);
 
                 let mut scrape_feed_producer = scrape_feed_producer.lock().await;
 
@@ -0,0 +1,77 @@
+use std::{
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+use colette_handler::{
+    FetchWebSubRenewableFeedsHandler, FetchWebSubRenewableFeedsQuery, Handler as _,
+    SubscribeWebSubCommand, SubscribeWebSubHandler,
+};
+use colette_http::{CachingClient, PoliteClient, ReqwestClient};
+use colette_queue::Job;
+use colette_repository::PostgresFeedRepository;
+use futures::FutureExt;
+use tower::Service;
+
+use crate::job::Error;
+
+pub struct RenewWebSubJobHandler {
+    fetch_websub_renewable_feeds: Arc<FetchWebSubRenewableFeedsHandler<PostgresFeedRepository>>,
+    subscribe_websub: Arc<
+        SubscribeWebSubHandler<PostgresFeedRepository, CachingClient<PoliteClient<ReqwestClient>>>,
+    >,
+}
+
+impl RenewWebSubJobHandler {
+    pub fn new(
+        fetch_websub_renewable_feeds: Arc<FetchWebSubRenewableFeedsHandler<PostgresFeedRepository>>,
+        subscribe_websub: Arc<
+            SubscribeWebSubHandler<
+                PostgresFeedRepository,
+                CachingClient<PoliteClient<ReqwestClient>>,
+            >,
+        >,
+    ) -> Self {
+        Self {
+            fetch_websub_renewable_feeds,
+            subscribe_websub,
+        }
+    }
+}
+
+impl Service<Job> for RenewWebSubJobHandler {
+    type Response = ();
+    type Error = Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, _job: Job) -> Self::Future {
+        let fetch_websub_renewable_feeds = self.fetch_websub_renewable_feeds.clone();
+        let subscribe_websub = self.subscribe_websub.clone();
+
+        async move {
+            tracing::debug!("Renewing WebSub subscriptions");
+
+            let feeds = fetch_websub_renewable_feeds
+                .handle(FetchWebSubRenewableFeedsQuery {})
+                .await
+                .map_err(|e| Error::Service(e.to_string()))?;
+
+            for feed in feeds {
+                if let Err(e) = subscribe_websub
+                    .handle(SubscribeWebSubCommand { id: feed.id() })
+                    .await
+                {
+                    tracing::warn!("Failed to subscribe to WebSub hub: {}", e);
+                }
+            }
+
+            Ok(())
+        }
+        .boxed()
+    }
+}
@@ -6,7 +6,7 @@ use std::{
 
 use colette_crud::ScrapeBookmarkJobData;
 use colette_handler::{Handler as _, RefreshBookmarkCommand, RefreshBookmarkHandler};
-use colette_http::ReqwestClient;
+use colette_http::{CachingClient, PoliteClient, ReqwestClient};
 use colette_queue::Job;
 use colette_repository::PostgresBookmarkRepository;
 use futures::FutureExt;
@@ -15,12 +15,22 @@ use tower::Service;
 use crate::job::Error;
 
 pub struct ScrapeBookmarkJobHandler {
-    refresh_bookmark: Arc<RefreshBookmarkHandler<PostgresBookmarkRepository, ReqwestClient>>,
+    refresh_bookmark: Arc<
+        RefreshBookmarkHandler<
+            PostgresBookmarkRepository,
+            CachingClient<PoliteClient<ReqwestClient>>,
+        >,
+    >,
 }
 
 impl ScrapeBookmarkJobHandler {
     pub fn new(
-        refresh_bookmark: Arc<RefreshBookmarkHandler<PostgresBookmarkRepository, ReqwestClient>>,
+        refresh_bookmark: Arc<
+            RefreshBookmarkHandler<
+                PostgresBookmarkRepository,
+                CachingClient<PoliteClient<ReqwestClient>>,
+            >,
+        >,
     ) -> Self {
         Self { refresh_bookmark }
     }
@@ -5,22 +5,34 @@ use std::{
 };
 
 use colette_handler::{Handler as _, RefreshFeedCommand, RefreshFeedHandler};
-use colette_http::ReqwestClient;
+use colette_http::{CachingClient, PoliteClient, ReqwestClient};
 use colette_ingestion::ScrapeFeedJobData;
 use colette_queue::Job;
-use colette_repository::PostgresFeedRepository;
+use colette_repository::{PostgresFeedRepository, PostgresRuleRepository};
 use futures::FutureExt;
 use tower::Service;
 
 use crate::job::Error;
 
 pub struct ScrapeFeedJobHandler {
-    refresh_feed: Arc<RefreshFeedHandler<PostgresFeedRepository, ReqwestClient>>,
+    refresh_feed: Arc<
+        RefreshFeedHandler<
+            PostgresFeedRepository,
+            PostgresRuleRepository,
+            CachingClient<PoliteClient<ReqwestClient>>,
+        >,
+    >,
 }
 
 impl ScrapeFeedJobHandler {
     pub fn new(
-        refresh_feed: Arc<RefreshFeedHandler<PostgresFeedRepository, ReqwestClient>>,
+        refresh_feed: Arc<
+            RefreshFeedHandler<
+                PostgresFeedRepository,
+                PostgresRuleRepository,
+                CachingClient<PoliteClient<ReqwestClient>>,
+            >,
+        >,
     ) -> Self {
         Self { refresh_feed }
     }
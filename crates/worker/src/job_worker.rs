@@ -1,28 +1,72 @@
-use colette_queue::{Job, JobConsumer as _, TokioJobConsumer};
+use std::time::Duration;
+
+use colette_queue::{Job, JobConsumer};
 use tower::{Service as _, ServiceExt as _, util::BoxService};
 
 use crate::job::Error;
 
-pub struct JobWorker {
-    job_consumer: TokioJobConsumer,
+const ERROR_BACKOFF: Duration = Duration::from_secs(5);
+
+pub struct JobWorker<JC: JobConsumer> {
+    job_consumer: JC,
     job_handler: BoxService<Job, (), Error>,
 }
 
-impl JobWorker {
-    pub fn new(job_consumer: TokioJobConsumer, job_handler: BoxService<Job, (), Error>) -> Self {
+impl<JC: JobConsumer> JobWorker<JC> {
+    pub fn new(job_consumer: JC, job_handler: BoxService<Job, (), Error>) -> Self {
         Self {
             job_consumer,
             job_handler,
         }
     }
 
-    pub async fn start(&mut self) -> Result<(), Error> {
-        while let Some(job) = self.job_consumer.pop().await? {
-            if let Err(e) = self.job_handler.ready().await?.call(job).await {
-                tracing::error!("{e}");
+    pub async fn start(&mut self) {
+        loop {
+            let job = match self.job_consumer.pop().await {
+                Ok(Some(job)) => job,
+                Ok(None) => break,
+                Err(e) => {
+                    tracing::error!("failed to pop job: {e}");
+                    tokio::time::sleep(ERROR_BACKOFF).await;
+
+                    continue;
+                }
+            };
+
+            let job_id = job.id;
+            let attempts = job.attempts;
+            let is_exhausted = job.is_exhausted();
+
+            let result = match self.job_handler.ready().await {
+                Ok(job_handler) => job_handler.call(job).await,
+                Err(e) => Err(e),
+            };
+
+            let result = match result {
+                Ok(()) => self.job_consumer.complete(job_id, attempts).await,
+                Err(e) => {
+                    if is_exhausted {
+                        tracing::error!("job {job_id} moved to dead-letter queue: {e}");
+                    } else {
+                        tracing::warn!("job {job_id} failed, retrying: {e}");
+                    }
+
+                    self.job_consumer
+                        .fail(job_id, attempts, e.to_string())
+                        .await
+                }
+            };
+
+            match result {
+                Ok(()) => {}
+                // The job went stale and another run took it over, which now owns its status
+                Err(e @ colette_queue::Error::LostOwnership(_)) => tracing::warn!("{e}"),
+                // The job stays running and is picked up again once it is stale
+                Err(e) => {
+                    tracing::error!("failed to update status of job {job_id}: {e}");
+                    tokio::time::sleep(ERROR_BACKOFF).await;
+                }
             }
         }
-
-        Ok(())
     }
 }
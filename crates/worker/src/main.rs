@@ -1,9 +1,10 @@
-use std::{error::Error, sync::Arc};
+use std::{error::Error, sync::Arc, time::Duration};
 
 use colette_handler::*;
-use colette_http::ReqwestClient;
-use colette_plugins::{register_bookmark_plugins, register_feed_plugins};
-use colette_queue::TokioQueue;
+use colette_http::{CacheOptions, CachingClient, PoliteClient, PolitenessOptions, ReqwestClient};
+use colette_ingestion::RefreshIntervalBounds;
+use colette_plugins::{DeclarativePlugins, register_bookmark_plugins, register_feed_plugins};
+use colette_queue::{PostgresJobConsumer, PostgresJobProducer};
 use colette_repository::*;
 use colette_s3::S3ClientImpl;
 use colette_scraper::{bookmark::BookmarkScraper, feed::FeedScraper};
@@ -16,8 +17,9 @@ use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 use crate::{
     cron_worker::CronWorker,
     job::{
-        ArchiveThumbnailJobHandler, ImportBookmarksJobHandler, RefreshFeedsJobHandler,
-        ScrapeBookmarkJobHandler, ScrapeFeedJobHandler,
+        ArchiveThumbnailJobHandler, ExtractArticlesJobHandler, ImportBookmarksJobHandler,
+        RefreshFeedsJobHandler, RenewWebSubJobHandler, ScrapeBookmarkJobHandler,
+        ScrapeFeedJobHandler,
     },
     job_worker::JobWorker,
 };
@@ -54,8 +56,32 @@ async fn main() -> Result<(), Box<dyn Error>> {
     let bookmark_repository = PostgresBookmarkRepository::new(pool.clone());
     let collection_repository = PostgresCollectionRepository::new(pool.clone());
 
-    let reqwest_client = reqwest::Client::builder().build()?;
-    let http_client = ReqwestClient::new(reqwest_client.clone());
+    let reqwest_client = reqwest::Client::builder()
+        .user_agent(app_config.http.user_agent.clone())
+        .build()?;
+    let http_client = CachingClient::new(
+        PoliteClient::new(
+            ReqwestClient::new(reqwest_client),
+            PolitenessOptions {
+                user_agent: app_config.http.user_agent,
+                max_concurrency_per_host: app_config.http.max_concurrency_per_host,
+                min_request_interval: Duration::from_millis(app_config.http.min_request_interval),
+                max_retry_delay: Duration::from_secs(app_config.http.max_retry_delay),
+                respect_robots_txt: app_config.http.respect_robots_txt,
+            },
+        )?,
+        CacheOptions {
+            capacity: app_config.http.cache.capacity,
+            max_body_size: app_config.http.cache.max_body_size,
+            default_ttl: Duration::from_secs(app_config.http.cache.default_ttl),
+            dir: app_config.http.cache.dir,
+        },
+    );
+
+    let declarative_plugins = match app_config.plugins.dir {
+        Some(ref dir) => DeclarativePlugins::load(dir)?,
+        None => DeclarativePlugins::default(),
+    };
 
     let stmp_client = SmtpClientImpl::create(SmtpConfig {
         host: app_config.smtp.host,
@@ -74,21 +100,25 @@ async fn main() -> Result<(), Box<dyn Error>> {
     })
     .await?;
 
-    let (scrape_feed_producer, scrape_feed_consumer) = TokioQueue::new().split();
-    let (scrape_bookmark_producer, scrape_bookmark_consumer) = TokioQueue::new().split();
-    let (archive_thumbnail_producer, archive_thumbnail_consumer) = TokioQueue::new().split();
-    let (import_bookmarks_producer, import_bookmarks_consumer) = TokioQueue::new().split();
+    let job_producer = PostgresJobProducer::new(pool.clone());
+
+    let scrape_feed_consumer = PostgresJobConsumer::new(pool.clone(), "scrape_feed");
+    let scrape_bookmark_consumer = PostgresJobConsumer::new(pool.clone(), "scrape_bookmark");
+    let archive_thumbnail_consumer = PostgresJobConsumer::new(pool.clone(), "archive_thumbnail");
+    let import_bookmarks_consumer = PostgresJobConsumer::new(pool.clone(), "import_bookmarks");
 
     let bookmark_scraper = Arc::new(BookmarkScraper::new(
         http_client.clone(),
-        register_bookmark_plugins(reqwest_client.clone()),
+        register_bookmark_plugins(http_client.clone(), &declarative_plugins),
     ));
 
     let feed_repository = PostgresFeedRepository::new(pool.clone());
+    let feed_entry_repository = PostgresFeedEntryRepository::new(pool.clone());
+    let rule_repository = PostgresRuleRepository::new(pool.clone());
 
     let feed_scraper = Arc::new(FeedScraper::new(
         http_client.clone(),
-        register_feed_plugins(reqwest_client),
+        register_feed_plugins(http_client.clone(), &declarative_plugins),
     ));
 
     let list_bookmarks_handler = Arc::new(ListBookmarksHandler::new(
@@ -106,13 +136,31 @@ async fn main() -> Result<(), Box<dyn Error>> {
     ));
     let fetch_outdated_feeds_handler =
         Arc::new(FetchOutdatedFeedsHandler::new(feed_repository.clone()));
+    let fetch_websub_renewable_feeds_handler = Arc::new(FetchWebSubRenewableFeedsHandler::new(
+        feed_repository.clone(),
+    ));
+    let fetch_pending_articles_handler = Arc::new(FetchPendingArticlesHandler::new(
+        feed_entry_repository.clone(),
+    ));
+    let extract_article_handler = Arc::new(ExtractArticleHandler::new(
+        feed_entry_repository,
+        http_client.clone(),
+    ));
 
     let mut scrape_feed_worker = JobWorker::new(
         scrape_feed_consumer,
         ServiceBuilder::new()
             .concurrency_limit(5)
             .service(ScrapeFeedJobHandler::new(Arc::new(
-                RefreshFeedHandler::new(feed_repository, feed_scraper),
+                RefreshFeedHandler::new(
+                    feed_repository.clone(),
+                    rule_repository,
+                    feed_scraper,
+                    RefreshIntervalBounds {
+                        min: app_config.feed_refresh.min_interval,
+                        max: app_config.feed_refresh.max_interval,
+                    },
+                ),
             )))
             .boxed(),
     );
@@ -135,7 +183,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
         ServiceBuilder::new()
             .service(ImportBookmarksJobHandler::new(
                 list_bookmarks_handler,
-                Arc::new(Mutex::new(scrape_bookmark_producer)),
+                Arc::new(Mutex::new(job_producer.clone())),
             ))
             .boxed(),
     );
@@ -147,7 +195,49 @@ async fn main() -> Result<(), Box<dyn Error>> {
             ServiceBuilder::new()
                 .service(RefreshFeedsJobHandler::new(
                     fetch_outdated_feeds_handler,
-                    Arc::new(Mutex::new(scrape_feed_producer)),
+                    Arc::new(Mutex::new(job_producer)),
+                ))
+                .boxed(),
+        );
+
+        worker.start().await;
+    };
+
+    let start_extract_articles_worker = async {
+        let mut worker = CronWorker::new(
+            "extract_articles",
+            "0 */5 * * * *".parse().unwrap(),
+            ServiceBuilder::new()
+                .service(ExtractArticlesJobHandler::new(
+                    fetch_pending_articles_handler,
+                    extract_article_handler,
+                ))
+                .boxed(),
+        );
+
+        worker.start().await;
+    };
+
+    let start_renew_websub_worker = async {
+        let Some(callback_base_url) = app_config
+            .websub
+            .callback_base_url
+            .filter(|_| app_config.websub.enabled)
+        else {
+            return;
+        };
+
+        let mut worker = CronWorker::new(
+            "renew_websub",
+            "0 0 * * * *".parse().unwrap(),
+            ServiceBuilder::new()
+                .service(RenewWebSubJobHandler::new(
+                    fetch_websub_renewable_feeds_handler,
+                    Arc::new(SubscribeWebSubHandler::new(
+                        feed_repository,
+                        http_client,
+                        callback_base_url,
+                    )),
                 ))
                 .boxed(),
         );
@@ -160,7 +250,9 @@ async fn main() -> Result<(), Box<dyn Error>> {
         scrape_bookmark_worker.start(),
         archive_thumbnail_worker.start(),
         import_bookmarks_worker.start(),
-        start_refresh_feeds_worker
+        start_refresh_feeds_worker,
+        start_extract_articles_worker,
+        start_renew_websub_worker
     );
 
     Ok(())
@@ -0,0 +1,13 @@
+CREATE TABLE jobs (
+  id UUID NOT NULL PRIMARY KEY,
+  job_type TEXT NOT NULL,
+  data_json JSONB NOT NULL,
+  status TEXT NOT NULL,
+  message TEXT,
+  started_at TIMESTAMPTZ,
+  completed_at TIMESTAMPTZ,
+  created_at TIMESTAMPTZ NOT NULL,
+  updated_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX jobs_job_type_status_idx ON jobs (job_type, status, created_at);
@@ -0,0 +1,15 @@
+ALTER TABLE jobs
+ADD COLUMN attempts INTEGER NOT NULL DEFAULT 0,
+ADD COLUMN max_attempts INTEGER NOT NULL DEFAULT 5,
+ADD COLUMN backoff_secs INTEGER NOT NULL DEFAULT 30,
+ADD COLUMN run_at TIMESTAMPTZ NOT NULL DEFAULT now();
+
+DROP INDEX jobs_job_type_status_idx;
+
+CREATE INDEX jobs_job_type_status_idx ON jobs (job_type, status, run_at);
+
+UPDATE jobs
+SET
+  status = 'dead'
+WHERE
+  status = 'failed';
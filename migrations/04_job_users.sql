@@ -0,0 +1,4 @@
+ALTER TABLE jobs
+ADD COLUMN user_id UUID REFERENCES users (id) ON DELETE CASCADE;
+
+CREATE INDEX jobs_user_id_created_at_idx ON jobs (user_id, created_at);
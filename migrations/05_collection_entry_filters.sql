@@ -0,0 +1,2 @@
+ALTER TABLE collections
+ADD COLUMN entry_filter_json JSONB;
@@ -0,0 +1,13 @@
+ALTER TABLE feed_entries
+ADD COLUMN search_vector TSVECTOR GENERATED ALWAYS AS (
+  setweight(to_tsvector('english', coalesce(title, '')), 'A') || setweight(to_tsvector('english', coalesce(description, '')), 'B') || setweight(to_tsvector('english', coalesce(author, '')), 'C')
+) STORED;
+
+CREATE INDEX feed_entries_search_vector_idx ON feed_entries USING GIN (search_vector);
+
+ALTER TABLE bookmarks
+ADD COLUMN search_vector TSVECTOR GENERATED ALWAYS AS (
+  setweight(to_tsvector('english', coalesce(title, '')), 'A') || setweight(to_tsvector('english', coalesce(author, '')), 'C')
+) STORED;
+
+CREATE INDEX bookmarks_search_vector_idx ON bookmarks USING GIN (search_vector);
@@ -0,0 +1,10 @@
+ALTER TABLE feeds
+ADD COLUMN failure_count INTEGER NOT NULL DEFAULT 0,
+ADD COLUMN last_error TEXT;
+
+UPDATE feeds
+SET
+  status = 'failing',
+  failure_count = 1
+WHERE
+  status = 'failed';
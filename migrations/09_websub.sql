@@ -0,0 +1,5 @@
+ALTER TABLE feeds
+ADD COLUMN hub_url TEXT,
+ADD COLUMN topic_url TEXT,
+ADD COLUMN websub_secret TEXT,
+ADD COLUMN websub_lease_expires_at TIMESTAMPTZ;
@@ -0,0 +1,8 @@
+CREATE TABLE feed_entry_enclosures (
+  feed_entry_id UUID NOT NULL REFERENCES feed_entries (id) ON DELETE CASCADE,
+  url TEXT NOT NULL,
+  mime_type TEXT,
+  length BIGINT,
+  duration_sec INTEGER,
+  PRIMARY KEY (feed_entry_id, url)
+);
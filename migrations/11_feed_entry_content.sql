@@ -0,0 +1,3 @@
+ALTER TABLE feed_entries
+ADD COLUMN content TEXT,
+ADD COLUMN content_type TEXT;
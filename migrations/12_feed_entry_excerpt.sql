@@ -0,0 +1,2 @@
+ALTER TABLE feed_entries
+ADD COLUMN excerpt TEXT;
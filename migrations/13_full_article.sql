@@ -0,0 +1,6 @@
+ALTER TABLE subscriptions
+ADD COLUMN fetch_full_article BOOLEAN NOT NULL DEFAULT FALSE;
+
+ALTER TABLE feed_entries
+ADD COLUMN article_content TEXT,
+ADD COLUMN article_fetched_at TIMESTAMPTZ;
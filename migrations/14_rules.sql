@@ -0,0 +1,26 @@
+CREATE TABLE rules (
+  id UUID NOT NULL PRIMARY KEY,
+  title TEXT NOT NULL,
+  condition_json JSONB NOT NULL,
+  actions_json JSONB NOT NULL,
+  enabled BOOLEAN NOT NULL,
+  user_id UUID NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+  created_at TIMESTAMPTZ NOT NULL,
+  updated_at TIMESTAMPTZ NOT NULL,
+  UNIQUE (user_id, title)
+);
+
+CREATE TABLE entry_tags (
+  feed_entry_id UUID NOT NULL REFERENCES feed_entries (id) ON DELETE CASCADE,
+  tag_id UUID NOT NULL REFERENCES tags (id) ON DELETE CASCADE,
+  created_at TIMESTAMPTZ NOT NULL,
+  updated_at TIMESTAMPTZ NOT NULL,
+  PRIMARY KEY (feed_entry_id, tag_id)
+);
+
+CREATE TABLE hidden_entries (
+  feed_entry_id UUID NOT NULL REFERENCES feed_entries (id) ON DELETE CASCADE,
+  user_id UUID NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+  created_at TIMESTAMPTZ NOT NULL,
+  PRIMARY KEY (user_id, feed_entry_id)
+);
@@ -0,0 +1,15 @@
+CREATE TABLE starred_entries (
+  feed_entry_id UUID NOT NULL REFERENCES feed_entries (id) ON DELETE CASCADE,
+  user_id UUID NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+  created_at TIMESTAMPTZ NOT NULL,
+  PRIMARY KEY (user_id, feed_entry_id)
+);
+
+CREATE TABLE entry_notes (
+  feed_entry_id UUID NOT NULL REFERENCES feed_entries (id) ON DELETE CASCADE,
+  user_id UUID NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+  content TEXT NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL,
+  updated_at TIMESTAMPTZ NOT NULL,
+  PRIMARY KEY (user_id, feed_entry_id)
+);
@@ -0,0 +1,6 @@
+ALTER TABLE jobs
+ADD COLUMN dedupe_key TEXT;
+
+CREATE UNIQUE INDEX jobs_dedupe_key_idx ON jobs (dedupe_key)
+WHERE
+  status IN ('pending', 'running');
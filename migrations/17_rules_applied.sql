@@ -0,0 +1,10 @@
+ALTER TABLE feed_entries
+ADD COLUMN rules_applied_at TIMESTAMPTZ;
+
+UPDATE feed_entries
+SET
+  rules_applied_at = created_at;
+
+CREATE INDEX feed_entries_rules_pending_idx ON feed_entries (feed_id)
+WHERE
+  rules_applied_at IS NULL;
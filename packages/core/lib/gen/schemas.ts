@@ -25,6 +25,7 @@ import type {
   CollectionUpdate,
   Config,
   DateOp,
+  EntryCounts,
   Feed,
   FeedDetect,
   FeedDetected,
@@ -32,6 +33,7 @@ import type {
   FeedScrape,
   LinkBookmarkTags,
   LinkSubscriptionTags,
+  ListInclude,
   LoginPayload,
   OidcConfig,
   PaginatedApiKey,
@@ -711,6 +713,12 @@ export const collectionSchema = z.object({
   id: z.string().uuid(),
   title: z.string(),
   filter: z.lazy(() => bookmarkFilterSchema),
+  entryCounts: z
+    .lazy(() => entryCountsSchema)
+    .describe(
+      'Entry counts of the collection, only present if requested and the collection has an entry\nfilter',
+    )
+    .optional(),
   createdAt: z.string().datetime(),
   updatedAt: z.string().datetime(),
 }) as unknown as ToZod<Collection>
@@ -757,6 +765,18 @@ export const dateOpSchema = z.union([
   }),
 ]) as unknown as ToZod<DateOp>
 
+/**
+ * @description Counts of the entries associated with a subscription, tag or collection
+ */
+export const entryCountsSchema = z
+  .object({
+    total: z.number().int().describe('Number of entries'),
+    unread: z.number().int().describe('Number of unread entries'),
+  })
+  .describe(
+    'Counts of the entries associated with a subscription, tag or collection',
+  ) as unknown as ToZod<EntryCounts>
+
 /**
  * @description RSS feed
  */
@@ -864,6 +884,15 @@ export const linkSubscriptionTagsSchema = z
     'Action to link tags to a user subscription',
   ) as unknown as ToZod<LinkSubscriptionTags>
 
+/**
+ * @description Optional data to include in list responses
+ */
+export const listIncludeSchema = z
+  .enum(['counts'])
+  .describe(
+    'Optional data to include in list responses',
+  ) as unknown as ToZod<ListInclude>
+
 export const loginPayloadSchema = z.object({
   email: z.string().email(),
   password: z.string().min(1),
@@ -1226,6 +1255,14 @@ export const subscriptionSchema = z
       .string()
       .uuid()
       .describe('Unique identifier of the associated RSS feed'),
+    unreadCount: z
+      .number()
+      .int()
+      .describe('Count of unread entries associated with the subscription'),
+    entryCounts: z
+      .lazy(() => entryCountsSchema)
+      .describe('Entry counts of the subscription, only present if requested')
+      .optional(),
     createdAt: z
       .string()
       .datetime()
@@ -1424,6 +1461,12 @@ export const tagSchema = z
     title: z
       .string()
       .describe('Human-readable name of the tag, unique per user'),
+    entryCounts: z
+      .lazy(() => entryCountsSchema)
+      .describe(
+        'Entry counts of the subscriptions and entries linked to the tag, only present if requested',
+      )
+      .optional(),
     createdAt: z
       .string()
       .datetime()
@@ -2410,6 +2453,10 @@ export const exportBookmarksMutationResponseSchema = z.lazy(
 export const listCollectionsQueryParamsSchema = z
   .object({
     cursor: z.string().describe('Pagination cursor').optional(),
+    include: z
+      .array(z.lazy(() => listIncludeSchema))
+      .describe('Optional data to include with each item')
+      .optional(),
   })
   .optional() as unknown as ToZod<ListCollectionsQueryParams>
 
@@ -3038,6 +3085,10 @@ export const listSubscriptionsQueryParamsSchema = z
       .boolean()
       .describe('Whether to include the tags linked to the subscription')
       .optional(),
+    include: z
+      .array(z.lazy(() => listIncludeSchema))
+      .describe('Optional data to include with each item')
+      .optional(),
   })
   .optional() as unknown as ToZod<ListSubscriptionsQueryParams>
 
@@ -3581,6 +3632,10 @@ export const listTagsQueryParamsSchema = z
         'Whether to include the count of bookmarks the tag is linked to',
       )
       .optional(),
+    include: z
+      .array(z.lazy(() => listIncludeSchema))
+      .describe('Optional data to include with each item')
+      .optional(),
   })
   .optional() as unknown as ToZod<ListTagsQueryParams>
 
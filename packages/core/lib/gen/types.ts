@@ -372,6 +372,12 @@ export type Collection = {
    */
   title: string
   filter: BookmarkFilter
+  /**
+   * @description Entry counts of the collection, only present if requested and the collection has an entry
+   * filter
+   * @type object | undefined
+   */
+  entryCounts?: EntryCounts
   /**
    * @type string, date-time
    */
@@ -456,6 +462,22 @@ export type DateOp =
       inLast: number
     }
 
+/**
+ * @description Counts of the entries associated with a subscription, tag or collection
+ */
+export type EntryCounts = {
+  /**
+   * @description Number of entries
+   * @type integer, int64
+   */
+  total: number
+  /**
+   * @description Number of unread entries
+   * @type integer, int64
+   */
+  unread: number
+}
+
 /**
  * @description RSS feed
  */
@@ -603,6 +625,18 @@ export type LinkSubscriptionTags = {
   tagIds: string[]
 }
 
+export const listIncludeEnum = {
+  counts: 'counts',
+} as const
+
+export type ListIncludeEnum =
+  (typeof listIncludeEnum)[keyof typeof listIncludeEnum]
+
+/**
+ * @description Optional data to include in list responses
+ */
+export type ListInclude = ListIncludeEnum
+
 export type LoginPayload = {
   /**
    * @type string, email
@@ -1021,6 +1055,16 @@ export type Subscription = {
    * @type string, uuid
    */
   feedId: string
+  /**
+   * @description Count of unread entries associated with the subscription
+   * @type integer, int64
+   */
+  unreadCount: number
+  /**
+   * @description Entry counts of the subscription, only present if requested
+   * @type object | undefined
+   */
+  entryCounts?: EntryCounts
   /**
    * @description Timestamp at which the subscription was created
    * @type string, date-time
@@ -1240,6 +1284,11 @@ export type Tag = {
    * @type string
    */
   title: string
+  /**
+   * @description Entry counts of the subscriptions and entries linked to the tag, only present if requested
+   * @type object | undefined
+   */
+  entryCounts?: EntryCounts
   /**
    * @description Timestamp at which the tag was created
    * @type string, date-time
@@ -2168,6 +2217,11 @@ export type ListCollectionsQueryParams = {
    * @type string | undefined
    */
   cursor?: string
+  /**
+   * @description Optional data to include with each item
+   * @type array | undefined
+   */
+  include?: ListInclude[]
 }
 
 /**
@@ -2750,6 +2804,11 @@ export type ListSubscriptionsQueryParams = {
    * @type boolean | undefined
    */
   withTags?: boolean
+  /**
+   * @description Optional data to include with each item
+   * @type array | undefined
+   */
+  include?: ListInclude[]
 }
 
 /**
@@ -3256,6 +3315,11 @@ export type ListTagsQueryParams = {
    * @type boolean | undefined
    */
   withBookmarkCount?: boolean
+  /**
+   * @description Optional data to include with each item
+   * @type array | undefined
+   */
+  include?: ListInclude[]
 }
 
 /**